@@ -1,12 +1,16 @@
 use std::io::Cursor;
 use std::path::{Path, PathBuf};
+use std::process::Command;
 
 use bcmp::AlgoSpec;
 use bindgen::callbacks::ParseCallbacks;
+use bindgen::EnumVariation;
 use clap::Parser;
 use color_eyre::eyre::{Context, Result};
 use regex::{self, Regex};
 
+mod enums;
+
 #[derive(clap::Parser, Debug)]
 #[command(version, about, long_about = None)]
 struct Args {
@@ -122,25 +126,23 @@ fn main() -> Result<()> {
     let build_path = tmp_dir.path();
     let src_path = PathBuf::from(args.src_path);
     let lib_path = build_path.join("libcec");
-    let out_path = PathBuf::from(match args.dest_path {
-        Some(dest_path) => format!(
-            "{}/lib_abi{}_{}.rs",
-            dest_path,
-            &args.major_version,
-            std::env::var("TARGET").unwrap()
-        ),
-        None => format!(
-            "src/lib_abi{}_{}.rs",
-            &args.major_version,
-            std::env::var("TARGET").unwrap()
-        ),
-    });
+    let dest_path = PathBuf::from(args.dest_path.unwrap_or_else(|| "src".to_owned()));
+    let out_file_name = format!(
+        "lib_abi{}_{}.rs",
+        &args.major_version,
+        std::env::var("TARGET").unwrap()
+    );
+    let out_path = dest_path.join(&out_file_name);
+    let newtype_out_path = dest_path.join("newtype").join(&out_file_name);
+    let enums_out_path = dest_path.join(format!("enums_abi{}.rs", &args.major_version));
     // foo2
 
     dbg!(
         &src_path,
         &lib_path,
         &out_path,
+        &newtype_out_path,
+        &enums_out_path,
         &tmp_dir,
         target_lexicon::HOST
     );
@@ -148,18 +150,52 @@ fn main() -> Result<()> {
     // Only the headers are used, so fetch the release version since it's smaller.
     fetch_libcec_source(&lib_path, &args.major_version).context("failed to fetch libcec source")?;
     preprocess_headers(&lib_path);
-    run_bindgen(&src_path, &lib_path, &out_path).context("failed to run bindgen")?;
+    run_bindgen(&src_path, &lib_path, &out_path, EnumStyle::Consts)
+        .context("failed to run bindgen")?;
     dbg!(&out_path);
+    std::fs::create_dir_all(newtype_out_path.parent().unwrap())
+        .context("failed to create newtype bindings directory")?;
+    run_bindgen(&src_path, &lib_path, &newtype_out_path, EnumStyle::NewType)
+        .context("failed to run bindgen (newtype enums)")?;
+    dbg!(&newtype_out_path);
+    generate_enums(&lib_path, &out_path, &enums_out_path).context("failed to generate enums")?;
+    dbg!(&enums_out_path);
 
     Ok(())
 }
 
-fn run_bindgen<P: AsRef<Path>>(src_path: P, lib_path: P, out_path: P) -> Result<()> {
+/// How C enums are represented in the generated bindings
+#[derive(Debug, Clone, Copy)]
+enum EnumStyle {
+    /// `pub type cec_opcode = c_uint` with free `cec_opcode_*` constants (default bindings)
+    Consts,
+    /// `pub struct cec_opcode(pub c_uint)` with associated `cec_opcode::*` constants
+    /// (`newtype-enums` feature). Bit masks are kept as modules of constants.
+    NewType,
+}
+
+fn run_bindgen<P: AsRef<Path>>(
+    src_path: P,
+    lib_path: P,
+    out_path: P,
+    enum_style: EnumStyle,
+) -> Result<()> {
     const ALLOW_REGEX: &str = "(libcec|cec|CEC|LIBCEC)_.*";
+    // Enums which are really bit masks, values are meant to be combined
+    const MASK_ENUM_REGEX: &str = "cec_(audio_status|channel_identifier)";
     let include_path = lib_path.as_ref().join("include");
     let header_path = src_path.as_ref().join("wrapper.h");
 
-    let bindings = bindgen::Builder::default()
+    let builder = match enum_style {
+        EnumStyle::Consts => bindgen::Builder::default(),
+        EnumStyle::NewType => bindgen::Builder::default()
+            .default_enum_style(EnumVariation::NewType {
+                is_bitfield: false,
+                is_global: false,
+            })
+            .constified_enum_module(MASK_ENUM_REGEX),
+    };
+    let bindings = builder
         .header(header_path.to_string_lossy())
         .allowlist_type(ALLOW_REGEX)
         .allowlist_function(ALLOW_REGEX)
@@ -187,6 +223,38 @@ fn run_bindgen<P: AsRef<Path>>(src_path: P, lib_path: P, out_path: P) -> Result<
     Ok(())
 }
 
+fn generate_enums<P: AsRef<Path>>(lib_path: P, bindings_path: P, out_path: P) -> Result<()> {
+    let type_utils_path = lib_path
+        .as_ref()
+        .join("src")
+        .join("libcec")
+        .join("CECTypeUtils.h");
+    let type_utils = std::fs::read_to_string(&type_utils_path).context(format!(
+        "failed to read `{}`",
+        type_utils_path.to_string_lossy()
+    ))?;
+    let bindings = std::fs::read_to_string(bindings_path.as_ref()).context(format!(
+        "failed to read `{}`",
+        bindings_path.as_ref().to_string_lossy()
+    ))?;
+
+    std::fs::write(
+        out_path.as_ref(),
+        enums::generate_enums(&bindings, &type_utils),
+    )
+    .context(format!(
+        "failed to write enums to `{}`",
+        out_path.as_ref().to_string_lossy()
+    ))?;
+    // Best effort, like bindgen does with its output
+    let _ = Command::new("rustfmt")
+        .arg("--edition=2021")
+        .arg(out_path.as_ref())
+        .status();
+
+    Ok(())
+}
+
 #[derive(Debug)]
 struct TidySymbols;
 
@@ -0,0 +1,376 @@
+//! Generates Rust enums for the libcec C enums.
+//!
+//! Enum groups and values are parsed from the (constified) bindgen output, and names from the
+//! `ToString` functions in libcec's `CECTypeUtils.h`, so that the result matches what
+//! `libcec_*_to_string` would produce without needing to call into libcec.
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+use regex::Regex;
+
+/// C enums which are bit masks, not exclusive values
+const MASK_ENUMS: [&str; 3] = [
+    "cec_audio_status",
+    "cec_channel_identifier",
+    "libcec_version",
+];
+
+/// Rust names which differ from the plain camel cased C name
+const NAME_OVERRIDES: [(&str, &str); 1] = [("cec_version", "CecVersion")];
+
+/// Rust variant names which differ from the plain camel cased C name, per enum
+const VARIANT_OVERRIDES: [(&str, &[(&str, &str)]); 1] = [(
+    "cec_logical_address",
+    &[
+        ("RECORDINGDEVICE1", "RecordingDevice1"),
+        ("RECORDINGDEVICE2", "RecordingDevice2"),
+        ("RECORDINGDEVICE3", "RecordingDevice3"),
+        ("PLAYBACKDEVICE1", "PlaybackDevice1"),
+        ("PLAYBACKDEVICE2", "PlaybackDevice2"),
+        ("PLAYBACKDEVICE3", "PlaybackDevice3"),
+        ("AUDIOSYSTEM", "AudioSystem"),
+        ("FREEUSE", "FreeUse"),
+    ],
+)];
+
+struct Variant {
+    /// Constant name as in the bindings, without the enum name prefix
+    c_name: String,
+    rust_name: String,
+    value: i64,
+    name: String,
+}
+
+struct EnumGroup {
+    c_name: String,
+    rust_name: String,
+    variants: Vec<Variant>,
+    /// Duplicate values and the C `UNKNOWN` constant, as (constant name, value expression)
+    aliases: Vec<(String, String)>,
+    /// Name of values not known to libcec
+    unknown_name: String,
+    /// Whether the C enum has an `UNKNOWN` constant
+    has_unknown: bool,
+}
+
+/// Names from a `ToString` function: C constant names mapped to their strings, and the string
+/// for other values
+#[derive(Default)]
+struct TypeNames {
+    names: Vec<(String, String)>,
+    default: Option<String>,
+}
+
+fn camel_case(name: &str) -> String {
+    let mut out = String::new();
+    for part in name.split('_').filter(|part| !part.is_empty()) {
+        if out.ends_with(|c: char| c.is_ascii_digit())
+            && part.starts_with(|c: char| c.is_ascii_digit())
+        {
+            out.push('_');
+        }
+        let mut chars = part.chars();
+        out.extend(chars.next().map(|c| c.to_ascii_uppercase()));
+        out.extend(chars.map(|c| c.to_ascii_lowercase()));
+    }
+    if out.starts_with(|c: char| c.is_ascii_digit()) {
+        out.insert(0, 'V');
+    }
+    out
+}
+
+fn rust_type_name(c_name: &str) -> String {
+    if let Some((_, name)) = NAME_OVERRIDES.iter().find(|(c, _)| *c == c_name) {
+        return (*name).to_owned();
+    }
+    let name = c_name
+        .strip_prefix("libcec_")
+        .or_else(|| c_name.strip_prefix("cec_"))
+        .unwrap_or(c_name);
+    camel_case(name)
+}
+
+fn rust_variant_name(ty: &str, c_name: &str, prefix: &str) -> String {
+    VARIANT_OVERRIDES
+        .iter()
+        .filter(|(override_ty, _)| *override_ty == ty)
+        .flat_map(|(_, overrides)| overrides.iter())
+        .find(|(constant, _)| *constant == c_name)
+        .map(|(_, name)| (*name).to_owned())
+        .unwrap_or_else(|| camel_case(&c_name[prefix.len()..]))
+}
+
+/// Parse `ToString(const <type> ...)` functions of CECTypeUtils.h, per C type
+fn parse_to_string(type_utils: &str) -> BTreeMap<String, TypeNames> {
+    let function_re = Regex::new(r"ToString\s*\(\s*const\s+(\w+)\s+\w+\s*\)").unwrap();
+    let token_re = Regex::new(r#"case\s+(\w+)\s*:|default\s*:|return\s+"([^"]*)"\s*;"#).unwrap();
+    let starts: Vec<_> = function_re.captures_iter(type_utils).collect();
+    let mut result = BTreeMap::new();
+    for (i, captures) in starts.iter().enumerate() {
+        let whole = captures.get(0).unwrap();
+        let end = starts
+            .get(i + 1)
+            .map(|next| next.get(0).unwrap().start())
+            .unwrap_or(type_utils.len());
+        let body = &type_utils[whole.end()..end];
+        // `None` for the `default:` label
+        let mut labels: Vec<Option<String>> = vec![];
+        let mut type_names = TypeNames::default();
+        for token in token_re.captures_iter(body) {
+            if let Some(label) = token.get(1) {
+                labels.push(Some(label.as_str().to_owned()));
+            } else if let Some(string) = token.get(2) {
+                for label in labels.drain(..) {
+                    match label {
+                        Some(label) => type_names.names.push((label, string.as_str().to_owned())),
+                        None => type_names.default = Some(string.as_str().to_owned()),
+                    }
+                }
+            } else {
+                labels.push(None);
+            }
+        }
+        result.entry(captures[1].to_owned()).or_insert(type_names);
+    }
+    result
+}
+/// Find the libcec name of a variant. C constants in CECTypeUtils.h carry the full prefix
+/// (e.g. `CEC_OPCODE_ACTIVE_SOURCE`), the bindings the tidied one (e.g. `ACTIVE_SOURCE`).
+fn libcec_name<'a>(
+    c_name: &str,
+    all_c_names: &[&str],
+    names: &'a [(String, String)],
+) -> Option<&'a str> {
+    let matches = |label: &str, c_name: &str| {
+        let c_name = c_name.trim_start_matches('_');
+        label == c_name || label.ends_with(&format!("_{c_name}"))
+    };
+    names
+        .iter()
+        .find(|(label, _)| {
+            // longest matching constant wins, e.g. CHANNEL_UP over UP
+            matches(label, c_name)
+                && !all_c_names
+                    .iter()
+                    .any(|other| other.len() > c_name.len() && matches(label, other))
+        })
+        .map(|(_, name)| name.as_str())
+}
+
+fn fallback_name(c_name: &str) -> String {
+    c_name
+        .trim_start_matches('_')
+        .replace('_', " ")
+        .to_lowercase()
+}
+
+fn parse_groups(bindings: &str, type_utils: &str) -> Vec<EnumGroup> {
+    // the integer type differs between targets, e.g. `c_int` on MSVC, so only the name is used
+    let alias_re = Regex::new(r"pub type (\w+) = ::std::os::raw::c_u?int;").unwrap();
+    let const_re = Regex::new(r"pub const (\w+):\s*(\w+)\s*=\s*(-?\d+);").unwrap();
+    let to_string = parse_to_string(type_utils);
+
+    let enum_types: BTreeSet<&str> = alias_re
+        .captures_iter(bindings)
+        .map(|captures| captures.extract::<1>().1[0])
+        .collect();
+    let mut groups: Vec<(String, Vec<(String, i64)>)> = vec![];
+    for captures in const_re.captures_iter(bindings) {
+        let ty = &captures[2];
+        if !enum_types.contains(ty) || MASK_ENUMS.contains(&ty) {
+            continue;
+        }
+        let c_name = captures[1][ty.len() + 1..].to_owned();
+        let value: i64 = captures[3].parse().unwrap();
+        match groups.iter_mut().find(|(name, _)| name == ty) {
+            Some((_, consts)) => consts.push((c_name, value)),
+            None => groups.push((ty.to_owned(), vec![(c_name, value)])),
+        }
+    }
+
+    groups
+        .into_iter()
+        .map(|(ty, consts)| {
+            let type_names = to_string.get(&ty);
+            let names = type_names.map(|t| t.names.as_slice()).unwrap_or_default();
+            let all_c_names: Vec<&str> = consts.iter().map(|(c_name, _)| c_name.as_str()).collect();
+            // leftover C prefixes, e.g. CEC_LOG_ERROR -> ERROR
+            let prefix = common_prefix(&all_c_names);
+            let mut variants: Vec<Variant> = vec![];
+            let mut aliases = vec![];
+            let mut has_unknown = false;
+            for (c_name, value) in &consts {
+                if c_name == "UNKNOWN" {
+                    // Folded into the `Unknown(raw)` variant
+                    aliases.push((c_name.clone(), format!("Unknown(c_enum!({ty}, {value}))")));
+                    has_unknown = true;
+                    continue;
+                }
+                let name = libcec_name(c_name, &all_c_names, names);
+                let variant = Variant {
+                    c_name: c_name.clone(),
+                    rust_name: rust_variant_name(&ty, c_name, prefix),
+                    value: *value,
+                    name: name
+                        .map(str::to_owned)
+                        .unwrap_or_else(|| fallback_name(c_name)),
+                };
+                match variants.iter_mut().find(|other| other.value == *value) {
+                    // Duplicate value: the constant libcec names wins, others become aliases
+                    Some(other)
+                        if name.is_some()
+                            && libcec_name(&other.c_name, &all_c_names, names).is_none() =>
+                    {
+                        let replaced = std::mem::replace(other, variant);
+                        aliases.push((replaced.c_name, other.rust_name.clone()));
+                    }
+                    Some(other) => aliases.push((variant.c_name, other.rust_name.clone())),
+                    None => variants.push(variant),
+                }
+            }
+            EnumGroup {
+                rust_name: rust_type_name(&ty),
+                c_name: ty,
+                variants,
+                aliases,
+                unknown_name: type_names
+                    .and_then(|t| t.default.clone())
+                    .unwrap_or_else(|| "unknown".to_owned()),
+                has_unknown,
+            }
+        })
+        .collect()
+}
+
+fn common_prefix<'a>(c_names: &[&'a str]) -> &'a str {
+    let first = c_names[0];
+    if !first.starts_with("CEC_") {
+        return "";
+    }
+    let mut prefix_len = 0;
+    for (i, _) in first.match_indices('_') {
+        let prefix = &first[..=i];
+        if c_names.iter().all(|c_name| c_name.starts_with(prefix)) {
+            prefix_len = i + 1;
+        } else {
+            break;
+        }
+    }
+    &first[..prefix_len]
+}
+
+fn write_group(out: &mut String, group: &EnumGroup) -> std::fmt::Result {
+    let EnumGroup {
+        c_name,
+        rust_name,
+        variants,
+        aliases,
+        unknown_name,
+        has_unknown,
+    } = group;
+    writeln!(out, "/// `{c_name}`")?;
+    writeln!(out, "#[derive(Debug, Clone, Copy)]")?;
+    writeln!(out, "pub enum {rust_name} {{")?;
+    for variant in variants {
+        writeln!(out, "    /// `{c_name}_{}`", variant.c_name)?;
+        writeln!(out, "    {},", variant.rust_name)?;
+    }
+    writeln!(out, "    /// Value not known to libcec")?;
+    writeln!(out, "    Unknown({c_name}),")?;
+    writeln!(out, "}}")?;
+
+    writeln!(out, "impl {rust_name} {{")?;
+    for (alias, value) in aliases {
+        writeln!(out, "    /// `{c_name}_{alias}`")?;
+        writeln!(out, "    pub const {alias}: Self = Self::{value};")?;
+    }
+    writeln!(out, "    /// All known values")?;
+    writeln!(out, "    pub const ALL: &'static [Self] = &[")?;
+    for variant in variants {
+        writeln!(out, "        Self::{},", variant.rust_name)?;
+    }
+    writeln!(out, "    ];")?;
+    let named_unknown = if *has_unknown {
+        "Some(Self::UNKNOWN)"
+    } else {
+        "None"
+    };
+    writeln!(
+        out,
+        "    const NAMED_UNKNOWN: Option<Self> = {named_unknown};"
+    )?;
+    writeln!(
+        out,
+        "    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,"
+    )?;
+    writeln!(out, "    /// and `Unknown`")?;
+    writeln!(out, "    #[cfg(feature = \"serde\")]")?;
+    writeln!(out, "    const VARIANT_NAMES: &'static [&'static str] = &[")?;
+    for variant in variants {
+        writeln!(out, "        {:?},", variant.rust_name)?;
+    }
+    if *has_unknown {
+        writeln!(out, "        \"UNKNOWN\",")?;
+    }
+    writeln!(out, "        \"Unknown\",")?;
+    writeln!(out, "    ];")?;
+    writeln!(out, "    /// Name of the value, as used by libcec")?;
+    writeln!(out, "    pub const fn name(self) -> &'static str {{")?;
+    writeln!(out, "        match self {{")?;
+    for variant in variants {
+        writeln!(
+            out,
+            "            Self::{} => {:?},",
+            variant.rust_name, variant.name
+        )?;
+    }
+    writeln!(out, "            Self::Unknown(_) => {unknown_name:?},")?;
+    writeln!(out, "        }}")?;
+    writeln!(out, "    }}")?;
+    writeln!(
+        out,
+        "    /// Converts from the C value, keeping values not known to libcec as `Unknown`"
+    )?;
+    writeln!(out, "    pub const fn from_raw(raw: {c_name}) -> Self {{")?;
+    writeln!(out, "        match c_enum_value!(raw) {{")?;
+    for variant in variants {
+        writeln!(
+            out,
+            "            {} => Self::{},",
+            variant.value, variant.rust_name
+        )?;
+    }
+    writeln!(out, "            _ => Self::Unknown(raw),")?;
+    writeln!(out, "        }}")?;
+    writeln!(out, "    }}")?;
+    writeln!(out, "    /// Converts to the C value")?;
+    writeln!(out, "    pub const fn raw(self) -> {c_name} {{")?;
+    writeln!(out, "        match self {{")?;
+    for variant in variants {
+        writeln!(
+            out,
+            "            Self::{} => c_enum!({c_name}, {}),",
+            variant.rust_name, variant.value
+        )?;
+    }
+    writeln!(out, "            Self::Unknown(raw) => raw,")?;
+    writeln!(out, "        }}")?;
+    writeln!(out, "    }}")?;
+    writeln!(out, "}}")?;
+    writeln!(out, "impl_enum_conversions!({rust_name}, {c_name});")?;
+    Ok(())
+}
+
+/// Generate Rust enums for the C enums in `bindings`, named after `ToString` in `type_utils`
+/// (contents of libcec's `CECTypeUtils.h`).
+///
+/// The result is meant to be included in the `enums` module of libcec-sys, which provides
+/// the `c_enum!`, `c_enum_value!` and `impl_enum_conversions!` macros. The C enums are referred
+/// to by their binding aliases, so the result is the same for every target.
+pub fn generate_enums(bindings: &str, type_utils: &str) -> String {
+    let mut out = String::from("/* automatically generated by cec_bindgen, do not edit */\n\n");
+    for group in parse_groups(bindings, type_utils) {
+        write_group(&mut out, &group).unwrap();
+    }
+    out
+}
@@ -0,0 +1,215 @@
+//! Owned descriptions of CEC adapters
+//!
+//! `libcec_detect_adapters` and `libcec_find_adapters` fill a caller allocated array of
+//! `cec_adapter_descriptor` / `cec_adapter`, each with two 1 KiB string fields, and return
+//! the number of adapters or -1. [`AdapterDescriptor`] and [`Adapter`] are owned copies of them,
+//! with the strings, adapter type, firmware build date and physical address decoded. With the
+//! `safe` feature, `Connection::detect_adapters` and `Connection::find_adapters` return them,
+//! growing the array when more adapters are found than fit. An [`AdapterDescriptor`] converts
+//! back to a `cec_adapter_descriptor`, e.g. for code written against the C type.
+//!
+//! ```
+//! use libcec_sys::adapter::AdapterDescriptor;
+//! use libcec_sys::cec_adapter_descriptor;
+//! use libcec_sys::strings::AdapterDescriptorExt;
+//!
+//! let mut raw = cec_adapter_descriptor::default();
+//! raw.set_com_path("/dev/ttyACM0").unwrap();
+//! raw.iVendorId = 0x2548;
+//! raw.iFirmwareBuildDate = 1_400_000_000;
+//! let adapter = AdapterDescriptor::from(&raw);
+//! assert_eq!(adapter.path, "/dev/ttyACM0");
+//! assert_eq!(adapter.vendor_id, 0x2548);
+//! assert!(adapter.firmware_build_date.is_some());
+//! ```
+use std::time::{Duration, SystemTime};
+
+use crate::address::PhysicalAddress;
+use crate::enums::AdapterType;
+#[cfg(any(feature = "safe", test))]
+use crate::error::CecError;
+use crate::strings::{self, AdapterDescriptorExt, StringError};
+use crate::*;
+
+/// Number of adapters asked for at first
+pub const INITIAL_ADAPTERS: usize = 10;
+
+/// Most adapters which libcec can return, as their number is an `i8`
+pub const MAX_ADAPTERS: usize = i8::MAX as usize;
+
+fn owned_str(chars: &[std::os::raw::c_char]) -> String {
+    String::from_utf8_lossy(strings::c_bytes(chars)).into_owned()
+}
+
+/// Adapter found by `libcec_detect_adapters`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AdapterDescriptor {
+    /// Path to open the adapter with, `strComPath`
+    pub path: String,
+    /// Name of the port, `strComName`
+    pub name: String,
+    /// USB vendor id, 0x2548 for Pulse-Eight adapters
+    pub vendor_id: u16,
+    /// USB product id
+    pub product_id: u16,
+    pub firmware_version: u16,
+    /// Firmware build date, `None` when the adapter does not report it
+    pub firmware_build_date: Option<SystemTime>,
+    pub physical_address: PhysicalAddress,
+    pub adapter_type: AdapterType,
+}
+
+impl From<&cec_adapter_descriptor> for AdapterDescriptor {
+    fn from(descriptor: &cec_adapter_descriptor) -> Self {
+        let build_date = descriptor.iFirmwareBuildDate;
+        AdapterDescriptor {
+            path: owned_str(&descriptor.strComPath),
+            name: owned_str(&descriptor.strComName),
+            vendor_id: descriptor.iVendorId,
+            product_id: descriptor.iProductId,
+            firmware_version: descriptor.iFirmwareVersion,
+            // seconds since the Unix epoch
+            firmware_build_date: (build_date != 0)
+                .then(|| SystemTime::UNIX_EPOCH + Duration::from_secs(build_date.into())),
+            physical_address: PhysicalAddress::from_raw(descriptor.iPhysicalAddress),
+            adapter_type: AdapterType::from_raw(descriptor.adapterType),
+        }
+    }
+}
+
+impl TryFrom<&AdapterDescriptor> for cec_adapter_descriptor {
+    type Error = StringError;
+
+    /// Fails when the path or the name does not fit the 1 KiB C strings, or contains NUL
+    fn try_from(adapter: &AdapterDescriptor) -> Result<Self, Self::Error> {
+        let mut descriptor = cec_adapter_descriptor {
+            iVendorId: adapter.vendor_id,
+            iProductId: adapter.product_id,
+            iFirmwareVersion: adapter.firmware_version,
+            iPhysicalAddress: adapter.physical_address.raw(),
+            iFirmwareBuildDate: adapter
+                .firmware_build_date
+                .and_then(|date| date.duration_since(SystemTime::UNIX_EPOCH).ok())
+                .map_or(0, |since| {
+                    u32::try_from(since.as_secs()).unwrap_or(u32::MAX)
+                }),
+            adapterType: adapter.adapter_type.raw(),
+            ..Default::default()
+        };
+        descriptor.set_com_path(&adapter.path)?;
+        descriptor.set_com_name(&adapter.name)?;
+        Ok(descriptor)
+    }
+}
+
+/// Adapter found by `libcec_find_adapters`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Adapter {
+    /// Path to open the adapter with
+    pub path: String,
+    /// Name of the port
+    pub name: String,
+}
+
+impl From<&cec_adapter> for Adapter {
+    fn from(adapter: &cec_adapter) -> Self {
+        Adapter {
+            path: owned_str(&adapter.path),
+            name: owned_str(&adapter.comm),
+        }
+    }
+}
+
+/// Adapters returned by `fill`, which fills a buffer and returns the number of adapters
+///
+/// When the buffer is full, there may be more adapters, so `fill` is called again with a
+/// larger one, up to [`MAX_ADAPTERS`].
+#[cfg(any(feature = "safe", test))]
+pub(crate) fn collect<T: Default + Clone>(
+    mut fill: impl FnMut(&mut [T]) -> i8,
+) -> Result<Vec<T>, CecError> {
+    let mut size = INITIAL_ADAPTERS;
+    loop {
+        let mut buffer = vec![T::default(); size];
+        let count = usize::from(CecError::check_detect_adapters(fill(&mut buffer))?);
+        if count < size || size == MAX_ADAPTERS {
+            buffer.truncate(count);
+            return Ok(buffer);
+        }
+        size = (size * 2).min(MAX_ADAPTERS);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strings::AdapterExt;
+
+    #[test]
+    fn test_descriptor() {
+        let raw = cec_adapter_descriptor {
+            iPhysicalAddress: 0x1000,
+            adapterType: AdapterType::Linux.raw(),
+            ..Default::default()
+        };
+        let adapter = AdapterDescriptor::from(&raw);
+        assert_eq!(adapter.path, "");
+        assert_eq!(adapter.firmware_build_date, None);
+        assert_eq!(adapter.physical_address, "1.0.0.0".parse().unwrap());
+        assert_eq!(adapter.adapter_type, AdapterType::Linux);
+    }
+
+    #[test]
+    fn test_descriptor_to_raw() {
+        let mut raw = cec_adapter_descriptor {
+            iVendorId: 0x2548,
+            iProductId: 0x1001,
+            iFirmwareVersion: 12,
+            iPhysicalAddress: 0x2100,
+            iFirmwareBuildDate: 1_400_000_000,
+            adapterType: AdapterType::P8External.raw(),
+            ..Default::default()
+        };
+        raw.set_com_path("/dev/ttyACM0").unwrap();
+        raw.set_com_name("/sys/bus/usb/devices/1-1").unwrap();
+        let adapter = AdapterDescriptor::from(&raw);
+        let back = cec_adapter_descriptor::try_from(&adapter).unwrap();
+        assert_eq!(AdapterDescriptor::from(&back), adapter);
+        assert_eq!(back.iFirmwareBuildDate, 1_400_000_000);
+
+        let long = AdapterDescriptor {
+            path: "x".repeat(1024),
+            ..adapter
+        };
+        assert!(cec_adapter_descriptor::try_from(&long).is_err());
+    }
+
+    #[test]
+    fn test_collect_grows() {
+        let mut sizes = Vec::new();
+        let adapters = collect(|buffer: &mut [cec_adapter]| {
+            sizes.push(buffer.len());
+            let count = buffer.len().min(25);
+            for (index, adapter) in buffer[..count].iter_mut().enumerate() {
+                adapter.set_path(&format!("/dev/cec{index}")).unwrap();
+            }
+            count as i8
+        })
+        .unwrap();
+        assert_eq!(sizes, [10, 20, 40]);
+        assert_eq!(adapters.len(), 25);
+        assert_eq!(Adapter::from(&adapters[24]).path, "/dev/cec24");
+    }
+
+    #[test]
+    fn test_collect_limit() {
+        let adapters = collect(|buffer: &mut [u8]| buffer.len() as i8).unwrap();
+        assert_eq!(adapters.len(), MAX_ADAPTERS);
+        assert_eq!(
+            collect(|_: &mut [u8]| -1),
+            Err(CecError::DetectAdaptersFailed)
+        );
+    }
+}
@@ -0,0 +1,688 @@
+//! Logical and physical addresses
+//!
+//! [`LogicalAddresses`] is the Rust counterpart of `cec_logical_addresses`, a primary address
+//! plus a 16 entry bitmap, used for `wakeDevices`, `powerOffDevices`, `logicalAddresses` and
+//! `libcec_get_active_devices`. It converts to and from the C struct without loss.
+//!
+//! [`PhysicalAddress`] is the position of a device in the HDMI topology, printed as
+//! `"1.2.0.0"`: port 2 of the device at port 1 of the TV.
+//!
+//! ```
+//! use libcec_sys::address::LogicalAddresses;
+//! use libcec_sys::enums::{DeviceType, LogicalAddress};
+//!
+//! let mut addresses = LogicalAddresses::new();
+//! addresses.insert(LogicalAddress::PlaybackDevice1);
+//! addresses.insert(LogicalAddress::AudioSystem);
+//! assert_eq!(addresses.primary(), LogicalAddress::PlaybackDevice1);
+//! assert!(addresses.contains(LogicalAddress::AudioSystem));
+//! assert_eq!(
+//!     LogicalAddress::PlaybackDevice2.device_type(),
+//!     DeviceType::PlaybackDevice
+//! );
+//!
+//! use libcec_sys::address::PhysicalAddress;
+//!
+//! let address: PhysicalAddress = "1.2.0.0".parse().unwrap();
+//! assert_eq!(address.raw(), 0x1200);
+//! assert_eq!(address.parent(), Some(PhysicalAddress::from_raw(0x1000)));
+//! assert!(PhysicalAddress::TV.is_upstream_of(address));
+//! ```
+use std::error::Error;
+use std::fmt;
+use std::ops::{BitAnd, BitOr, Sub};
+use std::os::raw::c_int;
+use std::str::FromStr;
+
+use crate::enums::{DeviceType, LogicalAddress};
+use crate::*;
+
+impl LogicalAddress {
+    /// Address of the 4 bit `index`, `None` for indices above 15
+    pub const fn from_index(index: u8) -> Option<Self> {
+        if index < 16 {
+            Some(Self::from_raw(c_enum!(cec_logical_address, index as c_int)))
+        } else {
+            None
+        }
+    }
+
+    /// 4 bit index of the address, `None` for `UNKNOWN` and other values outside 0 to 15
+    pub const fn index(self) -> Option<u8> {
+        match self {
+            Self::Unknown(_) => None,
+            _ => Some(c_enum_value!(self.raw()) as u8),
+        }
+    }
+
+    /// Address 15, which is the broadcast address as destination and means
+    /// [`Self::UNREGISTERED`] as initiator
+    pub const fn is_broadcast(self) -> bool {
+        matches!(self, Self::Broadcast)
+    }
+
+    /// Device type of the address, `RESERVED` when there is none, as in libcec
+    pub const fn device_type(self) -> DeviceType {
+        match self {
+            Self::Tv => DeviceType::Tv,
+            Self::RecordingDevice1 | Self::RecordingDevice2 | Self::RecordingDevice3 => {
+                DeviceType::RecordingDevice
+            }
+            Self::Tuner1 | Self::Tuner2 | Self::Tuner3 | Self::Tuner4 => DeviceType::Tuner,
+            Self::PlaybackDevice1 | Self::PlaybackDevice2 | Self::PlaybackDevice3 => {
+                DeviceType::PlaybackDevice
+            }
+            Self::AudioSystem => DeviceType::AudioSystem,
+            _ => DeviceType::Reserved,
+        }
+    }
+}
+
+impl DeviceType {
+    /// Logical addresses a device of the type can claim, in order of preference
+    pub const fn logical_addresses(self) -> &'static [LogicalAddress] {
+        match self {
+            Self::Tv => &[LogicalAddress::Tv, LogicalAddress::FreeUse],
+            Self::RecordingDevice => &[
+                LogicalAddress::RecordingDevice1,
+                LogicalAddress::RecordingDevice2,
+                LogicalAddress::RecordingDevice3,
+            ],
+            Self::Tuner => &[
+                LogicalAddress::Tuner1,
+                LogicalAddress::Tuner2,
+                LogicalAddress::Tuner3,
+                LogicalAddress::Tuner4,
+            ],
+            Self::PlaybackDevice => &[
+                LogicalAddress::PlaybackDevice1,
+                LogicalAddress::PlaybackDevice2,
+                LogicalAddress::PlaybackDevice3,
+            ],
+            Self::AudioSystem => &[LogicalAddress::AudioSystem],
+            _ => &[],
+        }
+    }
+}
+
+/// Set of logical addresses with a primary address, as `cec_logical_addresses`
+///
+/// Like libcec, inserting an address makes it the primary address when there is none
+/// ([`LogicalAddress::UNREGISTERED`]), and removing the primary address unsets it.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LogicalAddresses {
+    primary: LogicalAddress,
+    bits: u16,
+}
+
+impl Default for LogicalAddresses {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LogicalAddresses {
+    /// Empty set, without primary address
+    pub const fn new() -> Self {
+        LogicalAddresses {
+            primary: LogicalAddress::UNREGISTERED,
+            bits: 0,
+        }
+    }
+
+    /// Set of the addresses in the bitmap, bit 0 being [`LogicalAddress::Tv`]
+    pub const fn from_bits(primary: LogicalAddress, bits: u16) -> Self {
+        LogicalAddresses { primary, bits }
+    }
+
+    pub const fn bits(&self) -> u16 {
+        self.bits
+    }
+
+    pub const fn primary(&self) -> LogicalAddress {
+        self.primary
+    }
+
+    pub fn set_primary(&mut self, primary: LogicalAddress) {
+        self.primary = primary;
+    }
+
+    /// Add `address`, returning whether it was not in the set yet. Addresses outside 0 to 15
+    /// are ignored.
+    pub fn insert(&mut self, address: LogicalAddress) -> bool {
+        let Some(index) = address.index() else {
+            return false;
+        };
+        if self.primary == LogicalAddress::UNREGISTERED {
+            self.primary = address;
+        }
+        let inserted = self.bits & (1 << index) == 0;
+        self.bits |= 1 << index;
+        inserted
+    }
+
+    /// Remove `address`, returning whether it was in the set
+    pub fn remove(&mut self, address: LogicalAddress) -> bool {
+        if self.primary == address {
+            self.primary = LogicalAddress::UNREGISTERED;
+        }
+        let Some(index) = address.index() else {
+            return false;
+        };
+        let removed = self.bits & (1 << index) != 0;
+        self.bits &= !(1 << index);
+        removed
+    }
+
+    pub const fn contains(&self, address: LogicalAddress) -> bool {
+        match address.index() {
+            Some(index) => self.bits & (1 << index) != 0,
+            None => false,
+        }
+    }
+
+    pub const fn len(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    /// Remove all addresses and the primary address
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Addresses in ascending order
+    pub fn iter(&self) -> Iter {
+        Iter { bits: self.bits }
+    }
+
+    /// Addresses in either set, with the primary address of `self`
+    pub const fn union(&self, other: &Self) -> Self {
+        Self::from_bits(self.primary, self.bits | other.bits)
+    }
+
+    /// Addresses in both sets, with the primary address of `self`
+    pub const fn intersection(&self, other: &Self) -> Self {
+        Self::from_bits(self.primary, self.bits & other.bits)
+    }
+
+    /// Addresses in `self` but not in `other`, with the primary address of `self`
+    pub const fn difference(&self, other: &Self) -> Self {
+        Self::from_bits(self.primary, self.bits & !other.bits)
+    }
+}
+
+impl fmt::Debug for LogicalAddresses {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LogicalAddresses")
+            .field("primary", &self.primary)
+            .field("addresses", &DebugSet(self))
+            .finish()
+    }
+}
+
+struct DebugSet<'a>(&'a LogicalAddresses);
+
+impl fmt::Debug for DebugSet<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.0.iter()).finish()
+    }
+}
+
+/// Iterator over the addresses of [`LogicalAddresses`]
+#[derive(Debug, Clone)]
+pub struct Iter {
+    bits: u16,
+}
+
+impl Iterator for Iter {
+    type Item = LogicalAddress;
+
+    fn next(&mut self) -> Option<LogicalAddress> {
+        if self.bits == 0 {
+            return None;
+        }
+        let index = self.bits.trailing_zeros();
+        self.bits &= self.bits - 1;
+        LogicalAddress::from_index(index as u8)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bits.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Iter {}
+
+impl IntoIterator for &LogicalAddresses {
+    type Item = LogicalAddress;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Iter {
+        self.iter()
+    }
+}
+
+impl Extend<LogicalAddress> for LogicalAddresses {
+    fn extend<I: IntoIterator<Item = LogicalAddress>>(&mut self, iter: I) {
+        for address in iter {
+            self.insert(address);
+        }
+    }
+}
+
+impl FromIterator<LogicalAddress> for LogicalAddresses {
+    fn from_iter<I: IntoIterator<Item = LogicalAddress>>(iter: I) -> Self {
+        let mut addresses = Self::new();
+        addresses.extend(iter);
+        addresses
+    }
+}
+
+impl BitOr for LogicalAddresses {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        self.union(&other)
+    }
+}
+
+impl BitAnd for LogicalAddresses {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        self.intersection(&other)
+    }
+}
+
+impl Sub for LogicalAddresses {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.difference(&other)
+    }
+}
+
+/// Entries other than 0 are set
+impl From<cec_logical_addresses> for LogicalAddresses {
+    fn from(addresses: cec_logical_addresses) -> Self {
+        let bits = addresses
+            .addresses
+            .iter()
+            .enumerate()
+            .filter(|(_, &flag)| flag != 0)
+            .fold(0, |bits, (index, _)| bits | 1 << index);
+        LogicalAddresses {
+            primary: LogicalAddress::from_raw(addresses.primary),
+            bits,
+        }
+    }
+}
+
+impl From<LogicalAddresses> for cec_logical_addresses {
+    fn from(addresses: LogicalAddresses) -> Self {
+        let mut flags = [0; 16];
+        for (index, flag) in flags.iter_mut().enumerate() {
+            *flag = c_int::from(addresses.bits & (1 << index) != 0);
+        }
+        cec_logical_addresses {
+            primary: addresses.primary.raw(),
+            addresses: flags,
+        }
+    }
+}
+
+/// Physical address, the position of a device in the HDMI topology
+///
+/// Each nibble is the HDMI port at a level of the topology, starting from the TV at
+/// `0.0.0.0`. The address is valid when it is the TV or in
+/// `CEC_MIN_PHYSICAL_ADDRESS..=CEC_MAX_PHYSICAL_ADDRESS`, with no port after a zero nibble.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PhysicalAddress(u16);
+
+/// Invalid or malformed [`PhysicalAddress`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PhysicalAddressError {
+    /// Not of the form `"1.2.0.0"`
+    Syntax(String),
+    /// Not a valid address, e.g. `CEC_INVALID_PHYSICAL_ADDRESS` or `1.0.2.0`
+    Invalid(u16),
+}
+
+impl fmt::Display for PhysicalAddressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PhysicalAddressError::Syntax(s) => {
+                write!(f, "invalid physical address `{s}`, expected e.g. `1.2.0.0`")
+            }
+            PhysicalAddressError::Invalid(raw) => write!(f, "invalid physical address {raw:#06x}"),
+        }
+    }
+}
+
+impl Error for PhysicalAddressError {}
+
+impl PhysicalAddress {
+    /// `CEC_PHYSICAL_ADDRESS_TV`, 0.0.0.0
+    pub const TV: Self = Self(CEC_PHYSICAL_ADDRESS_TV as u16);
+    /// `CEC_INVALID_PHYSICAL_ADDRESS`, f.f.f.f
+    pub const INVALID: Self = Self(CEC_INVALID_PHYSICAL_ADDRESS as u16);
+
+    /// Address of the raw value, without validation
+    pub const fn from_raw(raw: u16) -> Self {
+        Self(raw)
+    }
+
+    pub const fn raw(self) -> u16 {
+        self.0
+    }
+
+    /// Address of the ports from the TV, e.g. `[1, 2, 0, 0]` for 1.2.0.0
+    pub const fn from_nibbles(nibbles: [u8; 4]) -> Self {
+        Self(
+            ((nibbles[0] as u16 & 0xf) << 12)
+                | ((nibbles[1] as u16 & 0xf) << 8)
+                | ((nibbles[2] as u16 & 0xf) << 4)
+                | (nibbles[3] as u16 & 0xf),
+        )
+    }
+
+    pub const fn nibbles(self) -> [u8; 4] {
+        [
+            (self.0 >> 12) as u8,
+            (self.0 >> 8) as u8 & 0xf,
+            (self.0 >> 4) as u8 & 0xf,
+            self.0 as u8 & 0xf,
+        ]
+    }
+
+    pub const fn is_valid(self) -> bool {
+        let raw = self.0 as u32;
+        if raw == CEC_PHYSICAL_ADDRESS_TV {
+            return true;
+        }
+        if raw < CEC_MIN_PHYSICAL_ADDRESS || raw > CEC_MAX_PHYSICAL_ADDRESS {
+            return false;
+        }
+        // the nibbles after the first zero one must be zero too
+        let depth = self.depth() as u32;
+        depth == 4 || self.0 & (0xffff >> (4 * depth)) == 0
+    }
+
+    /// The address when it is valid, see [`PhysicalAddress::is_valid`]
+    pub fn validate(self) -> Result<Self, PhysicalAddressError> {
+        if self.is_valid() {
+            Ok(self)
+        } else {
+            Err(PhysicalAddressError::Invalid(self.0))
+        }
+    }
+
+    /// Number of HDMI links between the TV and the device, 0 for the TV
+    pub const fn depth(self) -> u8 {
+        let nibbles = self.nibbles();
+        let mut depth = 0;
+        while depth < 4 && nibbles[depth] != 0 {
+            depth += 1;
+        }
+        depth as u8
+    }
+
+    /// HDMI port at each level, starting from the port of the TV
+    pub fn ports(self) -> impl Iterator<Item = u8> {
+        self.nibbles().into_iter().take(self.depth().into())
+    }
+
+    /// HDMI port of the parent device the device is connected to, `None` for the TV
+    pub const fn port(self) -> Option<u8> {
+        match self.depth() {
+            0 => None,
+            depth => Some(self.nibbles()[depth as usize - 1]),
+        }
+    }
+
+    /// Address of the device the device is connected to, `None` for the TV
+    pub const fn parent(self) -> Option<Self> {
+        match self.depth() {
+            0 => None,
+            depth => Some(Self(self.0 & !(0xf000 >> (4 * (depth as u16 - 1))))),
+        }
+    }
+
+    /// Address of the device connected to HDMI `port` of the device, `None` when the port is
+    /// not in 1 to 15 or the topology is already 4 levels deep
+    pub const fn child(self, port: u8) -> Option<Self> {
+        let depth = self.depth();
+        if port == 0 || port > 0xf || depth >= 4 {
+            return None;
+        }
+        Some(Self(self.0 | (port as u16) << (12 - 4 * depth as u16)))
+    }
+
+    /// Whether `other` is connected, directly or through other devices, to this device
+    pub const fn is_upstream_of(self, other: Self) -> bool {
+        let depth = self.depth();
+        if depth >= other.depth() {
+            return false;
+        }
+        let mask = !(0xffffu16 >> (4 * depth as u16));
+        other.0 & mask == self.0
+    }
+
+    /// Whether this device is connected, directly or through other devices, to `other`
+    pub const fn is_downstream_of(self, other: Self) -> bool {
+        other.is_upstream_of(self)
+    }
+}
+
+impl Default for PhysicalAddress {
+    fn default() -> Self {
+        Self::INVALID
+    }
+}
+
+impl fmt::Display for PhysicalAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [a, b, c, d] = self.nibbles();
+        write!(f, "{a:x}.{b:x}.{c:x}.{d:x}")
+    }
+}
+
+impl fmt::Debug for PhysicalAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PhysicalAddress({self})")
+    }
+}
+
+impl FromStr for PhysicalAddress {
+    type Err = PhysicalAddressError;
+
+    /// Parses a valid address such as `"1.2.0.0"`, with hexadecimal nibbles
+    fn from_str(s: &str) -> Result<Self, PhysicalAddressError> {
+        let syntax_error = || PhysicalAddressError::Syntax(s.to_owned());
+        let mut nibbles = [0; 4];
+        let mut parts = s.split('.');
+        for nibble in &mut nibbles {
+            let part = parts.next().ok_or_else(syntax_error)?;
+            if part.len() != 1 {
+                return Err(syntax_error());
+            }
+            *nibble = u8::from_str_radix(part, 16).map_err(|_| syntax_error())?;
+        }
+        if parts.next().is_some() {
+            return Err(syntax_error());
+        }
+        PhysicalAddress::from_nibbles(nibbles).validate()
+    }
+}
+
+impl TryFrom<u16> for PhysicalAddress {
+    type Error = PhysicalAddressError;
+
+    fn try_from(raw: u16) -> Result<Self, PhysicalAddressError> {
+        PhysicalAddress(raw).validate()
+    }
+}
+
+impl From<PhysicalAddress> for u16 {
+    fn from(address: PhysicalAddress) -> Self {
+        address.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_logical_address() {
+        assert_eq!(
+            LogicalAddress::from_index(4),
+            Some(LogicalAddress::PlaybackDevice1)
+        );
+        assert_eq!(LogicalAddress::from_index(16), None);
+        assert_eq!(LogicalAddress::Broadcast.index(), Some(15));
+        assert_eq!(LogicalAddress::UNKNOWN.index(), None);
+        assert!(LogicalAddress::UNREGISTERED.is_broadcast());
+        assert_eq!(LogicalAddress::Tuner3.device_type(), DeviceType::Tuner);
+        assert_eq!(LogicalAddress::FreeUse.device_type(), DeviceType::Reserved);
+        for address in LogicalAddress::ALL {
+            let device_type = address.device_type();
+            assert_eq!(
+                device_type.logical_addresses().contains(address),
+                device_type != DeviceType::Reserved
+                    && !(device_type == DeviceType::Tv && *address == LogicalAddress::FreeUse),
+                "{address}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_set() {
+        let mut addresses = LogicalAddresses::new();
+        assert!(addresses.is_empty());
+        assert!(addresses.insert(LogicalAddress::AudioSystem));
+        assert!(!addresses.insert(LogicalAddress::AudioSystem));
+        assert!(addresses.insert(LogicalAddress::Tv));
+        assert!(!addresses.insert(LogicalAddress::UNKNOWN));
+        assert_eq!(addresses.primary(), LogicalAddress::AudioSystem);
+        assert_eq!(addresses.len(), 2);
+        assert_eq!(
+            addresses.iter().collect::<Vec<_>>(),
+            [LogicalAddress::Tv, LogicalAddress::AudioSystem]
+        );
+
+        assert!(addresses.remove(LogicalAddress::AudioSystem));
+        assert!(!addresses.remove(LogicalAddress::AudioSystem));
+        assert_eq!(addresses.primary(), LogicalAddress::UNREGISTERED);
+        assert!(!addresses.contains(LogicalAddress::AudioSystem));
+        assert!(addresses.contains(LogicalAddress::Tv));
+
+        addresses.clear();
+        assert_eq!(addresses, LogicalAddresses::default());
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a: LogicalAddresses = [LogicalAddress::Tv, LogicalAddress::Tuner1]
+            .into_iter()
+            .collect();
+        let b: LogicalAddresses = [LogicalAddress::Tuner1, LogicalAddress::FreeUse]
+            .into_iter()
+            .collect();
+        assert_eq!((a | b).bits(), 0b0100_0000_0000_1001);
+        assert_eq!((a & b).bits(), 0b1000);
+        assert_eq!((a - b).bits(), 0b1);
+        assert_eq!((b | a).primary(), LogicalAddress::Tuner1);
+    }
+
+    #[test]
+    fn test_physical_address_syntax() {
+        let address: PhysicalAddress = "1.2.0.0".parse().unwrap();
+        assert_eq!(address, PhysicalAddress::from_raw(0x1200));
+        assert_eq!(address.to_string(), "1.2.0.0");
+        assert_eq!("f.f.f.e".parse(), Ok(PhysicalAddress::from_raw(0xfffe)));
+        assert_eq!("0.0.0.0".parse(), Ok(PhysicalAddress::TV));
+        for s in ["1.2.0", "1.2.0.0.0", "1.20.0.0", "", "g.0.0.0", "1..0.0"] {
+            assert_eq!(
+                s.parse::<PhysicalAddress>(),
+                Err(PhysicalAddressError::Syntax(s.to_owned()))
+            );
+        }
+        assert_eq!(
+            "1.0.2.0".parse::<PhysicalAddress>(),
+            Err(PhysicalAddressError::Invalid(0x1020))
+        );
+        assert_eq!(
+            PhysicalAddress::try_from(0xffff),
+            Err(PhysicalAddressError::Invalid(0xffff))
+        );
+        assert_eq!(
+            PhysicalAddress::try_from(0x0100),
+            Err(PhysicalAddressError::Invalid(0x0100))
+        );
+    }
+
+    #[test]
+    fn test_physical_address_topology() {
+        let address = PhysicalAddress::from_raw(0x1230);
+        assert_eq!(address.depth(), 3);
+        assert_eq!(address.ports().collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(address.port(), Some(3));
+        assert_eq!(address.parent(), Some(PhysicalAddress::from_raw(0x1200)));
+        assert_eq!(address.child(4), Some(PhysicalAddress::from_raw(0x1234)));
+        assert_eq!(address.child(0), None);
+        assert_eq!(address.child(4).unwrap().child(1), None);
+
+        assert_eq!(PhysicalAddress::TV.depth(), 0);
+        assert_eq!(PhysicalAddress::TV.port(), None);
+        assert_eq!(PhysicalAddress::TV.parent(), None);
+        assert_eq!(
+            PhysicalAddress::TV.child(2),
+            Some(PhysicalAddress::from_raw(0x2000))
+        );
+
+        assert!(PhysicalAddress::TV.is_upstream_of(address));
+        assert!(PhysicalAddress::from_raw(0x1000).is_upstream_of(address));
+        assert!(address.is_downstream_of(PhysicalAddress::from_raw(0x1200)));
+        assert!(!address.is_upstream_of(address));
+        assert!(!PhysicalAddress::from_raw(0x2000).is_upstream_of(address));
+        assert!(!PhysicalAddress::from_raw(0x1300).is_upstream_of(address));
+    }
+
+    #[test]
+    fn test_c_conversion() {
+        let mut raw = cec_logical_addresses {
+            primary: LogicalAddress::PlaybackDevice2.raw(),
+            addresses: [0; 16],
+        };
+        raw.addresses[8] = 1;
+        raw.addresses[15] = 1;
+        let addresses = LogicalAddresses::from(raw);
+        assert_eq!(addresses.primary(), LogicalAddress::PlaybackDevice2);
+        assert_eq!(
+            addresses.iter().collect::<Vec<_>>(),
+            [LogicalAddress::PlaybackDevice2, LogicalAddress::Broadcast]
+        );
+        let back = cec_logical_addresses::from(addresses);
+        assert_eq!(back.addresses, raw.addresses);
+        assert_eq!(
+            LogicalAddress::from_raw(back.primary),
+            LogicalAddress::PlaybackDevice2
+        );
+
+        // unknown primary addresses are kept
+        raw.primary = LogicalAddress::UNKNOWN.raw();
+        let back = cec_logical_addresses::from(LogicalAddresses::from(raw));
+        assert_eq!(
+            LogicalAddress::from_raw(back.primary),
+            LogicalAddress::UNKNOWN
+        );
+    }
+}
@@ -0,0 +1,318 @@
+//! Async wrapper around a libcec connection
+//!
+//! The queries of libcec block until the device answers or libcec gives up. [`AsyncConnection`]
+//! runs them on a dedicated worker thread, so that they can be awaited from any executor. The
+//! events of libcec are available as a `Stream` with [`crate::events::EventReceiver`].
+//!
+//! Timeouts are cancellation safe: when a timeout expires, or the future is dropped, the
+//! operation still completes on the worker thread and its result is discarded. Later
+//! operations wait for it.
+//!
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use futures::StreamExt;
+//! use libcec_sys::asynchronous::AsyncConnection;
+//! use libcec_sys::config::ConfigurationBuilder;
+//! use libcec_sys::enums::LogicalAddress;
+//! use libcec_sys::events::{self, OverflowPolicy};
+//! use libcec_sys::safe::Connection;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let (sender, mut receiver) = events::channel(64, OverflowPolicy::DropOldest);
+//! let configuration = ConfigurationBuilder::new().device_name("example").build()?;
+//! let mut connection = Connection::init_with_handler(configuration, sender)?;
+//! connection.open("RPI", Duration::from_secs(5))?;
+//!
+//! let connection = AsyncConnection::new(connection)?.with_timeout(Duration::from_secs(2));
+//! let status = connection.device_power_status(LogicalAddress::Tv).await?;
+//! println!("TV is {status}");
+//! while let Some(event) = receiver.next().await {
+//!     println!("{event:?}");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use std::future::{self, Future};
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::{mpsc, Arc};
+use std::task::Poll;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use futures_channel::oneshot;
+use futures_timer::Delay;
+
+use crate::address::{LogicalAddresses, PhysicalAddress};
+use crate::enums::{CecVersion, DeviceType, LogicalAddress, PowerStatus};
+use crate::safe::{Connection, Error, Result};
+use crate::*;
+
+type Job<T> = Box<dyn FnOnce(&T) + Send>;
+
+/// Thread running jobs on a shared value, one at a time
+struct Worker<T> {
+    value: Arc<T>,
+    jobs: Option<mpsc::Sender<Job<T>>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl<T: Send + Sync + 'static> Worker<T> {
+    fn new(value: T) -> io::Result<Self> {
+        let value = Arc::new(value);
+        let (jobs, job_receiver) = mpsc::channel::<Job<T>>();
+        let worker_value = Arc::clone(&value);
+        let thread = thread::Builder::new()
+            .name("libcec-worker".to_owned())
+            .spawn(move || {
+                for job in job_receiver {
+                    // a panicking job drops its result sender, failing only that operation
+                    let _ = panic::catch_unwind(AssertUnwindSafe(|| job(&worker_value)));
+                }
+            })?;
+        Ok(Worker {
+            value,
+            jobs: Some(jobs),
+            thread: Some(thread),
+        })
+    }
+
+    async fn run<R, F>(&self, timeout: Option<Duration>, f: F) -> Result<R>
+    where
+        R: Send + 'static,
+        F: FnOnce(&T) -> R + Send + 'static,
+    {
+        let (result_sender, mut result) = oneshot::channel();
+        let job: Job<T> = Box::new(move |value| {
+            // the receiver is gone when the caller timed out or gave up
+            let _ = result_sender.send(f(value));
+        });
+        self.jobs
+            .as_ref()
+            .and_then(|jobs| jobs.send(job).ok())
+            .ok_or(Error::WorkerStopped)?;
+        let mut delay = timeout.map(Delay::new);
+        future::poll_fn(|cx| {
+            if let Poll::Ready(result) = Pin::new(&mut result).poll(cx) {
+                return Poll::Ready(result.map_err(|_| Error::WorkerStopped));
+            }
+            if let Some(delay) = &mut delay {
+                if Pin::new(delay).poll(cx).is_ready() {
+                    return Poll::Ready(Err(Error::Timeout));
+                }
+            }
+            Poll::Pending
+        })
+        .await
+    }
+}
+
+impl<T> Drop for Worker<T> {
+    fn drop(&mut self) {
+        drop(self.jobs.take());
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Connection running blocking operations on a dedicated worker thread
+///
+/// Dropping it waits for the running operation, then closes and destroys the connection.
+pub struct AsyncConnection {
+    worker: Worker<Connection>,
+    timeout: Option<Duration>,
+}
+
+impl AsyncConnection {
+    /// Take over `connection`, which is normally already open
+    ///
+    /// Fails with [`Error::SpawnFailed`] when the worker thread cannot be started.
+    pub fn new(connection: Connection) -> Result<Self> {
+        Ok(AsyncConnection {
+            worker: Worker::new(connection).map_err(|err| Error::SpawnFailed(err.kind()))?,
+            timeout: None,
+        })
+    }
+
+    /// Fail operations with [`Error::Timeout`] when they take longer than `timeout`
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// The connection, for operations which do not block
+    pub fn connection(&self) -> &Connection {
+        &self.worker.value
+    }
+
+    /// Run `f` on the worker thread
+    pub async fn run<R, F>(&self, f: F) -> Result<R>
+    where
+        R: Send + 'static,
+        F: FnOnce(&Connection) -> R + Send + 'static,
+    {
+        self.worker.run(self.timeout, f).await
+    }
+
+    pub async fn transmit(&self, command: cec_command) -> Result<()> {
+        self.run(move |connection| connection.transmit(&command))
+            .await?
+    }
+
+    pub async fn poll_device(&self, address: LogicalAddress) -> Result<()> {
+        self.run(move |connection| connection.poll_device(address))
+            .await?
+    }
+
+    pub async fn power_on_devices(&self, address: LogicalAddress) -> Result<()> {
+        self.run(move |connection| connection.power_on_devices(address))
+            .await?
+    }
+
+    pub async fn standby_devices(&self, address: LogicalAddress) -> Result<()> {
+        self.run(move |connection| connection.standby_devices(address))
+            .await?
+    }
+
+    pub async fn set_active_source(&self, device_type: DeviceType) -> Result<()> {
+        self.run(move |connection| connection.set_active_source(device_type))
+            .await?
+    }
+
+    pub async fn device_power_status(&self, address: LogicalAddress) -> Result<PowerStatus> {
+        self.run(move |connection| connection.device_power_status(address))
+            .await
+    }
+
+    pub async fn device_cec_version(&self, address: LogicalAddress) -> Result<CecVersion> {
+        self.run(move |connection| connection.device_cec_version(address))
+            .await
+    }
+
+    pub async fn device_vendor_id(&self, address: LogicalAddress) -> Result<u32> {
+        self.run(move |connection| connection.device_vendor_id(address))
+            .await
+    }
+
+    pub async fn device_physical_address(
+        &self,
+        address: LogicalAddress,
+    ) -> Result<PhysicalAddress> {
+        self.run(move |connection| connection.device_physical_address(address))
+            .await
+    }
+
+    pub async fn device_menu_language(&self, address: LogicalAddress) -> Result<String> {
+        self.run(move |connection| connection.device_menu_language(address))
+            .await?
+    }
+
+    pub async fn device_osd_name(&self, address: LogicalAddress) -> Result<String> {
+        self.run(move |connection| connection.device_osd_name(address))
+            .await?
+    }
+
+    pub async fn active_source(&self) -> Result<LogicalAddress> {
+        self.run(|connection| connection.active_source()).await
+    }
+
+    pub async fn active_devices(&self) -> Result<LogicalAddresses> {
+        self.run(|connection| connection.active_devices()).await
+    }
+
+    pub async fn rescan_devices(&self) -> Result<()> {
+        self.run(|connection| connection.rescan_devices()).await
+    }
+}
+
+impl std::fmt::Debug for AsyncConnection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncConnection")
+            .field("connection", self.connection())
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use futures::executor::block_on;
+    use futures::StreamExt;
+
+    use super::*;
+    use crate::enums::UserControlCode;
+    use crate::events::{self, CecEvent, OverflowPolicy};
+    use crate::handler::{CecHandler, KeyPress};
+
+    #[test]
+    fn test_run() {
+        let worker = Worker::new(AtomicU32::new(0)).unwrap();
+        let thread = block_on(worker.run(None, |_| thread::current().id())).unwrap();
+        assert_ne!(thread, thread::current().id());
+        let value = block_on(worker.run(None, |value: &AtomicU32| {
+            value.fetch_add(1, Ordering::SeqCst) + 1
+        }));
+        assert_eq!(value, Ok(1));
+    }
+
+    #[test]
+    fn test_timeout() {
+        let worker = Worker::new(AtomicU32::new(0)).unwrap();
+        let slow = worker.run(Some(Duration::from_millis(10)), |value: &AtomicU32| {
+            thread::sleep(Duration::from_millis(200));
+            value.store(1, Ordering::SeqCst);
+        });
+        assert_eq!(block_on(slow), Err(Error::Timeout));
+        // the timed out operation still completes, before the next one
+        let value = block_on(worker.run(None, |value: &AtomicU32| value.load(Ordering::SeqCst)));
+        assert_eq!(value, Ok(1));
+    }
+
+    #[test]
+    fn test_cancelled() {
+        let worker = Worker::new(AtomicU32::new(0)).unwrap();
+        drop(worker.run(None, |value: &AtomicU32| value.store(1, Ordering::SeqCst)));
+        let value = block_on(worker.run(None, |value: &AtomicU32| value.load(Ordering::SeqCst)));
+        // never sent, as the future was not polled
+        assert_eq!(value, Ok(0));
+    }
+
+    #[test]
+    fn test_panic() {
+        let worker = Worker::new(()).unwrap();
+        assert_eq!(
+            block_on(worker.run(None, |_| panic!("job"))),
+            Err::<(), _>(Error::WorkerStopped)
+        );
+        assert_eq!(block_on(worker.run(None, |_| 1)), Ok(1));
+    }
+
+    #[test]
+    fn test_event_stream() {
+        let (sender, receiver) = events::channel(8, OverflowPolicy::Block);
+        let producer = thread::spawn(move || {
+            for _ in 0..3 {
+                thread::sleep(Duration::from_millis(5));
+                sender.key_press(KeyPress {
+                    code: UserControlCode::Play,
+                    duration: Duration::ZERO,
+                });
+            }
+        });
+        let events: Vec<CecEvent> = block_on(receiver.collect());
+        producer.join().unwrap();
+        assert_eq!(events.len(), 3);
+        assert!(matches!(
+            events[0],
+            CecEvent::KeyPress(KeyPress {
+                code: UserControlCode::Play,
+                ..
+            })
+        ));
+    }
+}
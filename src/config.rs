@@ -0,0 +1,495 @@
+//! Validated construction of [`libcec_configuration`]
+//!
+//! The derived `Default` of `libcec_configuration` zero-fills it, whereas
+//! [`default_configuration`] has the same defaults as `libcec_clear_configuration`.
+//! [`ConfigurationBuilder`] starts from these and checks values against the limits of libcec.
+//!
+//! ```
+//! use libcec_sys::config::ConfigurationBuilder;
+//! use libcec_sys::enums::DeviceType;
+//!
+//! let configuration = ConfigurationBuilder::new()
+//!     .device_name("Kodi")
+//!     .device_types(&[DeviceType::PlaybackDevice])
+//!     .hdmi_port(2)
+//!     .build()
+//!     .unwrap();
+//! assert_eq!(configuration.iHDMIPort, 2);
+//!
+//! assert!(ConfigurationBuilder::new().hdmi_port(16).build().is_err());
+//! ```
+use std::error::Error;
+use std::fmt;
+use std::os::raw::{c_char, c_int};
+use std::time::Duration;
+
+use crate::address::{LogicalAddresses, PhysicalAddress};
+use crate::enums::{
+    AdapterType, CecVersion, DeviceType, LogicalAddress, UserControlCode, VendorId,
+};
+use crate::strings::{self, ConfigurationExt, StringError};
+use crate::*;
+
+#[cfg(not(feature = "newtype-enums"))]
+const LIBCEC_VERSION_CURRENT: u32 = libcec_version_CURRENT;
+#[cfg(feature = "newtype-enums")]
+const LIBCEC_VERSION_CURRENT: u32 = libcec_version::CURRENT.0;
+
+/// Maximum number of device types of a configuration
+pub const MAX_DEVICE_TYPES: usize = 5;
+
+/// Invalid [`ConfigurationBuilder`] value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigurationError {
+    /// Device name does not fit `strDeviceName`, or contains NUL
+    InvalidDeviceName(StringError),
+    /// Device language is not a 3 letter ISO 639-2 code
+    InvalidDeviceLanguage(String),
+    /// More than [`MAX_DEVICE_TYPES`] device types
+    TooManyDeviceTypes(usize),
+    /// HDMI port outside `CEC_MIN_HDMI_PORTNUMBER..=CEC_MAX_HDMI_PORTNUMBER`
+    InvalidHdmiPort(u8),
+    /// Physical address which is not valid, see [`PhysicalAddress::is_valid`], and not 0 for
+    /// autodetection
+    InvalidPhysicalAddress(u16),
+    /// Vendor id outside `CEC_MIN_VENDORID..=CEC_MAX_VENDORID`, and not 0 for autodetection
+    InvalidVendorId(u32),
+    /// Field which does not exist in the libcec ABI in use
+    UnsupportedField(&'static str),
+}
+
+impl fmt::Display for ConfigurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigurationError::InvalidDeviceName(err) => write!(f, "invalid device name: {err}"),
+            ConfigurationError::InvalidDeviceLanguage(language) => {
+                write!(
+                    f,
+                    "invalid device language `{language}`, expected ISO 639-2 code"
+                )
+            }
+            ConfigurationError::TooManyDeviceTypes(count) => write!(
+                f,
+                "{count} device types given, at most {MAX_DEVICE_TYPES} allowed"
+            ),
+            ConfigurationError::InvalidHdmiPort(port) => write!(
+                f,
+                "invalid HDMI port {port}, expected {CEC_MIN_HDMI_PORTNUMBER} to \
+                 {CEC_MAX_HDMI_PORTNUMBER}"
+            ),
+            ConfigurationError::InvalidPhysicalAddress(address) => {
+                write!(f, "invalid physical address {address:#06x}")
+            }
+            ConfigurationError::InvalidVendorId(vendor) => {
+                write!(f, "invalid vendor id {vendor:#08x}")
+            }
+            ConfigurationError::UnsupportedField(field) => write!(
+                f,
+                "{field} is not supported by libcec {CEC_LIB_VERSION_MAJOR}"
+            ),
+        }
+    }
+}
+
+impl Error for ConfigurationError {}
+
+fn millis(duration: Duration) -> u32 {
+    duration.as_millis().try_into().unwrap_or(u32::MAX)
+}
+
+/// Configuration with the defaults of `libcec_clear_configuration`
+pub fn default_configuration() -> libcec_configuration {
+    let mut configuration = libcec_configuration {
+        clientVersion: LIBCEC_VERSION_CURRENT,
+        serverVersion: LIBCEC_VERSION_CURRENT,
+        deviceTypes: cec_device_type_list {
+            types: [DeviceType::Reserved.raw(); MAX_DEVICE_TYPES],
+        },
+        bAutodetectAddress: CEC_DEFAULT_SETTING_AUTODETECT_ADDRESS as u8,
+        iPhysicalAddress: CEC_PHYSICAL_ADDRESS_TV as u16,
+        baseDevice: c_enum!(cec_logical_address, CEC_DEFAULT_BASE_DEVICE as c_int),
+        iHDMIPort: CEC_DEFAULT_HDMI_PORT as u8,
+        tvVendor: c_enum_value!(VendorId::UNKNOWN.raw()),
+        bGetSettingsFromROM: CEC_DEFAULT_SETTING_GET_SETTINGS_FROM_ROM as u8,
+        bActivateSource: CEC_DEFAULT_SETTING_ACTIVATE_SOURCE as u8,
+        bPowerOffOnStandby: CEC_DEFAULT_SETTING_POWER_OFF_ON_STANDBY as u8,
+        iFirmwareVersion: CEC_FW_VERSION_UNKNOWN as u16,
+        iFirmwareBuildDate: CEC_FW_BUILD_UNKNOWN,
+        cecVersion: c_enum!(cec_version, CEC_DEFAULT_SETTING_CEC_VERSION),
+        adapterType: AdapterType::UNKNOWN.raw(),
+        comboKey: if cfg!(abi4) {
+            UserControlCode::F1Blue.raw()
+        } else {
+            UserControlCode::Stop.raw()
+        },
+        iComboKeyTimeoutMs: CEC_DEFAULT_COMBO_TIMEOUT_MS,
+        iButtonReleaseDelayMs: CEC_BUTTON_TIMEOUT,
+        iDoubleTapTimeoutMs: CEC_DOUBLE_TAP_TIMEOUT_MS,
+        ..Default::default()
+    };
+    for (c, &b) in configuration
+        .strDeviceLanguage
+        .iter_mut()
+        .zip(CEC_DEFAULT_DEVICE_LANGUAGE)
+    {
+        *c = b as c_char;
+    }
+    let mut wake_devices = LogicalAddresses::new();
+    if CEC_DEFAULT_SETTING_ACTIVATE_SOURCE == 1 {
+        wake_devices.insert(LogicalAddress::Tv);
+    }
+    let mut power_off_devices = LogicalAddresses::new();
+    if CEC_DEFAULT_SETTING_POWER_OFF_SHUTDOWN == 1 {
+        power_off_devices.insert(LogicalAddress::Broadcast);
+    }
+    configuration.logicalAddresses = LogicalAddresses::new().into();
+    configuration.wakeDevices = wake_devices.into();
+    configuration.powerOffDevices = power_off_devices.into();
+    configuration
+}
+
+/// Builder of a validated [`libcec_configuration`], starting from [`default_configuration`]
+///
+/// Values are validated by [`ConfigurationBuilder::build`], which reports the first invalid
+/// one.
+#[derive(Debug, Clone)]
+pub struct ConfigurationBuilder {
+    configuration: libcec_configuration,
+    device_name: Option<String>,
+    device_language: Option<String>,
+    device_type_count: usize,
+    auto_power_on: Option<bool>,
+}
+
+impl Default for ConfigurationBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConfigurationBuilder {
+    pub fn new() -> Self {
+        ConfigurationBuilder {
+            configuration: default_configuration(),
+            device_name: None,
+            device_language: None,
+            device_type_count: 0,
+            auto_power_on: None,
+        }
+    }
+
+    /// Device name to use on the CEC bus, at most
+    /// [`ConfigurationExt::DEVICE_NAME_MAX_LEN`] bytes
+    pub fn device_name(mut self, name: &str) -> Self {
+        self.device_name = Some(name.to_owned());
+        self
+    }
+
+    /// Device types to use on the CEC bus, at most [`MAX_DEVICE_TYPES`]
+    pub fn device_types(mut self, types: &[DeviceType]) -> Self {
+        self.device_type_count = types.len();
+        let device_types = &mut self.configuration.deviceTypes.types;
+        *device_types = [DeviceType::Reserved.raw(); MAX_DEVICE_TYPES];
+        for (slot, device_type) in device_types.iter_mut().zip(types) {
+            *slot = device_type.raw();
+        }
+        self
+    }
+
+    /// Physical address of the adapter, [`PhysicalAddress::TV`] to autodetect
+    pub fn physical_address(mut self, physical_address: PhysicalAddress) -> Self {
+        self.configuration.iPhysicalAddress = physical_address.raw();
+        self
+    }
+
+    /// Device the adapter is connected to, when the physical address is not autodetected
+    pub fn base_device(mut self, base_device: LogicalAddress) -> Self {
+        self.configuration.baseDevice = base_device.raw();
+        self
+    }
+
+    /// HDMI port the adapter is connected to, when the physical address is not autodetected
+    pub fn hdmi_port(mut self, port: u8) -> Self {
+        self.configuration.iHDMIPort = port;
+        self
+    }
+
+    /// Vendor id of the TV, 0 to autodetect
+    pub fn tv_vendor(mut self, vendor: u32) -> Self {
+        self.configuration.tvVendor = vendor;
+        self
+    }
+
+    /// Devices to power on when libcec starts
+    pub fn wake_devices(mut self, addresses: &[LogicalAddress]) -> Self {
+        self.configuration.wakeDevices = addresses
+            .iter()
+            .copied()
+            .collect::<LogicalAddresses>()
+            .into();
+        self
+    }
+
+    /// Devices to put in standby when libcec is told to
+    pub fn power_off_devices(mut self, addresses: &[LogicalAddress]) -> Self {
+        self.configuration.powerOffDevices = addresses
+            .iter()
+            .copied()
+            .collect::<LogicalAddresses>()
+            .into();
+        self
+    }
+
+    pub fn get_settings_from_rom(mut self, enable: bool) -> Self {
+        self.configuration.bGetSettingsFromROM = enable.into();
+        self
+    }
+
+    /// Make libcec the active source when starting
+    pub fn activate_source(mut self, enable: bool) -> Self {
+        self.configuration.bActivateSource = enable.into();
+        self
+    }
+
+    /// Put this device in standby when the TV is switched off
+    pub fn power_off_on_standby(mut self, enable: bool) -> Self {
+        self.configuration.bPowerOffOnStandby = enable.into();
+        self
+    }
+
+    /// Menu language, a 3 letter ISO 639-2 code such as `"eng"`
+    pub fn device_language(mut self, language: &str) -> Self {
+        self.device_language = Some(language.to_owned());
+        self
+    }
+
+    /// Connect without registering a client, only monitoring the bus
+    pub fn monitor_only(mut self, enable: bool) -> Self {
+        self.configuration.bMonitorOnly = enable.into();
+        self
+    }
+
+    pub fn cec_version(mut self, version: CecVersion) -> Self {
+        self.configuration.cecVersion = version.raw();
+        self
+    }
+
+    /// Key which starts combo keys, [`UserControlCode::UNKNOWN`] to disable
+    pub fn combo_key(mut self, key: UserControlCode) -> Self {
+        self.configuration.comboKey = key.raw();
+        self
+    }
+
+    pub fn combo_key_timeout(mut self, timeout: Duration) -> Self {
+        self.configuration.iComboKeyTimeoutMs = millis(timeout);
+        self
+    }
+
+    /// Rate of repeated key presses, zero to rely on the CEC device
+    pub fn button_repeat_rate(mut self, rate: Duration) -> Self {
+        self.configuration.iButtonRepeatRateMs = millis(rate);
+        self
+    }
+
+    pub fn button_release_delay(mut self, delay: Duration) -> Self {
+        self.configuration.iButtonReleaseDelayMs = millis(delay);
+        self
+    }
+
+    pub fn double_tap_timeout(mut self, timeout: Duration) -> Self {
+        self.configuration.iDoubleTapTimeoutMs = millis(timeout);
+        self
+    }
+
+    /// Wake an AVR when the source is activated
+    pub fn auto_wake_avr(mut self, enable: bool) -> Self {
+        self.configuration.bAutoWakeAVR = enable.into();
+        self
+    }
+
+    /// Wake the TV when the adapter is powered, `bAutoPowerOn`. Not supported by libcec 4.
+    pub fn auto_power_on(mut self, enable: bool) -> Self {
+        self.auto_power_on = Some(enable);
+        self
+    }
+
+    pub fn build(self) -> Result<libcec_configuration, ConfigurationError> {
+        let mut configuration = self.configuration;
+        if let Some(name) = &self.device_name {
+            configuration
+                .set_device_name(name)
+                .map_err(ConfigurationError::InvalidDeviceName)?;
+        }
+        if let Some(language) = &self.device_language {
+            if language.len() != libcec_configuration::DEVICE_LANGUAGE_LEN
+                || !language.bytes().all(|b| b.is_ascii_lowercase())
+            {
+                return Err(ConfigurationError::InvalidDeviceLanguage(language.clone()));
+            }
+            strings::set_str(
+                &mut configuration.strDeviceLanguage,
+                language,
+                libcec_configuration::DEVICE_LANGUAGE_LEN,
+            )
+            .expect("length checked");
+        }
+        if self.device_type_count > MAX_DEVICE_TYPES {
+            return Err(ConfigurationError::TooManyDeviceTypes(
+                self.device_type_count,
+            ));
+        }
+        let port = u32::from(configuration.iHDMIPort);
+        if !(CEC_MIN_HDMI_PORTNUMBER..=CEC_MAX_HDMI_PORTNUMBER).contains(&port) {
+            return Err(ConfigurationError::InvalidHdmiPort(configuration.iHDMIPort));
+        }
+        if !PhysicalAddress::from_raw(configuration.iPhysicalAddress).is_valid() {
+            return Err(ConfigurationError::InvalidPhysicalAddress(
+                configuration.iPhysicalAddress,
+            ));
+        }
+        let vendor = configuration.tvVendor;
+        if vendor != 0 && !(CEC_MIN_VENDORID..=CEC_MAX_VENDORID).contains(&vendor) {
+            return Err(ConfigurationError::InvalidVendorId(vendor));
+        }
+        if let Some(auto_power_on) = self.auto_power_on {
+            cfg_if::cfg_if! {
+                if #[cfg(abi4)] {
+                    let _ = auto_power_on;
+                    return Err(ConfigurationError::UnsupportedField("bAutoPowerOn"));
+                } else {
+                    configuration.bAutoPowerOn = auto_power_on.into();
+                }
+            }
+        }
+        configuration.clientVersion = LIBCEC_VERSION_CURRENT;
+        Ok(configuration)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults() {
+        let configuration = ConfigurationBuilder::new().build().unwrap();
+        assert_eq!(configuration.clientVersion, LIBCEC_VERSION_CURRENT);
+        assert_eq!(configuration.clientVersion >> 16, CEC_LIB_VERSION_MAJOR);
+        assert_eq!(configuration.iHDMIPort, 1);
+        assert_eq!(configuration.iDoubleTapTimeoutMs, 200);
+        assert_eq!(configuration.device_language(), Ok("eng"));
+        assert_eq!(configuration.device_name(), Ok(""));
+        assert_eq!(
+            CecVersion::from_raw(configuration.cecVersion),
+            CecVersion::V1_4
+        );
+        assert_eq!(
+            DeviceType::from_raw(configuration.deviceTypes.types[0]),
+            DeviceType::Reserved
+        );
+        assert_eq!(configuration.wakeDevices.addresses[0], 1);
+        assert_eq!(
+            LogicalAddress::from_raw(configuration.wakeDevices.primary),
+            LogicalAddress::Tv
+        );
+        assert_eq!(configuration.powerOffDevices.addresses[15], 1);
+        assert!(configuration.callbacks.is_null());
+    }
+
+    #[test]
+    fn test_build() {
+        let configuration = ConfigurationBuilder::new()
+            .device_name("Kodi")
+            .device_language("fin")
+            .device_types(&[DeviceType::PlaybackDevice, DeviceType::RecordingDevice])
+            .physical_address(PhysicalAddress::from_raw(0x1000))
+            .base_device(LogicalAddress::AudioSystem)
+            .hdmi_port(3)
+            .wake_devices(&[])
+            .combo_key_timeout(Duration::from_millis(500))
+            .build()
+            .unwrap();
+        assert_eq!(configuration.device_name(), Ok("Kodi"));
+        assert_eq!(configuration.device_language(), Ok("fin"));
+        assert_eq!(
+            DeviceType::from_raw(configuration.deviceTypes.types[1]),
+            DeviceType::RecordingDevice
+        );
+        assert_eq!(
+            DeviceType::from_raw(configuration.deviceTypes.types[2]),
+            DeviceType::Reserved
+        );
+        assert_eq!(configuration.iPhysicalAddress, 0x1000);
+        assert_eq!(
+            LogicalAddress::from_raw(configuration.baseDevice),
+            LogicalAddress::AudioSystem
+        );
+        assert_eq!(configuration.iHDMIPort, 3);
+        assert_eq!(configuration.wakeDevices.addresses, [0; 16]);
+        assert_eq!(configuration.iComboKeyTimeoutMs, 500);
+    }
+
+    #[test]
+    fn test_validation() {
+        let err = |builder: ConfigurationBuilder| builder.build().unwrap_err();
+        let builder = ConfigurationBuilder::new;
+
+        assert!(matches!(
+            err(builder().device_name("a device name too long")),
+            ConfigurationError::InvalidDeviceName(StringError::TooLong { .. })
+        ));
+        assert!(matches!(
+            err(builder().device_name("Ko\0di")),
+            ConfigurationError::InvalidDeviceName(StringError::Nul { position: 2 })
+        ));
+        for language in ["en", "engl", "ENG", "e1g"] {
+            assert_eq!(
+                err(builder().device_language(language)),
+                ConfigurationError::InvalidDeviceLanguage(language.to_owned())
+            );
+        }
+        assert_eq!(
+            err(builder().device_types(&[DeviceType::Tv; 6])),
+            ConfigurationError::TooManyDeviceTypes(6)
+        );
+        assert_eq!(
+            err(builder().hdmi_port(0)),
+            ConfigurationError::InvalidHdmiPort(0)
+        );
+        assert_eq!(
+            err(builder().hdmi_port(16)),
+            ConfigurationError::InvalidHdmiPort(16)
+        );
+        assert_eq!(
+            err(builder().physical_address(PhysicalAddress::INVALID)),
+            ConfigurationError::InvalidPhysicalAddress(0xffff)
+        );
+        assert_eq!(
+            err(builder().tv_vendor(0xffffff)),
+            ConfigurationError::InvalidVendorId(0xffffff)
+        );
+        assert!(builder().tv_vendor(0x00f0).build().is_ok());
+    }
+
+    #[cfg(abi4)]
+    #[test]
+    fn test_auto_power_on_abi4() {
+        assert_eq!(
+            ConfigurationBuilder::new()
+                .auto_power_on(true)
+                .build()
+                .unwrap_err(),
+            ConfigurationError::UnsupportedField("bAutoPowerOn")
+        );
+    }
+
+    #[cfg(not(abi4))]
+    #[test]
+    fn test_auto_power_on() {
+        let configuration = ConfigurationBuilder::new()
+            .auto_power_on(true)
+            .build()
+            .unwrap();
+        assert_eq!(configuration.bAutoPowerOn, 1);
+    }
+}
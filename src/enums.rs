@@ -0,0 +1,311 @@
+//! Rust enums for the libcec C enums
+//!
+//! The enums are generated by `cec_bindgen` from the same headers as the bindings. Their names
+//! match the ones returned by the `libcec_*_to_string` functions, but `Display`, `FromStr` and
+//! [`Opcode::name`] etc. are pure Rust and work without an initialized libcec connection.
+//!
+//! Values not known to libcec are kept as `Unknown(raw)`, with the C value, e.g. a
+//! `cec_opcode`, so that converting from and back to the C value never loses information.
+//! `TryFrom` is the strict variant, failing on them. Values compare and hash by their C value,
+//! so an `Unknown` holding a known value equals that value, although [`Opcode::from_raw`]
+//! never returns one.
+//!
+//! ```
+//! use libcec_sys::enums::Opcode;
+//!
+//! assert_eq!(Opcode::ActiveSource.to_string(), "active source");
+//! assert_eq!("Active Source".parse(), Ok(Opcode::ActiveSource));
+//! # #[cfg(not(feature = "newtype-enums"))]
+//! # let raw = 0x01;
+//! # #[cfg(feature = "newtype-enums")]
+//! # let raw = libcec_sys::cec_opcode(0x01);
+//! assert_eq!(Opcode::from_raw(raw), Opcode::Unknown(raw));
+//! assert_eq!(Opcode::from_raw(raw).raw(), raw);
+//! assert!(Opcode::try_from(raw).is_err());
+//! ```
+use std::error::Error;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use crate::*;
+
+// Build a C enum value from an integer literal, and back
+#[cfg(not(feature = "newtype-enums"))]
+macro_rules! c_enum {
+    ($ty:ty, $value:expr) => {
+        $value
+    };
+}
+#[cfg(feature = "newtype-enums")]
+macro_rules! c_enum {
+    ($ty:ident, $value:expr) => {
+        $ty($value)
+    };
+}
+#[cfg(not(feature = "newtype-enums"))]
+macro_rules! c_enum_value {
+    ($raw:expr) => {
+        $raw
+    };
+}
+#[cfg(feature = "newtype-enums")]
+macro_rules! c_enum_value {
+    ($raw:expr) => {
+        $raw.0
+    };
+}
+
+macro_rules! impl_enum_conversions {
+    ($ty:ident, $c_ty:ident) => {
+        impl PartialEq for $ty {
+            fn eq(&self, other: &Self) -> bool {
+                c_enum_value!(self.raw()) == c_enum_value!(other.raw())
+            }
+        }
+
+        impl Eq for $ty {}
+
+        impl Hash for $ty {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                c_enum_value!(self.raw()).hash(state);
+            }
+        }
+
+        impl fmt::Display for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.name())
+            }
+        }
+
+        impl std::str::FromStr for $ty {
+            type Err = ParseEnumError;
+
+            /// Parses the libcec name of the value, ignoring ASCII case
+            fn from_str(s: &str) -> Result<Self, ParseEnumError> {
+                Self::ALL
+                    .iter()
+                    .copied()
+                    .chain(Self::NAMED_UNKNOWN)
+                    .find(|value| value.name().eq_ignore_ascii_case(s))
+                    .ok_or(ParseEnumError {
+                        type_name: stringify!($ty),
+                    })
+            }
+        }
+
+        impl TryFrom<$c_ty> for $ty {
+            type Error = UnknownValueError;
+
+            /// Converts from the C value, failing for values other than [`Self::ALL`]
+            fn try_from(raw: $c_ty) -> Result<Self, UnknownValueError> {
+                match Self::from_raw(raw) {
+                    Self::Unknown(_) => Err(UnknownValueError {
+                        type_name: stringify!($ty),
+                        value: c_enum_value!(raw).into(),
+                    }),
+                    value => Ok(value),
+                }
+            }
+        }
+
+        impl From<$ty> for $c_ty {
+            fn from(value: $ty) -> Self {
+                value.raw()
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl serde_enum::SerdeEnum for $ty {
+            const NAME: &'static str = stringify!($ty);
+            const KNOWN: &'static [Self] = Self::ALL;
+            const NAMED_UNKNOWN: Option<Self> = Self::NAMED_UNKNOWN;
+            const VARIANTS: &'static [&'static str] = Self::VARIANT_NAMES;
+
+            fn to_i64(self) -> i64 {
+                c_enum_value!(self.raw()).into()
+            }
+
+            fn from_i64(value: i64) -> Option<Self> {
+                let raw = value.try_into().ok()?;
+                Some(Self::from_raw(c_enum!($c_ty, raw)))
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $ty {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serde_enum::serialize(*self, serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $ty {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                serde_enum::deserialize(deserializer)
+            }
+        }
+    };
+}
+
+/// serde for the enums, as externally tagged enums: known values by variant name, the C
+/// `UNKNOWN` value as `UNKNOWN` and other values as `Unknown` with the C value, e.g.
+/// `{"Unknown": 200}` in JSON
+#[cfg(feature = "serde")]
+mod serde_enum {
+    use std::fmt;
+    use std::marker::PhantomData;
+
+    use serde::de::{self, DeserializeSeed, EnumAccess, Unexpected, VariantAccess, Visitor};
+    use serde::{Deserializer, Serializer};
+
+    pub trait SerdeEnum: Copy + PartialEq + 'static {
+        const NAME: &'static str;
+        const KNOWN: &'static [Self];
+        const NAMED_UNKNOWN: Option<Self>;
+        /// Names of [`Self::KNOWN`], then `UNKNOWN` with [`Self::NAMED_UNKNOWN`], then `Unknown`
+        const VARIANTS: &'static [&'static str];
+
+        fn to_i64(self) -> i64;
+        fn from_i64(value: i64) -> Option<Self>;
+    }
+
+    pub fn serialize<T: SerdeEnum, S: Serializer>(
+        value: T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let index = match T::KNOWN.iter().position(|known| *known == value) {
+            Some(index) => index,
+            None if Some(value) == T::NAMED_UNKNOWN => T::KNOWN.len(),
+            None => {
+                let index = T::VARIANTS.len() - 1;
+                return serializer.serialize_newtype_variant(
+                    T::NAME,
+                    index as u32,
+                    T::VARIANTS[index],
+                    &value.to_i64(),
+                );
+            }
+        };
+        serializer.serialize_unit_variant(T::NAME, index as u32, T::VARIANTS[index])
+    }
+
+    pub fn deserialize<'de, T: SerdeEnum, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        deserializer.deserialize_enum(T::NAME, T::VARIANTS, EnumVisitor(PhantomData))
+    }
+
+    struct EnumVisitor<T>(PhantomData<T>);
+
+    impl<'de, T: SerdeEnum> Visitor<'de> for EnumVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "enum {}", T::NAME)
+        }
+
+        fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<T, A::Error> {
+            let (index, variant) = data.variant_seed(VariantIndex::<T>(PhantomData))?;
+            if index == T::VARIANTS.len() - 1 {
+                let value: i64 = variant.newtype_variant()?;
+                return T::from_i64(value).ok_or_else(|| {
+                    de::Error::invalid_value(Unexpected::Signed(value), &"a C enum value")
+                });
+            }
+            variant.unit_variant()?;
+            Ok(match T::KNOWN.get(index) {
+                Some(known) => *known,
+                // `UNKNOWN` is only in the variants when there is a C `UNKNOWN` value
+                None => T::NAMED_UNKNOWN.unwrap(),
+            })
+        }
+    }
+
+    /// Index of the variant in [`SerdeEnum::VARIANTS`]
+    struct VariantIndex<T>(PhantomData<T>);
+
+    impl<'de, T: SerdeEnum> DeserializeSeed<'de> for VariantIndex<T> {
+        type Value = usize;
+
+        fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<usize, D::Error> {
+            deserializer.deserialize_identifier(self)
+        }
+    }
+
+    impl<T: SerdeEnum> Visitor<'_> for VariantIndex<T> {
+        type Value = usize;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "variant of enum {}", T::NAME)
+        }
+
+        fn visit_u64<E: de::Error>(self, index: u64) -> Result<usize, E> {
+            usize::try_from(index)
+                .ok()
+                .filter(|&index| index < T::VARIANTS.len())
+                .ok_or_else(|| E::invalid_value(Unexpected::Unsigned(index), &self))
+        }
+
+        fn visit_str<E: de::Error>(self, name: &str) -> Result<usize, E> {
+            T::VARIANTS
+                .iter()
+                .position(|variant| *variant == name)
+                .ok_or_else(|| E::unknown_variant(name, T::VARIANTS))
+        }
+
+        fn visit_bytes<E: de::Error>(self, name: &[u8]) -> Result<usize, E> {
+            match std::str::from_utf8(name) {
+                Ok(name) => self.visit_str(name),
+                Err(_) => Err(E::invalid_value(Unexpected::Bytes(name), &self)),
+            }
+        }
+    }
+}
+
+/// Error parsing an enum from its name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseEnumError {
+    type_name: &'static str,
+}
+
+impl fmt::Display for ParseEnumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown {} name", self.type_name)
+    }
+}
+
+impl Error for ParseEnumError {}
+
+/// Error converting a C value which is not known to libcec
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownValueError {
+    type_name: &'static str,
+    value: i64,
+}
+
+impl UnknownValueError {
+    /// The C value
+    pub fn value(&self) -> i64 {
+        self.value
+    }
+}
+
+impl fmt::Display for UnknownValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown {} value {}", self.type_name, self.value)
+    }
+}
+
+impl Error for UnknownValueError {}
+
+cfg_if::cfg_if! {
+    if #[cfg(abi4)] {
+        include!("enums_abi4.rs");
+    } else if #[cfg(abi5)] {
+        include!("enums_abi5.rs");
+    } else if #[cfg(abi6)] {
+        include!("enums_abi6.rs");
+    } else if #[cfg(abi7)] {
+        include!("enums_abi7.rs");
+    }
+}
@@ -0,0 +1,4092 @@
+/* automatically generated by cec_bindgen, do not edit */
+
+/// `cec_abort_reason`
+#[derive(Debug, Clone, Copy)]
+pub enum AbortReason {
+    /// `cec_abort_reason_UNRECOGNIZED_OPCODE`
+    UnrecognizedOpcode,
+    /// `cec_abort_reason_NOT_IN_CORRECT_MODE_TO_RESPOND`
+    NotInCorrectModeToRespond,
+    /// `cec_abort_reason_CANNOT_PROVIDE_SOURCE`
+    CannotProvideSource,
+    /// `cec_abort_reason_INVALID_OPERAND`
+    InvalidOperand,
+    /// `cec_abort_reason_REFUSED`
+    Refused,
+    /// Value not known to libcec
+    Unknown(cec_abort_reason),
+}
+impl AbortReason {
+    /// All known values
+    pub const ALL: &'static [Self] = &[
+        Self::UnrecognizedOpcode,
+        Self::NotInCorrectModeToRespond,
+        Self::CannotProvideSource,
+        Self::InvalidOperand,
+        Self::Refused,
+    ];
+    const NAMED_UNKNOWN: Option<Self> = None;
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &[
+        "UnrecognizedOpcode",
+        "NotInCorrectModeToRespond",
+        "CannotProvideSource",
+        "InvalidOperand",
+        "Refused",
+        "Unknown",
+    ];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::UnrecognizedOpcode => "unrecognized opcode",
+            Self::NotInCorrectModeToRespond => "not in correct mode to respond",
+            Self::CannotProvideSource => "cannot provide source",
+            Self::InvalidOperand => "invalid operand",
+            Self::Refused => "refused",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_abort_reason) -> Self {
+        match c_enum_value!(raw) {
+            0 => Self::UnrecognizedOpcode,
+            1 => Self::NotInCorrectModeToRespond,
+            2 => Self::CannotProvideSource,
+            3 => Self::InvalidOperand,
+            4 => Self::Refused,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_abort_reason {
+        match self {
+            Self::UnrecognizedOpcode => c_enum!(cec_abort_reason, 0),
+            Self::NotInCorrectModeToRespond => c_enum!(cec_abort_reason, 1),
+            Self::CannotProvideSource => c_enum!(cec_abort_reason, 2),
+            Self::InvalidOperand => c_enum!(cec_abort_reason, 3),
+            Self::Refused => c_enum!(cec_abort_reason, 4),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(AbortReason, cec_abort_reason);
+/// `cec_analogue_broadcast_type`
+#[derive(Debug, Clone, Copy)]
+pub enum AnalogueBroadcastType {
+    /// `cec_analogue_broadcast_type_CABLE`
+    Cable,
+    /// `cec_analogue_broadcast_type_SATELLITE`
+    Satellite,
+    /// `cec_analogue_broadcast_type_TERRESTIAL`
+    Terrestial,
+    /// Value not known to libcec
+    Unknown(cec_analogue_broadcast_type),
+}
+impl AnalogueBroadcastType {
+    /// All known values
+    pub const ALL: &'static [Self] = &[Self::Cable, Self::Satellite, Self::Terrestial];
+    const NAMED_UNKNOWN: Option<Self> = None;
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &["Cable", "Satellite", "Terrestial", "Unknown"];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Cable => "cable",
+            Self::Satellite => "satellite",
+            Self::Terrestial => "terrestial",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_analogue_broadcast_type) -> Self {
+        match c_enum_value!(raw) {
+            0 => Self::Cable,
+            1 => Self::Satellite,
+            2 => Self::Terrestial,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_analogue_broadcast_type {
+        match self {
+            Self::Cable => c_enum!(cec_analogue_broadcast_type, 0),
+            Self::Satellite => c_enum!(cec_analogue_broadcast_type, 1),
+            Self::Terrestial => c_enum!(cec_analogue_broadcast_type, 2),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(AnalogueBroadcastType, cec_analogue_broadcast_type);
+/// `cec_audio_rate`
+#[derive(Debug, Clone, Copy)]
+pub enum AudioRate {
+    /// `cec_audio_rate_RATE_CONTROL_OFF`
+    RateControlOff,
+    /// `cec_audio_rate_STANDARD_RATE_100`
+    StandardRate100,
+    /// `cec_audio_rate_FAST_RATE_MAX_101`
+    FastRateMax101,
+    /// `cec_audio_rate_SLOW_RATE_MIN_99`
+    SlowRateMin99,
+    /// `cec_audio_rate_STANDARD_RATE_100_0`
+    StandardRate100_0,
+    /// `cec_audio_rate_FAST_RATE_MAX_100_1`
+    FastRateMax100_1,
+    /// `cec_audio_rate_SLOW_RATE_MIN_99_9`
+    SlowRateMin99_9,
+    /// Value not known to libcec
+    Unknown(cec_audio_rate),
+}
+impl AudioRate {
+    /// All known values
+    pub const ALL: &'static [Self] = &[
+        Self::RateControlOff,
+        Self::StandardRate100,
+        Self::FastRateMax101,
+        Self::SlowRateMin99,
+        Self::StandardRate100_0,
+        Self::FastRateMax100_1,
+        Self::SlowRateMin99_9,
+    ];
+    const NAMED_UNKNOWN: Option<Self> = None;
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &[
+        "RateControlOff",
+        "StandardRate100",
+        "FastRateMax101",
+        "SlowRateMin99",
+        "StandardRate100_0",
+        "FastRateMax100_1",
+        "SlowRateMin99_9",
+        "Unknown",
+    ];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::RateControlOff => "rate control off",
+            Self::StandardRate100 => "standard rate 100",
+            Self::FastRateMax101 => "fast rate max 101",
+            Self::SlowRateMin99 => "slow rate min 99",
+            Self::StandardRate100_0 => "standard rate 100 0",
+            Self::FastRateMax100_1 => "fast rate max 100 1",
+            Self::SlowRateMin99_9 => "slow rate min 99 9",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_audio_rate) -> Self {
+        match c_enum_value!(raw) {
+            0 => Self::RateControlOff,
+            1 => Self::StandardRate100,
+            2 => Self::FastRateMax101,
+            3 => Self::SlowRateMin99,
+            4 => Self::StandardRate100_0,
+            5 => Self::FastRateMax100_1,
+            6 => Self::SlowRateMin99_9,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_audio_rate {
+        match self {
+            Self::RateControlOff => c_enum!(cec_audio_rate, 0),
+            Self::StandardRate100 => c_enum!(cec_audio_rate, 1),
+            Self::FastRateMax101 => c_enum!(cec_audio_rate, 2),
+            Self::SlowRateMin99 => c_enum!(cec_audio_rate, 3),
+            Self::StandardRate100_0 => c_enum!(cec_audio_rate, 4),
+            Self::FastRateMax100_1 => c_enum!(cec_audio_rate, 5),
+            Self::SlowRateMin99_9 => c_enum!(cec_audio_rate, 6),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(AudioRate, cec_audio_rate);
+/// `cec_boolean`
+#[derive(Debug, Clone, Copy)]
+pub enum Boolean {
+    /// `cec_boolean_CEC_FALSE`
+    False,
+    /// `cec_boolean_CEC_TRUE`
+    True,
+    /// Value not known to libcec
+    Unknown(cec_boolean),
+}
+impl Boolean {
+    /// All known values
+    pub const ALL: &'static [Self] = &[Self::False, Self::True];
+    const NAMED_UNKNOWN: Option<Self> = None;
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &["False", "True", "Unknown"];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::False => "cec false",
+            Self::True => "cec true",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_boolean) -> Self {
+        match c_enum_value!(raw) {
+            0 => Self::False,
+            1 => Self::True,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_boolean {
+        match self {
+            Self::False => c_enum!(cec_boolean, 0),
+            Self::True => c_enum!(cec_boolean, 1),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(Boolean, cec_boolean);
+/// `cec_version`
+#[derive(Debug, Clone, Copy)]
+pub enum CecVersion {
+    /// `cec_version__1_2`
+    V1_2,
+    /// `cec_version__1_2A`
+    V1_2a,
+    /// `cec_version__1_3`
+    V1_3,
+    /// `cec_version__1_3A`
+    V1_3a,
+    /// `cec_version__1_4`
+    V1_4,
+    /// `cec_version__2_0`
+    V2_0,
+    /// Value not known to libcec
+    Unknown(cec_version),
+}
+impl CecVersion {
+    /// `cec_version_UNKNOWN`
+    pub const UNKNOWN: Self = Self::Unknown(c_enum!(cec_version, 0));
+    /// All known values
+    pub const ALL: &'static [Self] = &[
+        Self::V1_2,
+        Self::V1_2a,
+        Self::V1_3,
+        Self::V1_3a,
+        Self::V1_4,
+        Self::V2_0,
+    ];
+    const NAMED_UNKNOWN: Option<Self> = Some(Self::UNKNOWN);
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &[
+        "V1_2", "V1_2a", "V1_3", "V1_3a", "V1_4", "V2_0", "UNKNOWN", "Unknown",
+    ];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::V1_2 => "1.2",
+            Self::V1_2a => "1.2a",
+            Self::V1_3 => "1.3",
+            Self::V1_3a => "1.3a",
+            Self::V1_4 => "1.4",
+            Self::V2_0 => "2.0",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_version) -> Self {
+        match c_enum_value!(raw) {
+            1 => Self::V1_2,
+            2 => Self::V1_2a,
+            3 => Self::V1_3,
+            4 => Self::V1_3a,
+            5 => Self::V1_4,
+            6 => Self::V2_0,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_version {
+        match self {
+            Self::V1_2 => c_enum!(cec_version, 1),
+            Self::V1_2a => c_enum!(cec_version, 2),
+            Self::V1_3 => c_enum!(cec_version, 3),
+            Self::V1_3a => c_enum!(cec_version, 4),
+            Self::V1_4 => c_enum!(cec_version, 5),
+            Self::V2_0 => c_enum!(cec_version, 6),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(CecVersion, cec_version);
+/// `cec_deck_control_mode`
+#[derive(Debug, Clone, Copy)]
+pub enum DeckControlMode {
+    /// `cec_deck_control_mode_SKIP_FORWARD_WIND`
+    SkipForwardWind,
+    /// `cec_deck_control_mode_SKIP_REVERSE_REWIND`
+    SkipReverseRewind,
+    /// `cec_deck_control_mode_STOP`
+    Stop,
+    /// `cec_deck_control_mode_EJECT`
+    Eject,
+    /// Value not known to libcec
+    Unknown(cec_deck_control_mode),
+}
+impl DeckControlMode {
+    /// All known values
+    pub const ALL: &'static [Self] = &[
+        Self::SkipForwardWind,
+        Self::SkipReverseRewind,
+        Self::Stop,
+        Self::Eject,
+    ];
+    const NAMED_UNKNOWN: Option<Self> = None;
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &[
+        "SkipForwardWind",
+        "SkipReverseRewind",
+        "Stop",
+        "Eject",
+        "Unknown",
+    ];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::SkipForwardWind => "skip forward wind",
+            Self::SkipReverseRewind => "reverse rewind",
+            Self::Stop => "stop",
+            Self::Eject => "eject",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_deck_control_mode) -> Self {
+        match c_enum_value!(raw) {
+            1 => Self::SkipForwardWind,
+            2 => Self::SkipReverseRewind,
+            3 => Self::Stop,
+            4 => Self::Eject,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_deck_control_mode {
+        match self {
+            Self::SkipForwardWind => c_enum!(cec_deck_control_mode, 1),
+            Self::SkipReverseRewind => c_enum!(cec_deck_control_mode, 2),
+            Self::Stop => c_enum!(cec_deck_control_mode, 3),
+            Self::Eject => c_enum!(cec_deck_control_mode, 4),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(DeckControlMode, cec_deck_control_mode);
+/// `cec_deck_info`
+#[derive(Debug, Clone, Copy)]
+pub enum DeckInfo {
+    /// `cec_deck_info_PLAY`
+    Play,
+    /// `cec_deck_info_RECORD`
+    Record,
+    /// `cec_deck_info_PLAY_REVERSE`
+    PlayReverse,
+    /// `cec_deck_info_STILL`
+    Still,
+    /// `cec_deck_info_SLOW`
+    Slow,
+    /// `cec_deck_info_SLOW_REVERSE`
+    SlowReverse,
+    /// `cec_deck_info_FAST_FORWARD`
+    FastForward,
+    /// `cec_deck_info_FAST_REVERSE`
+    FastReverse,
+    /// `cec_deck_info_NO_MEDIA`
+    NoMedia,
+    /// `cec_deck_info_STOP`
+    Stop,
+    /// `cec_deck_info_SKIP_FORWARD_WIND`
+    SkipForwardWind,
+    /// `cec_deck_info_SKIP_REVERSE_REWIND`
+    SkipReverseRewind,
+    /// `cec_deck_info_INDEX_SEARCH_FORWARD`
+    IndexSearchForward,
+    /// `cec_deck_info_INDEX_SEARCH_REVERSE`
+    IndexSearchReverse,
+    /// `cec_deck_info_OTHER_STATUS`
+    OtherStatus,
+    /// `cec_deck_info_OTHER_STATUS_LG`
+    OtherStatusLg,
+    /// Value not known to libcec
+    Unknown(cec_deck_info),
+}
+impl DeckInfo {
+    /// All known values
+    pub const ALL: &'static [Self] = &[
+        Self::Play,
+        Self::Record,
+        Self::PlayReverse,
+        Self::Still,
+        Self::Slow,
+        Self::SlowReverse,
+        Self::FastForward,
+        Self::FastReverse,
+        Self::NoMedia,
+        Self::Stop,
+        Self::SkipForwardWind,
+        Self::SkipReverseRewind,
+        Self::IndexSearchForward,
+        Self::IndexSearchReverse,
+        Self::OtherStatus,
+        Self::OtherStatusLg,
+    ];
+    const NAMED_UNKNOWN: Option<Self> = None;
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &[
+        "Play",
+        "Record",
+        "PlayReverse",
+        "Still",
+        "Slow",
+        "SlowReverse",
+        "FastForward",
+        "FastReverse",
+        "NoMedia",
+        "Stop",
+        "SkipForwardWind",
+        "SkipReverseRewind",
+        "IndexSearchForward",
+        "IndexSearchReverse",
+        "OtherStatus",
+        "OtherStatusLg",
+        "Unknown",
+    ];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Play => "play",
+            Self::Record => "record",
+            Self::PlayReverse => "play reverse",
+            Self::Still => "still",
+            Self::Slow => "slow",
+            Self::SlowReverse => "slow reverse",
+            Self::FastForward => "fast forward",
+            Self::FastReverse => "fast reverse",
+            Self::NoMedia => "no media",
+            Self::Stop => "stop",
+            Self::SkipForwardWind => "info skip forward wind",
+            Self::SkipReverseRewind => "info skip reverse rewind",
+            Self::IndexSearchForward => "info index search forward",
+            Self::IndexSearchReverse => "info index search reverse",
+            Self::OtherStatus => "other",
+            Self::OtherStatusLg => "LG other",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_deck_info) -> Self {
+        match c_enum_value!(raw) {
+            17 => Self::Play,
+            18 => Self::Record,
+            19 => Self::PlayReverse,
+            20 => Self::Still,
+            21 => Self::Slow,
+            22 => Self::SlowReverse,
+            23 => Self::FastForward,
+            24 => Self::FastReverse,
+            25 => Self::NoMedia,
+            26 => Self::Stop,
+            27 => Self::SkipForwardWind,
+            28 => Self::SkipReverseRewind,
+            29 => Self::IndexSearchForward,
+            30 => Self::IndexSearchReverse,
+            31 => Self::OtherStatus,
+            32 => Self::OtherStatusLg,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_deck_info {
+        match self {
+            Self::Play => c_enum!(cec_deck_info, 17),
+            Self::Record => c_enum!(cec_deck_info, 18),
+            Self::PlayReverse => c_enum!(cec_deck_info, 19),
+            Self::Still => c_enum!(cec_deck_info, 20),
+            Self::Slow => c_enum!(cec_deck_info, 21),
+            Self::SlowReverse => c_enum!(cec_deck_info, 22),
+            Self::FastForward => c_enum!(cec_deck_info, 23),
+            Self::FastReverse => c_enum!(cec_deck_info, 24),
+            Self::NoMedia => c_enum!(cec_deck_info, 25),
+            Self::Stop => c_enum!(cec_deck_info, 26),
+            Self::SkipForwardWind => c_enum!(cec_deck_info, 27),
+            Self::SkipReverseRewind => c_enum!(cec_deck_info, 28),
+            Self::IndexSearchForward => c_enum!(cec_deck_info, 29),
+            Self::IndexSearchReverse => c_enum!(cec_deck_info, 30),
+            Self::OtherStatus => c_enum!(cec_deck_info, 31),
+            Self::OtherStatusLg => c_enum!(cec_deck_info, 32),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(DeckInfo, cec_deck_info);
+/// `cec_device_type`
+#[derive(Debug, Clone, Copy)]
+pub enum DeviceType {
+    /// `cec_device_type_TV`
+    Tv,
+    /// `cec_device_type_RECORDING_DEVICE`
+    RecordingDevice,
+    /// `cec_device_type_RESERVED`
+    Reserved,
+    /// `cec_device_type_TUNER`
+    Tuner,
+    /// `cec_device_type_PLAYBACK_DEVICE`
+    PlaybackDevice,
+    /// `cec_device_type_AUDIO_SYSTEM`
+    AudioSystem,
+    /// Value not known to libcec
+    Unknown(cec_device_type),
+}
+impl DeviceType {
+    /// All known values
+    pub const ALL: &'static [Self] = &[
+        Self::Tv,
+        Self::RecordingDevice,
+        Self::Reserved,
+        Self::Tuner,
+        Self::PlaybackDevice,
+        Self::AudioSystem,
+    ];
+    const NAMED_UNKNOWN: Option<Self> = None;
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &[
+        "Tv",
+        "RecordingDevice",
+        "Reserved",
+        "Tuner",
+        "PlaybackDevice",
+        "AudioSystem",
+        "Unknown",
+    ];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Tv => "TV",
+            Self::RecordingDevice => "recording device",
+            Self::Reserved => "reserved",
+            Self::Tuner => "tuner",
+            Self::PlaybackDevice => "playback device",
+            Self::AudioSystem => "audio system",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_device_type) -> Self {
+        match c_enum_value!(raw) {
+            0 => Self::Tv,
+            1 => Self::RecordingDevice,
+            2 => Self::Reserved,
+            3 => Self::Tuner,
+            4 => Self::PlaybackDevice,
+            5 => Self::AudioSystem,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_device_type {
+        match self {
+            Self::Tv => c_enum!(cec_device_type, 0),
+            Self::RecordingDevice => c_enum!(cec_device_type, 1),
+            Self::Reserved => c_enum!(cec_device_type, 2),
+            Self::Tuner => c_enum!(cec_device_type, 3),
+            Self::PlaybackDevice => c_enum!(cec_device_type, 4),
+            Self::AudioSystem => c_enum!(cec_device_type, 5),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(DeviceType, cec_device_type);
+/// `cec_display_control`
+#[derive(Debug, Clone, Copy)]
+pub enum DisplayControl {
+    /// `cec_display_control_DISPLAY_FOR_DEFAULT_TIME`
+    DisplayForDefaultTime,
+    /// `cec_display_control_DISPLAY_UNTIL_CLEARED`
+    DisplayUntilCleared,
+    /// `cec_display_control_CLEAR_PREVIOUS_MESSAGE`
+    ClearPreviousMessage,
+    /// `cec_display_control_RESERVED_FOR_FUTURE_USE`
+    ReservedForFutureUse,
+    /// Value not known to libcec
+    Unknown(cec_display_control),
+}
+impl DisplayControl {
+    /// All known values
+    pub const ALL: &'static [Self] = &[
+        Self::DisplayForDefaultTime,
+        Self::DisplayUntilCleared,
+        Self::ClearPreviousMessage,
+        Self::ReservedForFutureUse,
+    ];
+    const NAMED_UNKNOWN: Option<Self> = None;
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &[
+        "DisplayForDefaultTime",
+        "DisplayUntilCleared",
+        "ClearPreviousMessage",
+        "ReservedForFutureUse",
+        "Unknown",
+    ];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::DisplayForDefaultTime => "display for default time",
+            Self::DisplayUntilCleared => "display until cleared",
+            Self::ClearPreviousMessage => "clear previous message",
+            Self::ReservedForFutureUse => "reserved for future use",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_display_control) -> Self {
+        match c_enum_value!(raw) {
+            0 => Self::DisplayForDefaultTime,
+            64 => Self::DisplayUntilCleared,
+            128 => Self::ClearPreviousMessage,
+            192 => Self::ReservedForFutureUse,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_display_control {
+        match self {
+            Self::DisplayForDefaultTime => c_enum!(cec_display_control, 0),
+            Self::DisplayUntilCleared => c_enum!(cec_display_control, 64),
+            Self::ClearPreviousMessage => c_enum!(cec_display_control, 128),
+            Self::ReservedForFutureUse => c_enum!(cec_display_control, 192),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(DisplayControl, cec_display_control);
+/// `cec_external_source_specifier`
+#[derive(Debug, Clone, Copy)]
+pub enum ExternalSourceSpecifier {
+    /// `cec_external_source_specifier_EXTERNAL_PLUG`
+    ExternalPlug,
+    /// `cec_external_source_specifier_EXTERNAL_PHYSICAL_ADDRESS`
+    ExternalPhysicalAddress,
+    /// Value not known to libcec
+    Unknown(cec_external_source_specifier),
+}
+impl ExternalSourceSpecifier {
+    /// All known values
+    pub const ALL: &'static [Self] = &[Self::ExternalPlug, Self::ExternalPhysicalAddress];
+    const NAMED_UNKNOWN: Option<Self> = None;
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] =
+        &["ExternalPlug", "ExternalPhysicalAddress", "Unknown"];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::ExternalPlug => "external plug",
+            Self::ExternalPhysicalAddress => "external physical address",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_external_source_specifier) -> Self {
+        match c_enum_value!(raw) {
+            4 => Self::ExternalPlug,
+            5 => Self::ExternalPhysicalAddress,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_external_source_specifier {
+        match self {
+            Self::ExternalPlug => c_enum!(cec_external_source_specifier, 4),
+            Self::ExternalPhysicalAddress => c_enum!(cec_external_source_specifier, 5),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(ExternalSourceSpecifier, cec_external_source_specifier);
+/// `cec_menu_request_type`
+#[derive(Debug, Clone, Copy)]
+pub enum MenuRequestType {
+    /// `cec_menu_request_type_ACTIVATE`
+    Activate,
+    /// `cec_menu_request_type_DEACTIVATE`
+    Deactivate,
+    /// `cec_menu_request_type_QUERY`
+    Query,
+    /// Value not known to libcec
+    Unknown(cec_menu_request_type),
+}
+impl MenuRequestType {
+    /// All known values
+    pub const ALL: &'static [Self] = &[Self::Activate, Self::Deactivate, Self::Query];
+    const NAMED_UNKNOWN: Option<Self> = None;
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &["Activate", "Deactivate", "Query", "Unknown"];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Activate => "activate",
+            Self::Deactivate => "deactivate",
+            Self::Query => "query",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_menu_request_type) -> Self {
+        match c_enum_value!(raw) {
+            0 => Self::Activate,
+            1 => Self::Deactivate,
+            2 => Self::Query,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_menu_request_type {
+        match self {
+            Self::Activate => c_enum!(cec_menu_request_type, 0),
+            Self::Deactivate => c_enum!(cec_menu_request_type, 1),
+            Self::Query => c_enum!(cec_menu_request_type, 2),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(MenuRequestType, cec_menu_request_type);
+/// `cec_menu_state`
+#[derive(Debug, Clone, Copy)]
+pub enum MenuState {
+    /// `cec_menu_state_ACTIVATED`
+    Activated,
+    /// `cec_menu_state_DEACTIVATED`
+    Deactivated,
+    /// Value not known to libcec
+    Unknown(cec_menu_state),
+}
+impl MenuState {
+    /// All known values
+    pub const ALL: &'static [Self] = &[Self::Activated, Self::Deactivated];
+    const NAMED_UNKNOWN: Option<Self> = None;
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &["Activated", "Deactivated", "Unknown"];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Activated => "activated",
+            Self::Deactivated => "deactivated",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_menu_state) -> Self {
+        match c_enum_value!(raw) {
+            0 => Self::Activated,
+            1 => Self::Deactivated,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_menu_state {
+        match self {
+            Self::Activated => c_enum!(cec_menu_state, 0),
+            Self::Deactivated => c_enum!(cec_menu_state, 1),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(MenuState, cec_menu_state);
+/// `cec_play_mode`
+#[derive(Debug, Clone, Copy)]
+pub enum PlayMode {
+    /// `cec_play_mode_PLAY_FORWARD`
+    PlayForward,
+    /// `cec_play_mode_PLAY_REVERSE`
+    PlayReverse,
+    /// `cec_play_mode_PLAY_STILL`
+    PlayStill,
+    /// `cec_play_mode_FAST_FORWARD_MIN_SPEED`
+    FastForwardMinSpeed,
+    /// `cec_play_mode_FAST_FORWARD_MEDIUM_SPEED`
+    FastForwardMediumSpeed,
+    /// `cec_play_mode_FAST_FORWARD_MAX_SPEED`
+    FastForwardMaxSpeed,
+    /// `cec_play_mode_FAST_REVERSE_MIN_SPEED`
+    FastReverseMinSpeed,
+    /// `cec_play_mode_FAST_REVERSE_MEDIUM_SPEED`
+    FastReverseMediumSpeed,
+    /// `cec_play_mode_FAST_REVERSE_MAX_SPEED`
+    FastReverseMaxSpeed,
+    /// `cec_play_mode_SLOW_FORWARD_MIN_SPEED`
+    SlowForwardMinSpeed,
+    /// `cec_play_mode_SLOW_FORWARD_MEDIUM_SPEED`
+    SlowForwardMediumSpeed,
+    /// `cec_play_mode_SLOW_FORWARD_MAX_SPEED`
+    SlowForwardMaxSpeed,
+    /// `cec_play_mode_SLOW_REVERSE_MIN_SPEED`
+    SlowReverseMinSpeed,
+    /// `cec_play_mode_SLOW_REVERSE_MEDIUM_SPEED`
+    SlowReverseMediumSpeed,
+    /// `cec_play_mode_SLOW_REVERSE_MAX_SPEED`
+    SlowReverseMaxSpeed,
+    /// Value not known to libcec
+    Unknown(cec_play_mode),
+}
+impl PlayMode {
+    /// All known values
+    pub const ALL: &'static [Self] = &[
+        Self::PlayForward,
+        Self::PlayReverse,
+        Self::PlayStill,
+        Self::FastForwardMinSpeed,
+        Self::FastForwardMediumSpeed,
+        Self::FastForwardMaxSpeed,
+        Self::FastReverseMinSpeed,
+        Self::FastReverseMediumSpeed,
+        Self::FastReverseMaxSpeed,
+        Self::SlowForwardMinSpeed,
+        Self::SlowForwardMediumSpeed,
+        Self::SlowForwardMaxSpeed,
+        Self::SlowReverseMinSpeed,
+        Self::SlowReverseMediumSpeed,
+        Self::SlowReverseMaxSpeed,
+    ];
+    const NAMED_UNKNOWN: Option<Self> = None;
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &[
+        "PlayForward",
+        "PlayReverse",
+        "PlayStill",
+        "FastForwardMinSpeed",
+        "FastForwardMediumSpeed",
+        "FastForwardMaxSpeed",
+        "FastReverseMinSpeed",
+        "FastReverseMediumSpeed",
+        "FastReverseMaxSpeed",
+        "SlowForwardMinSpeed",
+        "SlowForwardMediumSpeed",
+        "SlowForwardMaxSpeed",
+        "SlowReverseMinSpeed",
+        "SlowReverseMediumSpeed",
+        "SlowReverseMaxSpeed",
+        "Unknown",
+    ];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::PlayForward => "play forward",
+            Self::PlayReverse => "play reverse",
+            Self::PlayStill => "play still",
+            Self::FastForwardMinSpeed => "fast forward min speed",
+            Self::FastForwardMediumSpeed => "fast forward medium speed",
+            Self::FastForwardMaxSpeed => "fast forward max speed",
+            Self::FastReverseMinSpeed => "fast reverse min speed",
+            Self::FastReverseMediumSpeed => "fast reverse medium speed",
+            Self::FastReverseMaxSpeed => "fast reverse max speed",
+            Self::SlowForwardMinSpeed => "slow forward min speed",
+            Self::SlowForwardMediumSpeed => "slow forward medium speed",
+            Self::SlowForwardMaxSpeed => "slow forward max speed",
+            Self::SlowReverseMinSpeed => "slow reverse min speed",
+            Self::SlowReverseMediumSpeed => "slow reverse medium speed",
+            Self::SlowReverseMaxSpeed => "slow reverse max speed",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_play_mode) -> Self {
+        match c_enum_value!(raw) {
+            36 => Self::PlayForward,
+            32 => Self::PlayReverse,
+            37 => Self::PlayStill,
+            5 => Self::FastForwardMinSpeed,
+            6 => Self::FastForwardMediumSpeed,
+            7 => Self::FastForwardMaxSpeed,
+            9 => Self::FastReverseMinSpeed,
+            10 => Self::FastReverseMediumSpeed,
+            11 => Self::FastReverseMaxSpeed,
+            21 => Self::SlowForwardMinSpeed,
+            22 => Self::SlowForwardMediumSpeed,
+            23 => Self::SlowForwardMaxSpeed,
+            25 => Self::SlowReverseMinSpeed,
+            26 => Self::SlowReverseMediumSpeed,
+            27 => Self::SlowReverseMaxSpeed,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_play_mode {
+        match self {
+            Self::PlayForward => c_enum!(cec_play_mode, 36),
+            Self::PlayReverse => c_enum!(cec_play_mode, 32),
+            Self::PlayStill => c_enum!(cec_play_mode, 37),
+            Self::FastForwardMinSpeed => c_enum!(cec_play_mode, 5),
+            Self::FastForwardMediumSpeed => c_enum!(cec_play_mode, 6),
+            Self::FastForwardMaxSpeed => c_enum!(cec_play_mode, 7),
+            Self::FastReverseMinSpeed => c_enum!(cec_play_mode, 9),
+            Self::FastReverseMediumSpeed => c_enum!(cec_play_mode, 10),
+            Self::FastReverseMaxSpeed => c_enum!(cec_play_mode, 11),
+            Self::SlowForwardMinSpeed => c_enum!(cec_play_mode, 21),
+            Self::SlowForwardMediumSpeed => c_enum!(cec_play_mode, 22),
+            Self::SlowForwardMaxSpeed => c_enum!(cec_play_mode, 23),
+            Self::SlowReverseMinSpeed => c_enum!(cec_play_mode, 25),
+            Self::SlowReverseMediumSpeed => c_enum!(cec_play_mode, 26),
+            Self::SlowReverseMaxSpeed => c_enum!(cec_play_mode, 27),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(PlayMode, cec_play_mode);
+/// `cec_power_status`
+#[derive(Debug, Clone, Copy)]
+pub enum PowerStatus {
+    /// `cec_power_status_ON`
+    On,
+    /// `cec_power_status_STANDBY`
+    Standby,
+    /// `cec_power_status_IN_TRANSITION_STANDBY_TO_ON`
+    InTransitionStandbyToOn,
+    /// `cec_power_status_IN_TRANSITION_ON_TO_STANDBY`
+    InTransitionOnToStandby,
+    /// Value not known to libcec
+    Unknown(cec_power_status),
+}
+impl PowerStatus {
+    /// `cec_power_status_UNKNOWN`
+    pub const UNKNOWN: Self = Self::Unknown(c_enum!(cec_power_status, 153));
+    /// All known values
+    pub const ALL: &'static [Self] = &[
+        Self::On,
+        Self::Standby,
+        Self::InTransitionStandbyToOn,
+        Self::InTransitionOnToStandby,
+    ];
+    const NAMED_UNKNOWN: Option<Self> = Some(Self::UNKNOWN);
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &[
+        "On",
+        "Standby",
+        "InTransitionStandbyToOn",
+        "InTransitionOnToStandby",
+        "UNKNOWN",
+        "Unknown",
+    ];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::On => "on",
+            Self::Standby => "standby",
+            Self::InTransitionStandbyToOn => "in transition from standby to on",
+            Self::InTransitionOnToStandby => "in transition from on to standby",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_power_status) -> Self {
+        match c_enum_value!(raw) {
+            0 => Self::On,
+            1 => Self::Standby,
+            2 => Self::InTransitionStandbyToOn,
+            3 => Self::InTransitionOnToStandby,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_power_status {
+        match self {
+            Self::On => c_enum!(cec_power_status, 0),
+            Self::Standby => c_enum!(cec_power_status, 1),
+            Self::InTransitionStandbyToOn => c_enum!(cec_power_status, 2),
+            Self::InTransitionOnToStandby => c_enum!(cec_power_status, 3),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(PowerStatus, cec_power_status);
+/// `cec_record_source_type`
+#[derive(Debug, Clone, Copy)]
+pub enum RecordSourceType {
+    /// `cec_record_source_type_OWN_SOURCE`
+    OwnSource,
+    /// `cec_record_source_type_DIGITAL_SERVICE`
+    DigitalService,
+    /// `cec_record_source_type_ANALOGUE_SERVICE`
+    AnalogueService,
+    /// `cec_record_source_type_EXTERNAL_PLUS`
+    ExternalPlus,
+    /// `cec_record_source_type_EXTERNAL_PHYSICAL_ADDRESS`
+    ExternalPhysicalAddress,
+    /// Value not known to libcec
+    Unknown(cec_record_source_type),
+}
+impl RecordSourceType {
+    /// All known values
+    pub const ALL: &'static [Self] = &[
+        Self::OwnSource,
+        Self::DigitalService,
+        Self::AnalogueService,
+        Self::ExternalPlus,
+        Self::ExternalPhysicalAddress,
+    ];
+    const NAMED_UNKNOWN: Option<Self> = None;
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &[
+        "OwnSource",
+        "DigitalService",
+        "AnalogueService",
+        "ExternalPlus",
+        "ExternalPhysicalAddress",
+        "Unknown",
+    ];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::OwnSource => "own source",
+            Self::DigitalService => "digital service",
+            Self::AnalogueService => "analogue service",
+            Self::ExternalPlus => "external plus",
+            Self::ExternalPhysicalAddress => "external physical address",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_record_source_type) -> Self {
+        match c_enum_value!(raw) {
+            1 => Self::OwnSource,
+            2 => Self::DigitalService,
+            3 => Self::AnalogueService,
+            4 => Self::ExternalPlus,
+            5 => Self::ExternalPhysicalAddress,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_record_source_type {
+        match self {
+            Self::OwnSource => c_enum!(cec_record_source_type, 1),
+            Self::DigitalService => c_enum!(cec_record_source_type, 2),
+            Self::AnalogueService => c_enum!(cec_record_source_type, 3),
+            Self::ExternalPlus => c_enum!(cec_record_source_type, 4),
+            Self::ExternalPhysicalAddress => c_enum!(cec_record_source_type, 5),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(RecordSourceType, cec_record_source_type);
+/// `cec_record_status_info`
+#[derive(Debug, Clone, Copy)]
+pub enum RecordStatusInfo {
+    /// `cec_record_status_info_RECORDING_CURRENTLY_SELECTED_SOURCE`
+    RecordingCurrentlySelectedSource,
+    /// `cec_record_status_info_RECORDING_DIGITAL_SERVICE`
+    RecordingDigitalService,
+    /// `cec_record_status_info_RECORDING_ANALOGUE_SERVICE`
+    RecordingAnalogueService,
+    /// `cec_record_status_info_RECORDING_EXTERNAL_INPUT`
+    RecordingExternalInput,
+    /// `cec_record_status_info_NO_RECORDING_UNABLE_TO_RECORD_DIGITAL_SERVICE`
+    NoRecordingUnableToRecordDigitalService,
+    /// `cec_record_status_info_NO_RECORDING_UNABLE_TO_RECORD_ANALOGUE_SERVICE`
+    NoRecordingUnableToRecordAnalogueService,
+    /// `cec_record_status_info_NO_RECORDING_UNABLE_TO_SELECT_REQUIRED_SERVICE`
+    NoRecordingUnableToSelectRequiredService,
+    /// `cec_record_status_info_NO_RECORDING_INVALID_EXTERNAL_PLUG_NUMBER`
+    NoRecordingInvalidExternalPlugNumber,
+    /// `cec_record_status_info_NO_RECORDING_INVALID_EXTERNAL_ADDRESS`
+    NoRecordingInvalidExternalAddress,
+    /// `cec_record_status_info_NO_RECORDING_CA_SYSTEM_NOT_SUPPORTED`
+    NoRecordingCaSystemNotSupported,
+    /// `cec_record_status_info_NO_RECORDING_NO_OR_INSUFFICIENT_ENTITLEMENTS`
+    NoRecordingNoOrInsufficientEntitlements,
+    /// `cec_record_status_info_NO_RECORDING_NOT_ALLOWED_TO_COPY_SOURCE`
+    NoRecordingNotAllowedToCopySource,
+    /// `cec_record_status_info_NO_RECORDING_NO_FURTHER_COPIES_ALLOWED`
+    NoRecordingNoFurtherCopiesAllowed,
+    /// `cec_record_status_info_NO_RECORDING_NO_MEDIA`
+    NoRecordingNoMedia,
+    /// `cec_record_status_info_NO_RECORDING_PLAYING`
+    NoRecordingPlaying,
+    /// `cec_record_status_info_NO_RECORDING_ALREADY_RECORDING`
+    NoRecordingAlreadyRecording,
+    /// `cec_record_status_info_NO_RECORDING_MEDIA_PROTECTED`
+    NoRecordingMediaProtected,
+    /// `cec_record_status_info_NO_RECORDING_NO_SOURCE_SIGNAL`
+    NoRecordingNoSourceSignal,
+    /// `cec_record_status_info_NO_RECORDING_MEDIA_PROBLEM`
+    NoRecordingMediaProblem,
+    /// `cec_record_status_info_NO_RECORDING_NOT_ENOUGH_SPACE_AVAILABLE`
+    NoRecordingNotEnoughSpaceAvailable,
+    /// `cec_record_status_info_NO_RECORDING_PARENTAL_LOCK_ON`
+    NoRecordingParentalLockOn,
+    /// `cec_record_status_info_RECORDING_TERMINATED_NORMALLY`
+    RecordingTerminatedNormally,
+    /// `cec_record_status_info_RECORDING_HAS_ALREADY_TERMINATED`
+    RecordingHasAlreadyTerminated,
+    /// `cec_record_status_info_NO_RECORDING_OTHER_REASON`
+    NoRecordingOtherReason,
+    /// Value not known to libcec
+    Unknown(cec_record_status_info),
+}
+impl RecordStatusInfo {
+    /// All known values
+    pub const ALL: &'static [Self] = &[
+        Self::RecordingCurrentlySelectedSource,
+        Self::RecordingDigitalService,
+        Self::RecordingAnalogueService,
+        Self::RecordingExternalInput,
+        Self::NoRecordingUnableToRecordDigitalService,
+        Self::NoRecordingUnableToRecordAnalogueService,
+        Self::NoRecordingUnableToSelectRequiredService,
+        Self::NoRecordingInvalidExternalPlugNumber,
+        Self::NoRecordingInvalidExternalAddress,
+        Self::NoRecordingCaSystemNotSupported,
+        Self::NoRecordingNoOrInsufficientEntitlements,
+        Self::NoRecordingNotAllowedToCopySource,
+        Self::NoRecordingNoFurtherCopiesAllowed,
+        Self::NoRecordingNoMedia,
+        Self::NoRecordingPlaying,
+        Self::NoRecordingAlreadyRecording,
+        Self::NoRecordingMediaProtected,
+        Self::NoRecordingNoSourceSignal,
+        Self::NoRecordingMediaProblem,
+        Self::NoRecordingNotEnoughSpaceAvailable,
+        Self::NoRecordingParentalLockOn,
+        Self::RecordingTerminatedNormally,
+        Self::RecordingHasAlreadyTerminated,
+        Self::NoRecordingOtherReason,
+    ];
+    const NAMED_UNKNOWN: Option<Self> = None;
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &[
+        "RecordingCurrentlySelectedSource",
+        "RecordingDigitalService",
+        "RecordingAnalogueService",
+        "RecordingExternalInput",
+        "NoRecordingUnableToRecordDigitalService",
+        "NoRecordingUnableToRecordAnalogueService",
+        "NoRecordingUnableToSelectRequiredService",
+        "NoRecordingInvalidExternalPlugNumber",
+        "NoRecordingInvalidExternalAddress",
+        "NoRecordingCaSystemNotSupported",
+        "NoRecordingNoOrInsufficientEntitlements",
+        "NoRecordingNotAllowedToCopySource",
+        "NoRecordingNoFurtherCopiesAllowed",
+        "NoRecordingNoMedia",
+        "NoRecordingPlaying",
+        "NoRecordingAlreadyRecording",
+        "NoRecordingMediaProtected",
+        "NoRecordingNoSourceSignal",
+        "NoRecordingMediaProblem",
+        "NoRecordingNotEnoughSpaceAvailable",
+        "NoRecordingParentalLockOn",
+        "RecordingTerminatedNormally",
+        "RecordingHasAlreadyTerminated",
+        "NoRecordingOtherReason",
+        "Unknown",
+    ];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::RecordingCurrentlySelectedSource => "recording currently selected source",
+            Self::RecordingDigitalService => "recording digital service",
+            Self::RecordingAnalogueService => "recording analogue service",
+            Self::RecordingExternalInput => "recording external input",
+            Self::NoRecordingUnableToRecordDigitalService => {
+                "no recording unable to record digital service"
+            }
+            Self::NoRecordingUnableToRecordAnalogueService => {
+                "no recording unable to record analogue service"
+            }
+            Self::NoRecordingUnableToSelectRequiredService => {
+                "no recording unable to select required service"
+            }
+            Self::NoRecordingInvalidExternalPlugNumber => {
+                "no recording invalid external plug number"
+            }
+            Self::NoRecordingInvalidExternalAddress => "no recording invalid external address",
+            Self::NoRecordingCaSystemNotSupported => "no recording ca system not supported",
+            Self::NoRecordingNoOrInsufficientEntitlements => {
+                "no recording no or insufficient entitlements"
+            }
+            Self::NoRecordingNotAllowedToCopySource => "no recording not allowed to copy source",
+            Self::NoRecordingNoFurtherCopiesAllowed => "no recording no further copies allowed",
+            Self::NoRecordingNoMedia => "no recording no media",
+            Self::NoRecordingPlaying => "no recording playing",
+            Self::NoRecordingAlreadyRecording => "no recording already recording",
+            Self::NoRecordingMediaProtected => "no recording media protected",
+            Self::NoRecordingNoSourceSignal => "no recording no source signal",
+            Self::NoRecordingMediaProblem => "no recording media problem",
+            Self::NoRecordingNotEnoughSpaceAvailable => "no recording not enough space available",
+            Self::NoRecordingParentalLockOn => "no recording parental lock on",
+            Self::RecordingTerminatedNormally => "recording terminated normally",
+            Self::RecordingHasAlreadyTerminated => "recording has already terminated",
+            Self::NoRecordingOtherReason => "no recording other reason",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_record_status_info) -> Self {
+        match c_enum_value!(raw) {
+            1 => Self::RecordingCurrentlySelectedSource,
+            2 => Self::RecordingDigitalService,
+            3 => Self::RecordingAnalogueService,
+            4 => Self::RecordingExternalInput,
+            5 => Self::NoRecordingUnableToRecordDigitalService,
+            6 => Self::NoRecordingUnableToRecordAnalogueService,
+            7 => Self::NoRecordingUnableToSelectRequiredService,
+            9 => Self::NoRecordingInvalidExternalPlugNumber,
+            10 => Self::NoRecordingInvalidExternalAddress,
+            11 => Self::NoRecordingCaSystemNotSupported,
+            12 => Self::NoRecordingNoOrInsufficientEntitlements,
+            13 => Self::NoRecordingNotAllowedToCopySource,
+            14 => Self::NoRecordingNoFurtherCopiesAllowed,
+            16 => Self::NoRecordingNoMedia,
+            17 => Self::NoRecordingPlaying,
+            18 => Self::NoRecordingAlreadyRecording,
+            19 => Self::NoRecordingMediaProtected,
+            20 => Self::NoRecordingNoSourceSignal,
+            21 => Self::NoRecordingMediaProblem,
+            22 => Self::NoRecordingNotEnoughSpaceAvailable,
+            23 => Self::NoRecordingParentalLockOn,
+            26 => Self::RecordingTerminatedNormally,
+            27 => Self::RecordingHasAlreadyTerminated,
+            31 => Self::NoRecordingOtherReason,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_record_status_info {
+        match self {
+            Self::RecordingCurrentlySelectedSource => c_enum!(cec_record_status_info, 1),
+            Self::RecordingDigitalService => c_enum!(cec_record_status_info, 2),
+            Self::RecordingAnalogueService => c_enum!(cec_record_status_info, 3),
+            Self::RecordingExternalInput => c_enum!(cec_record_status_info, 4),
+            Self::NoRecordingUnableToRecordDigitalService => c_enum!(cec_record_status_info, 5),
+            Self::NoRecordingUnableToRecordAnalogueService => c_enum!(cec_record_status_info, 6),
+            Self::NoRecordingUnableToSelectRequiredService => c_enum!(cec_record_status_info, 7),
+            Self::NoRecordingInvalidExternalPlugNumber => c_enum!(cec_record_status_info, 9),
+            Self::NoRecordingInvalidExternalAddress => c_enum!(cec_record_status_info, 10),
+            Self::NoRecordingCaSystemNotSupported => c_enum!(cec_record_status_info, 11),
+            Self::NoRecordingNoOrInsufficientEntitlements => c_enum!(cec_record_status_info, 12),
+            Self::NoRecordingNotAllowedToCopySource => c_enum!(cec_record_status_info, 13),
+            Self::NoRecordingNoFurtherCopiesAllowed => c_enum!(cec_record_status_info, 14),
+            Self::NoRecordingNoMedia => c_enum!(cec_record_status_info, 16),
+            Self::NoRecordingPlaying => c_enum!(cec_record_status_info, 17),
+            Self::NoRecordingAlreadyRecording => c_enum!(cec_record_status_info, 18),
+            Self::NoRecordingMediaProtected => c_enum!(cec_record_status_info, 19),
+            Self::NoRecordingNoSourceSignal => c_enum!(cec_record_status_info, 20),
+            Self::NoRecordingMediaProblem => c_enum!(cec_record_status_info, 21),
+            Self::NoRecordingNotEnoughSpaceAvailable => c_enum!(cec_record_status_info, 22),
+            Self::NoRecordingParentalLockOn => c_enum!(cec_record_status_info, 23),
+            Self::RecordingTerminatedNormally => c_enum!(cec_record_status_info, 26),
+            Self::RecordingHasAlreadyTerminated => c_enum!(cec_record_status_info, 27),
+            Self::NoRecordingOtherReason => c_enum!(cec_record_status_info, 31),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(RecordStatusInfo, cec_record_status_info);
+/// `cec_recording_sequence`
+#[derive(Debug, Clone, Copy)]
+pub enum RecordingSequence {
+    /// `cec_recording_sequence_SUNDAY`
+    Sunday,
+    /// `cec_recording_sequence_MONDAY`
+    Monday,
+    /// `cec_recording_sequence_TUESDAY`
+    Tuesday,
+    /// `cec_recording_sequence_WEDNESDAY`
+    Wednesday,
+    /// `cec_recording_sequence_THURSDAY`
+    Thursday,
+    /// `cec_recording_sequence_FRIDAY`
+    Friday,
+    /// `cec_recording_sequence_SATURDAY`
+    Saturday,
+    /// `cec_recording_sequence_ONCE_ONLY`
+    OnceOnly,
+    /// Value not known to libcec
+    Unknown(cec_recording_sequence),
+}
+impl RecordingSequence {
+    /// All known values
+    pub const ALL: &'static [Self] = &[
+        Self::Sunday,
+        Self::Monday,
+        Self::Tuesday,
+        Self::Wednesday,
+        Self::Thursday,
+        Self::Friday,
+        Self::Saturday,
+        Self::OnceOnly,
+    ];
+    const NAMED_UNKNOWN: Option<Self> = None;
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &[
+        "Sunday",
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+        "OnceOnly",
+        "Unknown",
+    ];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Sunday => "sunday",
+            Self::Monday => "monday",
+            Self::Tuesday => "tuesday",
+            Self::Wednesday => "wednesday",
+            Self::Thursday => "thursday",
+            Self::Friday => "friday",
+            Self::Saturday => "saturday",
+            Self::OnceOnly => "once only",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_recording_sequence) -> Self {
+        match c_enum_value!(raw) {
+            1 => Self::Sunday,
+            2 => Self::Monday,
+            4 => Self::Tuesday,
+            8 => Self::Wednesday,
+            16 => Self::Thursday,
+            32 => Self::Friday,
+            64 => Self::Saturday,
+            0 => Self::OnceOnly,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_recording_sequence {
+        match self {
+            Self::Sunday => c_enum!(cec_recording_sequence, 1),
+            Self::Monday => c_enum!(cec_recording_sequence, 2),
+            Self::Tuesday => c_enum!(cec_recording_sequence, 4),
+            Self::Wednesday => c_enum!(cec_recording_sequence, 8),
+            Self::Thursday => c_enum!(cec_recording_sequence, 16),
+            Self::Friday => c_enum!(cec_recording_sequence, 32),
+            Self::Saturday => c_enum!(cec_recording_sequence, 64),
+            Self::OnceOnly => c_enum!(cec_recording_sequence, 0),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(RecordingSequence, cec_recording_sequence);
+/// `cec_status_request`
+#[derive(Debug, Clone, Copy)]
+pub enum StatusRequest {
+    /// `cec_status_request_ON`
+    On,
+    /// `cec_status_request_OFF`
+    Off,
+    /// `cec_status_request_ONCE`
+    Once,
+    /// Value not known to libcec
+    Unknown(cec_status_request),
+}
+impl StatusRequest {
+    /// All known values
+    pub const ALL: &'static [Self] = &[Self::On, Self::Off, Self::Once];
+    const NAMED_UNKNOWN: Option<Self> = None;
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &["On", "Off", "Once", "Unknown"];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::On => "on",
+            Self::Off => "off",
+            Self::Once => "once",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_status_request) -> Self {
+        match c_enum_value!(raw) {
+            1 => Self::On,
+            2 => Self::Off,
+            3 => Self::Once,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_status_request {
+        match self {
+            Self::On => c_enum!(cec_status_request, 1),
+            Self::Off => c_enum!(cec_status_request, 2),
+            Self::Once => c_enum!(cec_status_request, 3),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(StatusRequest, cec_status_request);
+/// `cec_system_audio_status`
+#[derive(Debug, Clone, Copy)]
+pub enum SystemAudioStatus {
+    /// `cec_system_audio_status_OFF`
+    Off,
+    /// `cec_system_audio_status_ON`
+    On,
+    /// Value not known to libcec
+    Unknown(cec_system_audio_status),
+}
+impl SystemAudioStatus {
+    /// `cec_system_audio_status_UNKNOWN`
+    pub const UNKNOWN: Self = Self::Unknown(c_enum!(cec_system_audio_status, 2));
+    /// All known values
+    pub const ALL: &'static [Self] = &[Self::Off, Self::On];
+    const NAMED_UNKNOWN: Option<Self> = Some(Self::UNKNOWN);
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &["Off", "On", "UNKNOWN", "Unknown"];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::On => "on",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_system_audio_status) -> Self {
+        match c_enum_value!(raw) {
+            0 => Self::Off,
+            1 => Self::On,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_system_audio_status {
+        match self {
+            Self::Off => c_enum!(cec_system_audio_status, 0),
+            Self::On => c_enum!(cec_system_audio_status, 1),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(SystemAudioStatus, cec_system_audio_status);
+/// `cec_timer_cleared_status_data`
+#[derive(Debug, Clone, Copy)]
+pub enum TimerClearedStatusData {
+    /// `cec_timer_cleared_status_data_TIMER_NOT_CLEARED_RECORDING`
+    TimerNotClearedRecording,
+    /// `cec_timer_cleared_status_data_TIMER_NOT_CLEARED_NO_MATCHING`
+    TimerNotClearedNoMatching,
+    /// `cec_timer_cleared_status_data_TIMER_NOT_CLEARED_NO_INF0_AVAILABLE`
+    TimerNotClearedNoInf0Available,
+    /// `cec_timer_cleared_status_data_TIMER_CLEARED`
+    TimerCleared,
+    /// Value not known to libcec
+    Unknown(cec_timer_cleared_status_data),
+}
+impl TimerClearedStatusData {
+    /// All known values
+    pub const ALL: &'static [Self] = &[
+        Self::TimerNotClearedRecording,
+        Self::TimerNotClearedNoMatching,
+        Self::TimerNotClearedNoInf0Available,
+        Self::TimerCleared,
+    ];
+    const NAMED_UNKNOWN: Option<Self> = None;
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &[
+        "TimerNotClearedRecording",
+        "TimerNotClearedNoMatching",
+        "TimerNotClearedNoInf0Available",
+        "TimerCleared",
+        "Unknown",
+    ];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::TimerNotClearedRecording => "timer not cleared recording",
+            Self::TimerNotClearedNoMatching => "timer not cleared no matching",
+            Self::TimerNotClearedNoInf0Available => "timer not cleared no inf0 available",
+            Self::TimerCleared => "timer cleared",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_timer_cleared_status_data) -> Self {
+        match c_enum_value!(raw) {
+            0 => Self::TimerNotClearedRecording,
+            1 => Self::TimerNotClearedNoMatching,
+            2 => Self::TimerNotClearedNoInf0Available,
+            128 => Self::TimerCleared,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_timer_cleared_status_data {
+        match self {
+            Self::TimerNotClearedRecording => c_enum!(cec_timer_cleared_status_data, 0),
+            Self::TimerNotClearedNoMatching => c_enum!(cec_timer_cleared_status_data, 1),
+            Self::TimerNotClearedNoInf0Available => c_enum!(cec_timer_cleared_status_data, 2),
+            Self::TimerCleared => c_enum!(cec_timer_cleared_status_data, 128),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(TimerClearedStatusData, cec_timer_cleared_status_data);
+/// `cec_timer_overlap_warning`
+#[derive(Debug, Clone, Copy)]
+pub enum TimerOverlapWarning {
+    /// `cec_timer_overlap_warning_NO_OVERLAP`
+    NoOverlap,
+    /// `cec_timer_overlap_warning_TIMER_BLOCKS_OVERLAP`
+    TimerBlocksOverlap,
+    /// Value not known to libcec
+    Unknown(cec_timer_overlap_warning),
+}
+impl TimerOverlapWarning {
+    /// All known values
+    pub const ALL: &'static [Self] = &[Self::NoOverlap, Self::TimerBlocksOverlap];
+    const NAMED_UNKNOWN: Option<Self> = None;
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &["NoOverlap", "TimerBlocksOverlap", "Unknown"];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::NoOverlap => "no overlap",
+            Self::TimerBlocksOverlap => "timer blocks overlap",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_timer_overlap_warning) -> Self {
+        match c_enum_value!(raw) {
+            0 => Self::NoOverlap,
+            1 => Self::TimerBlocksOverlap,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_timer_overlap_warning {
+        match self {
+            Self::NoOverlap => c_enum!(cec_timer_overlap_warning, 0),
+            Self::TimerBlocksOverlap => c_enum!(cec_timer_overlap_warning, 1),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(TimerOverlapWarning, cec_timer_overlap_warning);
+/// `cec_media_info`
+#[derive(Debug, Clone, Copy)]
+pub enum MediaInfo {
+    /// `cec_media_info_MEDIA_PRESENT_AND_NOT_PROTECTED`
+    MediaPresentAndNotProtected,
+    /// `cec_media_info_MEDIA_PRESENT_BUT_PROTECTED`
+    MediaPresentButProtected,
+    /// `cec_media_info_MEDIA_NOT_PRESENT`
+    MediaNotPresent,
+    /// `cec_media_info_FUTURE_USE`
+    FutureUse,
+    /// Value not known to libcec
+    Unknown(cec_media_info),
+}
+impl MediaInfo {
+    /// All known values
+    pub const ALL: &'static [Self] = &[
+        Self::MediaPresentAndNotProtected,
+        Self::MediaPresentButProtected,
+        Self::MediaNotPresent,
+        Self::FutureUse,
+    ];
+    const NAMED_UNKNOWN: Option<Self> = None;
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &[
+        "MediaPresentAndNotProtected",
+        "MediaPresentButProtected",
+        "MediaNotPresent",
+        "FutureUse",
+        "Unknown",
+    ];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::MediaPresentAndNotProtected => "media present and not protected",
+            Self::MediaPresentButProtected => "media present but protected",
+            Self::MediaNotPresent => "media not present",
+            Self::FutureUse => "future use",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_media_info) -> Self {
+        match c_enum_value!(raw) {
+            0 => Self::MediaPresentAndNotProtected,
+            1 => Self::MediaPresentButProtected,
+            2 => Self::MediaNotPresent,
+            3 => Self::FutureUse,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_media_info {
+        match self {
+            Self::MediaPresentAndNotProtected => c_enum!(cec_media_info, 0),
+            Self::MediaPresentButProtected => c_enum!(cec_media_info, 1),
+            Self::MediaNotPresent => c_enum!(cec_media_info, 2),
+            Self::FutureUse => c_enum!(cec_media_info, 3),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(MediaInfo, cec_media_info);
+/// `cec_programmed_indicator`
+#[derive(Debug, Clone, Copy)]
+pub enum ProgrammedIndicator {
+    /// `cec_programmed_indicator_NOT_PROGRAMMED`
+    NotProgrammed,
+    /// `cec_programmed_indicator_PROGRAMMED`
+    Programmed,
+    /// Value not known to libcec
+    Unknown(cec_programmed_indicator),
+}
+impl ProgrammedIndicator {
+    /// All known values
+    pub const ALL: &'static [Self] = &[Self::NotProgrammed, Self::Programmed];
+    const NAMED_UNKNOWN: Option<Self> = None;
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &["NotProgrammed", "Programmed", "Unknown"];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::NotProgrammed => "not programmed",
+            Self::Programmed => "programmed",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_programmed_indicator) -> Self {
+        match c_enum_value!(raw) {
+            0 => Self::NotProgrammed,
+            1 => Self::Programmed,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_programmed_indicator {
+        match self {
+            Self::NotProgrammed => c_enum!(cec_programmed_indicator, 0),
+            Self::Programmed => c_enum!(cec_programmed_indicator, 1),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(ProgrammedIndicator, cec_programmed_indicator);
+/// `cec_programmed_info`
+#[derive(Debug, Clone, Copy)]
+pub enum ProgrammedInfo {
+    /// `cec_programmed_info_FUTURE_USE`
+    FutureUse,
+    /// `cec_programmed_info_ENOUGH_SPACE_AVAILABLE_FOR_RECORDING`
+    EnoughSpaceAvailableForRecording,
+    /// `cec_programmed_info_NOT_ENOUGH_SPACE_AVAILABLE_FOR_RECORDING`
+    NotEnoughSpaceAvailableForRecording,
+    /// `cec_programmed_info_MAY_NOT_BE_ENOUGH_SPACE_AVAILABLE`
+    MayNotBeEnoughSpaceAvailable,
+    /// `cec_programmed_info_NO_MEDIA_INFO_AVAILABLE`
+    NoMediaInfoAvailable,
+    /// Value not known to libcec
+    Unknown(cec_programmed_info),
+}
+impl ProgrammedInfo {
+    /// All known values
+    pub const ALL: &'static [Self] = &[
+        Self::FutureUse,
+        Self::EnoughSpaceAvailableForRecording,
+        Self::NotEnoughSpaceAvailableForRecording,
+        Self::MayNotBeEnoughSpaceAvailable,
+        Self::NoMediaInfoAvailable,
+    ];
+    const NAMED_UNKNOWN: Option<Self> = None;
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &[
+        "FutureUse",
+        "EnoughSpaceAvailableForRecording",
+        "NotEnoughSpaceAvailableForRecording",
+        "MayNotBeEnoughSpaceAvailable",
+        "NoMediaInfoAvailable",
+        "Unknown",
+    ];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::FutureUse => "future use",
+            Self::EnoughSpaceAvailableForRecording => "enough space available for recording",
+            Self::NotEnoughSpaceAvailableForRecording => "not enough space available for recording",
+            Self::MayNotBeEnoughSpaceAvailable => "may not be enough space available",
+            Self::NoMediaInfoAvailable => "no media info available",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_programmed_info) -> Self {
+        match c_enum_value!(raw) {
+            0 => Self::FutureUse,
+            8 => Self::EnoughSpaceAvailableForRecording,
+            9 => Self::NotEnoughSpaceAvailableForRecording,
+            11 => Self::MayNotBeEnoughSpaceAvailable,
+            10 => Self::NoMediaInfoAvailable,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_programmed_info {
+        match self {
+            Self::FutureUse => c_enum!(cec_programmed_info, 0),
+            Self::EnoughSpaceAvailableForRecording => c_enum!(cec_programmed_info, 8),
+            Self::NotEnoughSpaceAvailableForRecording => c_enum!(cec_programmed_info, 9),
+            Self::MayNotBeEnoughSpaceAvailable => c_enum!(cec_programmed_info, 11),
+            Self::NoMediaInfoAvailable => c_enum!(cec_programmed_info, 10),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(ProgrammedInfo, cec_programmed_info);
+/// `cec_not_programmed_error_info`
+#[derive(Debug, Clone, Copy)]
+pub enum NotProgrammedErrorInfo {
+    /// `cec_not_programmed_error_info_FUTURE_USE`
+    FutureUse,
+    /// `cec_not_programmed_error_info_NO_FREE_TIMER_AVAILABLE`
+    NoFreeTimerAvailable,
+    /// `cec_not_programmed_error_info_DATE_OUT_OF_RANGE`
+    DateOutOfRange,
+    /// `cec_not_programmed_error_info_RECORDING_SEQUENCE_ERROR`
+    RecordingSequenceError,
+    /// `cec_not_programmed_error_info_INVALID_EXTERNAL_PLUG_NUMBER`
+    InvalidExternalPlugNumber,
+    /// `cec_not_programmed_error_info_INVALID_EXTERNAL_PHYSICAL_ADDRESS`
+    InvalidExternalPhysicalAddress,
+    /// `cec_not_programmed_error_info_CA_SYSTEM_NOT_SUPPORTED`
+    CaSystemNotSupported,
+    /// `cec_not_programmed_error_info_NO_OR_INSUFFICIENT_CA_ENTITLEMENTS`
+    NoOrInsufficientCaEntitlements,
+    /// `cec_not_programmed_error_info_DOES_NOT_SUPPORT_RESOLUTION`
+    DoesNotSupportResolution,
+    /// `cec_not_programmed_error_info_PARENTAL_LOCK_ON`
+    ParentalLockOn,
+    /// `cec_not_programmed_error_info_CLOCK_FAILURE`
+    ClockFailure,
+    /// `cec_not_programmed_error_info_RESERVED_FOR_FUTURE_USE_START`
+    ReservedForFutureUseStart,
+    /// `cec_not_programmed_error_info_RESERVED_FOR_FUTURE_USE_END`
+    ReservedForFutureUseEnd,
+    /// `cec_not_programmed_error_info_DUPLICATE_ALREADY_PROGRAMMED`
+    DuplicateAlreadyProgrammed,
+    /// Value not known to libcec
+    Unknown(cec_not_programmed_error_info),
+}
+impl NotProgrammedErrorInfo {
+    /// All known values
+    pub const ALL: &'static [Self] = &[
+        Self::FutureUse,
+        Self::NoFreeTimerAvailable,
+        Self::DateOutOfRange,
+        Self::RecordingSequenceError,
+        Self::InvalidExternalPlugNumber,
+        Self::InvalidExternalPhysicalAddress,
+        Self::CaSystemNotSupported,
+        Self::NoOrInsufficientCaEntitlements,
+        Self::DoesNotSupportResolution,
+        Self::ParentalLockOn,
+        Self::ClockFailure,
+        Self::ReservedForFutureUseStart,
+        Self::ReservedForFutureUseEnd,
+        Self::DuplicateAlreadyProgrammed,
+    ];
+    const NAMED_UNKNOWN: Option<Self> = None;
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &[
+        "FutureUse",
+        "NoFreeTimerAvailable",
+        "DateOutOfRange",
+        "RecordingSequenceError",
+        "InvalidExternalPlugNumber",
+        "InvalidExternalPhysicalAddress",
+        "CaSystemNotSupported",
+        "NoOrInsufficientCaEntitlements",
+        "DoesNotSupportResolution",
+        "ParentalLockOn",
+        "ClockFailure",
+        "ReservedForFutureUseStart",
+        "ReservedForFutureUseEnd",
+        "DuplicateAlreadyProgrammed",
+        "Unknown",
+    ];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::FutureUse => "future use",
+            Self::NoFreeTimerAvailable => "no free timer available",
+            Self::DateOutOfRange => "date out of range",
+            Self::RecordingSequenceError => "recording sequence error",
+            Self::InvalidExternalPlugNumber => "invalid external plug number",
+            Self::InvalidExternalPhysicalAddress => "invalid external physical address",
+            Self::CaSystemNotSupported => "ca system not supported",
+            Self::NoOrInsufficientCaEntitlements => "no or insufficient ca entitlements",
+            Self::DoesNotSupportResolution => "does not support resolution",
+            Self::ParentalLockOn => "parental lock on",
+            Self::ClockFailure => "clock failure",
+            Self::ReservedForFutureUseStart => "reserved for future use start",
+            Self::ReservedForFutureUseEnd => "reserved for future use end",
+            Self::DuplicateAlreadyProgrammed => "duplicate already programmed",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_not_programmed_error_info) -> Self {
+        match c_enum_value!(raw) {
+            0 => Self::FutureUse,
+            1 => Self::NoFreeTimerAvailable,
+            2 => Self::DateOutOfRange,
+            3 => Self::RecordingSequenceError,
+            4 => Self::InvalidExternalPlugNumber,
+            5 => Self::InvalidExternalPhysicalAddress,
+            6 => Self::CaSystemNotSupported,
+            7 => Self::NoOrInsufficientCaEntitlements,
+            8 => Self::DoesNotSupportResolution,
+            9 => Self::ParentalLockOn,
+            10 => Self::ClockFailure,
+            11 => Self::ReservedForFutureUseStart,
+            13 => Self::ReservedForFutureUseEnd,
+            14 => Self::DuplicateAlreadyProgrammed,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_not_programmed_error_info {
+        match self {
+            Self::FutureUse => c_enum!(cec_not_programmed_error_info, 0),
+            Self::NoFreeTimerAvailable => c_enum!(cec_not_programmed_error_info, 1),
+            Self::DateOutOfRange => c_enum!(cec_not_programmed_error_info, 2),
+            Self::RecordingSequenceError => c_enum!(cec_not_programmed_error_info, 3),
+            Self::InvalidExternalPlugNumber => c_enum!(cec_not_programmed_error_info, 4),
+            Self::InvalidExternalPhysicalAddress => c_enum!(cec_not_programmed_error_info, 5),
+            Self::CaSystemNotSupported => c_enum!(cec_not_programmed_error_info, 6),
+            Self::NoOrInsufficientCaEntitlements => c_enum!(cec_not_programmed_error_info, 7),
+            Self::DoesNotSupportResolution => c_enum!(cec_not_programmed_error_info, 8),
+            Self::ParentalLockOn => c_enum!(cec_not_programmed_error_info, 9),
+            Self::ClockFailure => c_enum!(cec_not_programmed_error_info, 10),
+            Self::ReservedForFutureUseStart => c_enum!(cec_not_programmed_error_info, 11),
+            Self::ReservedForFutureUseEnd => c_enum!(cec_not_programmed_error_info, 13),
+            Self::DuplicateAlreadyProgrammed => c_enum!(cec_not_programmed_error_info, 14),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(NotProgrammedErrorInfo, cec_not_programmed_error_info);
+/// `cec_recording_flag`
+#[derive(Debug, Clone, Copy)]
+pub enum RecordingFlag {
+    /// `cec_recording_flag_NOT_BEING_USED_FOR_RECORDING`
+    NotBeingUsedForRecording,
+    /// `cec_recording_flag_BEING_USED_FOR_RECORDING`
+    BeingUsedForRecording,
+    /// Value not known to libcec
+    Unknown(cec_recording_flag),
+}
+impl RecordingFlag {
+    /// All known values
+    pub const ALL: &'static [Self] = &[Self::NotBeingUsedForRecording, Self::BeingUsedForRecording];
+    const NAMED_UNKNOWN: Option<Self> = None;
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &[
+        "NotBeingUsedForRecording",
+        "BeingUsedForRecording",
+        "Unknown",
+    ];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::NotBeingUsedForRecording => "not being used for recording",
+            Self::BeingUsedForRecording => "being used for recording",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_recording_flag) -> Self {
+        match c_enum_value!(raw) {
+            0 => Self::NotBeingUsedForRecording,
+            1 => Self::BeingUsedForRecording,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_recording_flag {
+        match self {
+            Self::NotBeingUsedForRecording => c_enum!(cec_recording_flag, 0),
+            Self::BeingUsedForRecording => c_enum!(cec_recording_flag, 1),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(RecordingFlag, cec_recording_flag);
+/// `cec_tuner_display_info`
+#[derive(Debug, Clone, Copy)]
+pub enum TunerDisplayInfo {
+    /// `cec_tuner_display_info_DISPLAYING_DIGITAL_TUNER`
+    DisplayingDigitalTuner,
+    /// `cec_tuner_display_info_NOT_DISPLAYING_TUNER`
+    NotDisplayingTuner,
+    /// `cec_tuner_display_info_DISPLAYING_ANALOGUE_TUNER`
+    DisplayingAnalogueTuner,
+    /// Value not known to libcec
+    Unknown(cec_tuner_display_info),
+}
+impl TunerDisplayInfo {
+    /// All known values
+    pub const ALL: &'static [Self] = &[
+        Self::DisplayingDigitalTuner,
+        Self::NotDisplayingTuner,
+        Self::DisplayingAnalogueTuner,
+    ];
+    const NAMED_UNKNOWN: Option<Self> = None;
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &[
+        "DisplayingDigitalTuner",
+        "NotDisplayingTuner",
+        "DisplayingAnalogueTuner",
+        "Unknown",
+    ];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::DisplayingDigitalTuner => "displaying digital tuner",
+            Self::NotDisplayingTuner => "not displaying tuner",
+            Self::DisplayingAnalogueTuner => "displaying analogue tuner",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_tuner_display_info) -> Self {
+        match c_enum_value!(raw) {
+            0 => Self::DisplayingDigitalTuner,
+            1 => Self::NotDisplayingTuner,
+            2 => Self::DisplayingAnalogueTuner,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_tuner_display_info {
+        match self {
+            Self::DisplayingDigitalTuner => c_enum!(cec_tuner_display_info, 0),
+            Self::NotDisplayingTuner => c_enum!(cec_tuner_display_info, 1),
+            Self::DisplayingAnalogueTuner => c_enum!(cec_tuner_display_info, 2),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(TunerDisplayInfo, cec_tuner_display_info);
+/// `cec_broadcast_system`
+#[derive(Debug, Clone, Copy)]
+pub enum BroadcastSystem {
+    /// `cec_broadcast_system_PAL_B_G`
+    PalBG,
+    /// `cec_broadcast_system_SECAM_L1`
+    SecamL1,
+    /// `cec_broadcast_system_PAL_M`
+    PalM,
+    /// `cec_broadcast_system_NTSC_M`
+    NtscM,
+    /// `cec_broadcast_system_PAL_I`
+    PalI,
+    /// `cec_broadcast_system_SECAM_DK`
+    SecamDk,
+    /// `cec_broadcast_system_SECAM_B_G`
+    SecamBG,
+    /// `cec_broadcast_system_SECAM_L2`
+    SecamL2,
+    /// `cec_broadcast_system_PAL_DK`
+    PalDk,
+    /// `cec_broadcast_system_OTHER_SYSTEM`
+    OtherSystem,
+    /// Value not known to libcec
+    Unknown(cec_broadcast_system),
+}
+impl BroadcastSystem {
+    /// All known values
+    pub const ALL: &'static [Self] = &[
+        Self::PalBG,
+        Self::SecamL1,
+        Self::PalM,
+        Self::NtscM,
+        Self::PalI,
+        Self::SecamDk,
+        Self::SecamBG,
+        Self::SecamL2,
+        Self::PalDk,
+        Self::OtherSystem,
+    ];
+    const NAMED_UNKNOWN: Option<Self> = None;
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &[
+        "PalBG",
+        "SecamL1",
+        "PalM",
+        "NtscM",
+        "PalI",
+        "SecamDk",
+        "SecamBG",
+        "SecamL2",
+        "PalDk",
+        "OtherSystem",
+        "Unknown",
+    ];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::PalBG => "pal b g",
+            Self::SecamL1 => "secam l1",
+            Self::PalM => "pal m",
+            Self::NtscM => "ntsc m",
+            Self::PalI => "pal i",
+            Self::SecamDk => "secam dk",
+            Self::SecamBG => "secam b g",
+            Self::SecamL2 => "secam l2",
+            Self::PalDk => "pal dk",
+            Self::OtherSystem => "other system",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_broadcast_system) -> Self {
+        match c_enum_value!(raw) {
+            0 => Self::PalBG,
+            1 => Self::SecamL1,
+            2 => Self::PalM,
+            3 => Self::NtscM,
+            4 => Self::PalI,
+            5 => Self::SecamDk,
+            6 => Self::SecamBG,
+            7 => Self::SecamL2,
+            8 => Self::PalDk,
+            30 => Self::OtherSystem,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_broadcast_system {
+        match self {
+            Self::PalBG => c_enum!(cec_broadcast_system, 0),
+            Self::SecamL1 => c_enum!(cec_broadcast_system, 1),
+            Self::PalM => c_enum!(cec_broadcast_system, 2),
+            Self::NtscM => c_enum!(cec_broadcast_system, 3),
+            Self::PalI => c_enum!(cec_broadcast_system, 4),
+            Self::SecamDk => c_enum!(cec_broadcast_system, 5),
+            Self::SecamBG => c_enum!(cec_broadcast_system, 6),
+            Self::SecamL2 => c_enum!(cec_broadcast_system, 7),
+            Self::PalDk => c_enum!(cec_broadcast_system, 8),
+            Self::OtherSystem => c_enum!(cec_broadcast_system, 30),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(BroadcastSystem, cec_broadcast_system);
+/// `cec_user_control_code`
+#[derive(Debug, Clone, Copy)]
+pub enum UserControlCode {
+    /// `cec_user_control_code_SELECT`
+    Select,
+    /// `cec_user_control_code_UP`
+    Up,
+    /// `cec_user_control_code_DOWN`
+    Down,
+    /// `cec_user_control_code_LEFT`
+    Left,
+    /// `cec_user_control_code_RIGHT`
+    Right,
+    /// `cec_user_control_code_RIGHT_UP`
+    RightUp,
+    /// `cec_user_control_code_RIGHT_DOWN`
+    RightDown,
+    /// `cec_user_control_code_LEFT_UP`
+    LeftUp,
+    /// `cec_user_control_code_LEFT_DOWN`
+    LeftDown,
+    /// `cec_user_control_code_ROOT_MENU`
+    RootMenu,
+    /// `cec_user_control_code_SETUP_MENU`
+    SetupMenu,
+    /// `cec_user_control_code_CONTENTS_MENU`
+    ContentsMenu,
+    /// `cec_user_control_code_FAVORITE_MENU`
+    FavoriteMenu,
+    /// `cec_user_control_code_EXIT`
+    Exit,
+    /// `cec_user_control_code_TOP_MENU`
+    TopMenu,
+    /// `cec_user_control_code_DVD_MENU`
+    DvdMenu,
+    /// `cec_user_control_code_NUMBER_ENTRY_MODE`
+    NumberEntryMode,
+    /// `cec_user_control_code_NUMBER11`
+    Number11,
+    /// `cec_user_control_code_NUMBER12`
+    Number12,
+    /// `cec_user_control_code_NUMBER0`
+    Number0,
+    /// `cec_user_control_code_NUMBER1`
+    Number1,
+    /// `cec_user_control_code_NUMBER2`
+    Number2,
+    /// `cec_user_control_code_NUMBER3`
+    Number3,
+    /// `cec_user_control_code_NUMBER4`
+    Number4,
+    /// `cec_user_control_code_NUMBER5`
+    Number5,
+    /// `cec_user_control_code_NUMBER6`
+    Number6,
+    /// `cec_user_control_code_NUMBER7`
+    Number7,
+    /// `cec_user_control_code_NUMBER8`
+    Number8,
+    /// `cec_user_control_code_NUMBER9`
+    Number9,
+    /// `cec_user_control_code_DOT`
+    Dot,
+    /// `cec_user_control_code_ENTER`
+    Enter,
+    /// `cec_user_control_code_CLEAR`
+    Clear,
+    /// `cec_user_control_code_NEXT_FAVORITE`
+    NextFavorite,
+    /// `cec_user_control_code_CHANNEL_UP`
+    ChannelUp,
+    /// `cec_user_control_code_CHANNEL_DOWN`
+    ChannelDown,
+    /// `cec_user_control_code_PREVIOUS_CHANNEL`
+    PreviousChannel,
+    /// `cec_user_control_code_SOUND_SELECT`
+    SoundSelect,
+    /// `cec_user_control_code_INPUT_SELECT`
+    InputSelect,
+    /// `cec_user_control_code_DISPLAY_INFORMATION`
+    DisplayInformation,
+    /// `cec_user_control_code_HELP`
+    Help,
+    /// `cec_user_control_code_PAGE_UP`
+    PageUp,
+    /// `cec_user_control_code_PAGE_DOWN`
+    PageDown,
+    /// `cec_user_control_code_POWER`
+    Power,
+    /// `cec_user_control_code_VOLUME_UP`
+    VolumeUp,
+    /// `cec_user_control_code_VOLUME_DOWN`
+    VolumeDown,
+    /// `cec_user_control_code_MUTE`
+    Mute,
+    /// `cec_user_control_code_PLAY`
+    Play,
+    /// `cec_user_control_code_STOP`
+    Stop,
+    /// `cec_user_control_code_PAUSE`
+    Pause,
+    /// `cec_user_control_code_RECORD`
+    Record,
+    /// `cec_user_control_code_REWIND`
+    Rewind,
+    /// `cec_user_control_code_FAST_FORWARD`
+    FastForward,
+    /// `cec_user_control_code_EJECT`
+    Eject,
+    /// `cec_user_control_code_FORWARD`
+    Forward,
+    /// `cec_user_control_code_BACKWARD`
+    Backward,
+    /// `cec_user_control_code_STOP_RECORD`
+    StopRecord,
+    /// `cec_user_control_code_PAUSE_RECORD`
+    PauseRecord,
+    /// `cec_user_control_code_ANGLE`
+    Angle,
+    /// `cec_user_control_code_SUB_PICTURE`
+    SubPicture,
+    /// `cec_user_control_code_VIDEO_ON_DEMAND`
+    VideoOnDemand,
+    /// `cec_user_control_code_ELECTRONIC_PROGRAM_GUIDE`
+    ElectronicProgramGuide,
+    /// `cec_user_control_code_TIMER_PROGRAMMING`
+    TimerProgramming,
+    /// `cec_user_control_code_INITIAL_CONFIGURATION`
+    InitialConfiguration,
+    /// `cec_user_control_code_SELECT_BROADCAST_TYPE`
+    SelectBroadcastType,
+    /// `cec_user_control_code_SELECT_SOUND_PRESENTATION`
+    SelectSoundPresentation,
+    /// `cec_user_control_code_PLAY_FUNCTION`
+    PlayFunction,
+    /// `cec_user_control_code_PAUSE_PLAY_FUNCTION`
+    PausePlayFunction,
+    /// `cec_user_control_code_RECORD_FUNCTION`
+    RecordFunction,
+    /// `cec_user_control_code_PAUSE_RECORD_FUNCTION`
+    PauseRecordFunction,
+    /// `cec_user_control_code_STOP_FUNCTION`
+    StopFunction,
+    /// `cec_user_control_code_MUTE_FUNCTION`
+    MuteFunction,
+    /// `cec_user_control_code_RESTORE_VOLUME_FUNCTION`
+    RestoreVolumeFunction,
+    /// `cec_user_control_code_TUNE_FUNCTION`
+    TuneFunction,
+    /// `cec_user_control_code_SELECT_MEDIA_FUNCTION`
+    SelectMediaFunction,
+    /// `cec_user_control_code_SELECT_AV_INPUT_FUNCTION`
+    SelectAvInputFunction,
+    /// `cec_user_control_code_SELECT_AUDIO_INPUT_FUNCTION`
+    SelectAudioInputFunction,
+    /// `cec_user_control_code_POWER_TOGGLE_FUNCTION`
+    PowerToggleFunction,
+    /// `cec_user_control_code_POWER_OFF_FUNCTION`
+    PowerOffFunction,
+    /// `cec_user_control_code_POWER_ON_FUNCTION`
+    PowerOnFunction,
+    /// `cec_user_control_code_F1_BLUE`
+    F1Blue,
+    /// `cec_user_control_code_F2_RED`
+    F2Red,
+    /// `cec_user_control_code_F3_GREEN`
+    F3Green,
+    /// `cec_user_control_code_F4_YELLOW`
+    F4Yellow,
+    /// `cec_user_control_code_F5`
+    F5,
+    /// `cec_user_control_code_DATA`
+    Data,
+    /// `cec_user_control_code_AN_RETURN`
+    AnReturn,
+    /// `cec_user_control_code_AN_CHANNELS_LIST`
+    AnChannelsList,
+    /// Value not known to libcec
+    Unknown(cec_user_control_code),
+}
+impl UserControlCode {
+    /// `cec_user_control_code_MAX`
+    pub const MAX: Self = Self::AnChannelsList;
+    /// `cec_user_control_code_UNKNOWN`
+    pub const UNKNOWN: Self = Self::Unknown(c_enum!(cec_user_control_code, 255));
+    /// All known values
+    pub const ALL: &'static [Self] = &[
+        Self::Select,
+        Self::Up,
+        Self::Down,
+        Self::Left,
+        Self::Right,
+        Self::RightUp,
+        Self::RightDown,
+        Self::LeftUp,
+        Self::LeftDown,
+        Self::RootMenu,
+        Self::SetupMenu,
+        Self::ContentsMenu,
+        Self::FavoriteMenu,
+        Self::Exit,
+        Self::TopMenu,
+        Self::DvdMenu,
+        Self::NumberEntryMode,
+        Self::Number11,
+        Self::Number12,
+        Self::Number0,
+        Self::Number1,
+        Self::Number2,
+        Self::Number3,
+        Self::Number4,
+        Self::Number5,
+        Self::Number6,
+        Self::Number7,
+        Self::Number8,
+        Self::Number9,
+        Self::Dot,
+        Self::Enter,
+        Self::Clear,
+        Self::NextFavorite,
+        Self::ChannelUp,
+        Self::ChannelDown,
+        Self::PreviousChannel,
+        Self::SoundSelect,
+        Self::InputSelect,
+        Self::DisplayInformation,
+        Self::Help,
+        Self::PageUp,
+        Self::PageDown,
+        Self::Power,
+        Self::VolumeUp,
+        Self::VolumeDown,
+        Self::Mute,
+        Self::Play,
+        Self::Stop,
+        Self::Pause,
+        Self::Record,
+        Self::Rewind,
+        Self::FastForward,
+        Self::Eject,
+        Self::Forward,
+        Self::Backward,
+        Self::StopRecord,
+        Self::PauseRecord,
+        Self::Angle,
+        Self::SubPicture,
+        Self::VideoOnDemand,
+        Self::ElectronicProgramGuide,
+        Self::TimerProgramming,
+        Self::InitialConfiguration,
+        Self::SelectBroadcastType,
+        Self::SelectSoundPresentation,
+        Self::PlayFunction,
+        Self::PausePlayFunction,
+        Self::RecordFunction,
+        Self::PauseRecordFunction,
+        Self::StopFunction,
+        Self::MuteFunction,
+        Self::RestoreVolumeFunction,
+        Self::TuneFunction,
+        Self::SelectMediaFunction,
+        Self::SelectAvInputFunction,
+        Self::SelectAudioInputFunction,
+        Self::PowerToggleFunction,
+        Self::PowerOffFunction,
+        Self::PowerOnFunction,
+        Self::F1Blue,
+        Self::F2Red,
+        Self::F3Green,
+        Self::F4Yellow,
+        Self::F5,
+        Self::Data,
+        Self::AnReturn,
+        Self::AnChannelsList,
+    ];
+    const NAMED_UNKNOWN: Option<Self> = Some(Self::UNKNOWN);
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &[
+        "Select",
+        "Up",
+        "Down",
+        "Left",
+        "Right",
+        "RightUp",
+        "RightDown",
+        "LeftUp",
+        "LeftDown",
+        "RootMenu",
+        "SetupMenu",
+        "ContentsMenu",
+        "FavoriteMenu",
+        "Exit",
+        "TopMenu",
+        "DvdMenu",
+        "NumberEntryMode",
+        "Number11",
+        "Number12",
+        "Number0",
+        "Number1",
+        "Number2",
+        "Number3",
+        "Number4",
+        "Number5",
+        "Number6",
+        "Number7",
+        "Number8",
+        "Number9",
+        "Dot",
+        "Enter",
+        "Clear",
+        "NextFavorite",
+        "ChannelUp",
+        "ChannelDown",
+        "PreviousChannel",
+        "SoundSelect",
+        "InputSelect",
+        "DisplayInformation",
+        "Help",
+        "PageUp",
+        "PageDown",
+        "Power",
+        "VolumeUp",
+        "VolumeDown",
+        "Mute",
+        "Play",
+        "Stop",
+        "Pause",
+        "Record",
+        "Rewind",
+        "FastForward",
+        "Eject",
+        "Forward",
+        "Backward",
+        "StopRecord",
+        "PauseRecord",
+        "Angle",
+        "SubPicture",
+        "VideoOnDemand",
+        "ElectronicProgramGuide",
+        "TimerProgramming",
+        "InitialConfiguration",
+        "SelectBroadcastType",
+        "SelectSoundPresentation",
+        "PlayFunction",
+        "PausePlayFunction",
+        "RecordFunction",
+        "PauseRecordFunction",
+        "StopFunction",
+        "MuteFunction",
+        "RestoreVolumeFunction",
+        "TuneFunction",
+        "SelectMediaFunction",
+        "SelectAvInputFunction",
+        "SelectAudioInputFunction",
+        "PowerToggleFunction",
+        "PowerOffFunction",
+        "PowerOnFunction",
+        "F1Blue",
+        "F2Red",
+        "F3Green",
+        "F4Yellow",
+        "F5",
+        "Data",
+        "AnReturn",
+        "AnChannelsList",
+        "UNKNOWN",
+        "Unknown",
+    ];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Select => "select",
+            Self::Up => "up",
+            Self::Down => "down",
+            Self::Left => "left",
+            Self::Right => "right",
+            Self::RightUp => "right+up",
+            Self::RightDown => "right+down",
+            Self::LeftUp => "left+up",
+            Self::LeftDown => "left+down",
+            Self::RootMenu => "root menu",
+            Self::SetupMenu => "setup menu",
+            Self::ContentsMenu => "contents menu",
+            Self::FavoriteMenu => "favourite menu",
+            Self::Exit => "exit",
+            Self::TopMenu => "top menu",
+            Self::DvdMenu => "dvd menu",
+            Self::NumberEntryMode => "number entry mode",
+            Self::Number11 => "11",
+            Self::Number12 => "12",
+            Self::Number0 => "0",
+            Self::Number1 => "1",
+            Self::Number2 => "2",
+            Self::Number3 => "3",
+            Self::Number4 => "4",
+            Self::Number5 => "5",
+            Self::Number6 => "6",
+            Self::Number7 => "7",
+            Self::Number8 => "8",
+            Self::Number9 => "9",
+            Self::Dot => ".",
+            Self::Enter => "enter",
+            Self::Clear => "clear",
+            Self::NextFavorite => "next favourite",
+            Self::ChannelUp => "channel up",
+            Self::ChannelDown => "channel down",
+            Self::PreviousChannel => "previous channel",
+            Self::SoundSelect => "sound select",
+            Self::InputSelect => "input select",
+            Self::DisplayInformation => "display information",
+            Self::Help => "help",
+            Self::PageUp => "page up",
+            Self::PageDown => "page down",
+            Self::Power => "power",
+            Self::VolumeUp => "volume up",
+            Self::VolumeDown => "volume down",
+            Self::Mute => "mute",
+            Self::Play => "play",
+            Self::Stop => "stop",
+            Self::Pause => "pause",
+            Self::Record => "record",
+            Self::Rewind => "rewind",
+            Self::FastForward => "Fast forward",
+            Self::Eject => "eject",
+            Self::Forward => "forward",
+            Self::Backward => "backward",
+            Self::StopRecord => "stop record",
+            Self::PauseRecord => "pause record",
+            Self::Angle => "angle",
+            Self::SubPicture => "sub picture",
+            Self::VideoOnDemand => "video on demand",
+            Self::ElectronicProgramGuide => "electronic program guide",
+            Self::TimerProgramming => "timer programming",
+            Self::InitialConfiguration => "initial configuration",
+            Self::SelectBroadcastType => "select broadcast type",
+            Self::SelectSoundPresentation => "select sound presentation",
+            Self::PlayFunction => "play (function)",
+            Self::PausePlayFunction => "pause play (function)",
+            Self::RecordFunction => "record (function)",
+            Self::PauseRecordFunction => "pause record (function)",
+            Self::StopFunction => "stop (function)",
+            Self::MuteFunction => "mute (function)",
+            Self::RestoreVolumeFunction => "restore volume",
+            Self::TuneFunction => "tune",
+            Self::SelectMediaFunction => "select media",
+            Self::SelectAvInputFunction => "select AV input",
+            Self::SelectAudioInputFunction => "select audio input",
+            Self::PowerToggleFunction => "power toggle",
+            Self::PowerOffFunction => "power off",
+            Self::PowerOnFunction => "power on",
+            Self::F1Blue => "F1 (blue)",
+            Self::F2Red => "F2 (red)",
+            Self::F3Green => "F3 (green)",
+            Self::F4Yellow => "F4 (yellow)",
+            Self::F5 => "F5",
+            Self::Data => "data",
+            Self::AnReturn => "return (Samsung)",
+            Self::AnChannelsList => "channels list (Samsung)",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_user_control_code) -> Self {
+        match c_enum_value!(raw) {
+            0 => Self::Select,
+            1 => Self::Up,
+            2 => Self::Down,
+            3 => Self::Left,
+            4 => Self::Right,
+            5 => Self::RightUp,
+            6 => Self::RightDown,
+            7 => Self::LeftUp,
+            8 => Self::LeftDown,
+            9 => Self::RootMenu,
+            10 => Self::SetupMenu,
+            11 => Self::ContentsMenu,
+            12 => Self::FavoriteMenu,
+            13 => Self::Exit,
+            16 => Self::TopMenu,
+            17 => Self::DvdMenu,
+            29 => Self::NumberEntryMode,
+            30 => Self::Number11,
+            31 => Self::Number12,
+            32 => Self::Number0,
+            33 => Self::Number1,
+            34 => Self::Number2,
+            35 => Self::Number3,
+            36 => Self::Number4,
+            37 => Self::Number5,
+            38 => Self::Number6,
+            39 => Self::Number7,
+            40 => Self::Number8,
+            41 => Self::Number9,
+            42 => Self::Dot,
+            43 => Self::Enter,
+            44 => Self::Clear,
+            47 => Self::NextFavorite,
+            48 => Self::ChannelUp,
+            49 => Self::ChannelDown,
+            50 => Self::PreviousChannel,
+            51 => Self::SoundSelect,
+            52 => Self::InputSelect,
+            53 => Self::DisplayInformation,
+            54 => Self::Help,
+            55 => Self::PageUp,
+            56 => Self::PageDown,
+            64 => Self::Power,
+            65 => Self::VolumeUp,
+            66 => Self::VolumeDown,
+            67 => Self::Mute,
+            68 => Self::Play,
+            69 => Self::Stop,
+            70 => Self::Pause,
+            71 => Self::Record,
+            72 => Self::Rewind,
+            73 => Self::FastForward,
+            74 => Self::Eject,
+            75 => Self::Forward,
+            76 => Self::Backward,
+            77 => Self::StopRecord,
+            78 => Self::PauseRecord,
+            80 => Self::Angle,
+            81 => Self::SubPicture,
+            82 => Self::VideoOnDemand,
+            83 => Self::ElectronicProgramGuide,
+            84 => Self::TimerProgramming,
+            85 => Self::InitialConfiguration,
+            86 => Self::SelectBroadcastType,
+            87 => Self::SelectSoundPresentation,
+            96 => Self::PlayFunction,
+            97 => Self::PausePlayFunction,
+            98 => Self::RecordFunction,
+            99 => Self::PauseRecordFunction,
+            100 => Self::StopFunction,
+            101 => Self::MuteFunction,
+            102 => Self::RestoreVolumeFunction,
+            103 => Self::TuneFunction,
+            104 => Self::SelectMediaFunction,
+            105 => Self::SelectAvInputFunction,
+            106 => Self::SelectAudioInputFunction,
+            107 => Self::PowerToggleFunction,
+            108 => Self::PowerOffFunction,
+            109 => Self::PowerOnFunction,
+            113 => Self::F1Blue,
+            114 => Self::F2Red,
+            115 => Self::F3Green,
+            116 => Self::F4Yellow,
+            117 => Self::F5,
+            118 => Self::Data,
+            145 => Self::AnReturn,
+            150 => Self::AnChannelsList,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_user_control_code {
+        match self {
+            Self::Select => c_enum!(cec_user_control_code, 0),
+            Self::Up => c_enum!(cec_user_control_code, 1),
+            Self::Down => c_enum!(cec_user_control_code, 2),
+            Self::Left => c_enum!(cec_user_control_code, 3),
+            Self::Right => c_enum!(cec_user_control_code, 4),
+            Self::RightUp => c_enum!(cec_user_control_code, 5),
+            Self::RightDown => c_enum!(cec_user_control_code, 6),
+            Self::LeftUp => c_enum!(cec_user_control_code, 7),
+            Self::LeftDown => c_enum!(cec_user_control_code, 8),
+            Self::RootMenu => c_enum!(cec_user_control_code, 9),
+            Self::SetupMenu => c_enum!(cec_user_control_code, 10),
+            Self::ContentsMenu => c_enum!(cec_user_control_code, 11),
+            Self::FavoriteMenu => c_enum!(cec_user_control_code, 12),
+            Self::Exit => c_enum!(cec_user_control_code, 13),
+            Self::TopMenu => c_enum!(cec_user_control_code, 16),
+            Self::DvdMenu => c_enum!(cec_user_control_code, 17),
+            Self::NumberEntryMode => c_enum!(cec_user_control_code, 29),
+            Self::Number11 => c_enum!(cec_user_control_code, 30),
+            Self::Number12 => c_enum!(cec_user_control_code, 31),
+            Self::Number0 => c_enum!(cec_user_control_code, 32),
+            Self::Number1 => c_enum!(cec_user_control_code, 33),
+            Self::Number2 => c_enum!(cec_user_control_code, 34),
+            Self::Number3 => c_enum!(cec_user_control_code, 35),
+            Self::Number4 => c_enum!(cec_user_control_code, 36),
+            Self::Number5 => c_enum!(cec_user_control_code, 37),
+            Self::Number6 => c_enum!(cec_user_control_code, 38),
+            Self::Number7 => c_enum!(cec_user_control_code, 39),
+            Self::Number8 => c_enum!(cec_user_control_code, 40),
+            Self::Number9 => c_enum!(cec_user_control_code, 41),
+            Self::Dot => c_enum!(cec_user_control_code, 42),
+            Self::Enter => c_enum!(cec_user_control_code, 43),
+            Self::Clear => c_enum!(cec_user_control_code, 44),
+            Self::NextFavorite => c_enum!(cec_user_control_code, 47),
+            Self::ChannelUp => c_enum!(cec_user_control_code, 48),
+            Self::ChannelDown => c_enum!(cec_user_control_code, 49),
+            Self::PreviousChannel => c_enum!(cec_user_control_code, 50),
+            Self::SoundSelect => c_enum!(cec_user_control_code, 51),
+            Self::InputSelect => c_enum!(cec_user_control_code, 52),
+            Self::DisplayInformation => c_enum!(cec_user_control_code, 53),
+            Self::Help => c_enum!(cec_user_control_code, 54),
+            Self::PageUp => c_enum!(cec_user_control_code, 55),
+            Self::PageDown => c_enum!(cec_user_control_code, 56),
+            Self::Power => c_enum!(cec_user_control_code, 64),
+            Self::VolumeUp => c_enum!(cec_user_control_code, 65),
+            Self::VolumeDown => c_enum!(cec_user_control_code, 66),
+            Self::Mute => c_enum!(cec_user_control_code, 67),
+            Self::Play => c_enum!(cec_user_control_code, 68),
+            Self::Stop => c_enum!(cec_user_control_code, 69),
+            Self::Pause => c_enum!(cec_user_control_code, 70),
+            Self::Record => c_enum!(cec_user_control_code, 71),
+            Self::Rewind => c_enum!(cec_user_control_code, 72),
+            Self::FastForward => c_enum!(cec_user_control_code, 73),
+            Self::Eject => c_enum!(cec_user_control_code, 74),
+            Self::Forward => c_enum!(cec_user_control_code, 75),
+            Self::Backward => c_enum!(cec_user_control_code, 76),
+            Self::StopRecord => c_enum!(cec_user_control_code, 77),
+            Self::PauseRecord => c_enum!(cec_user_control_code, 78),
+            Self::Angle => c_enum!(cec_user_control_code, 80),
+            Self::SubPicture => c_enum!(cec_user_control_code, 81),
+            Self::VideoOnDemand => c_enum!(cec_user_control_code, 82),
+            Self::ElectronicProgramGuide => c_enum!(cec_user_control_code, 83),
+            Self::TimerProgramming => c_enum!(cec_user_control_code, 84),
+            Self::InitialConfiguration => c_enum!(cec_user_control_code, 85),
+            Self::SelectBroadcastType => c_enum!(cec_user_control_code, 86),
+            Self::SelectSoundPresentation => c_enum!(cec_user_control_code, 87),
+            Self::PlayFunction => c_enum!(cec_user_control_code, 96),
+            Self::PausePlayFunction => c_enum!(cec_user_control_code, 97),
+            Self::RecordFunction => c_enum!(cec_user_control_code, 98),
+            Self::PauseRecordFunction => c_enum!(cec_user_control_code, 99),
+            Self::StopFunction => c_enum!(cec_user_control_code, 100),
+            Self::MuteFunction => c_enum!(cec_user_control_code, 101),
+            Self::RestoreVolumeFunction => c_enum!(cec_user_control_code, 102),
+            Self::TuneFunction => c_enum!(cec_user_control_code, 103),
+            Self::SelectMediaFunction => c_enum!(cec_user_control_code, 104),
+            Self::SelectAvInputFunction => c_enum!(cec_user_control_code, 105),
+            Self::SelectAudioInputFunction => c_enum!(cec_user_control_code, 106),
+            Self::PowerToggleFunction => c_enum!(cec_user_control_code, 107),
+            Self::PowerOffFunction => c_enum!(cec_user_control_code, 108),
+            Self::PowerOnFunction => c_enum!(cec_user_control_code, 109),
+            Self::F1Blue => c_enum!(cec_user_control_code, 113),
+            Self::F2Red => c_enum!(cec_user_control_code, 114),
+            Self::F3Green => c_enum!(cec_user_control_code, 115),
+            Self::F4Yellow => c_enum!(cec_user_control_code, 116),
+            Self::F5 => c_enum!(cec_user_control_code, 117),
+            Self::Data => c_enum!(cec_user_control_code, 118),
+            Self::AnReturn => c_enum!(cec_user_control_code, 145),
+            Self::AnChannelsList => c_enum!(cec_user_control_code, 150),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(UserControlCode, cec_user_control_code);
+/// `cec_logical_address`
+#[derive(Debug, Clone, Copy)]
+pub enum LogicalAddress {
+    /// `cec_logical_address_TV`
+    Tv,
+    /// `cec_logical_address_RECORDINGDEVICE1`
+    RecordingDevice1,
+    /// `cec_logical_address_RECORDINGDEVICE2`
+    RecordingDevice2,
+    /// `cec_logical_address_TUNER1`
+    Tuner1,
+    /// `cec_logical_address_PLAYBACKDEVICE1`
+    PlaybackDevice1,
+    /// `cec_logical_address_AUDIOSYSTEM`
+    AudioSystem,
+    /// `cec_logical_address_TUNER2`
+    Tuner2,
+    /// `cec_logical_address_TUNER3`
+    Tuner3,
+    /// `cec_logical_address_PLAYBACKDEVICE2`
+    PlaybackDevice2,
+    /// `cec_logical_address_RECORDINGDEVICE3`
+    RecordingDevice3,
+    /// `cec_logical_address_TUNER4`
+    Tuner4,
+    /// `cec_logical_address_PLAYBACKDEVICE3`
+    PlaybackDevice3,
+    /// `cec_logical_address_RESERVED1`
+    Reserved1,
+    /// `cec_logical_address_RESERVED2`
+    Reserved2,
+    /// `cec_logical_address_FREEUSE`
+    FreeUse,
+    /// `cec_logical_address_BROADCAST`
+    Broadcast,
+    /// Value not known to libcec
+    Unknown(cec_logical_address),
+}
+impl LogicalAddress {
+    /// `cec_logical_address_UNKNOWN`
+    pub const UNKNOWN: Self = Self::Unknown(c_enum!(cec_logical_address, -1));
+    /// `cec_logical_address_UNREGISTERED`
+    pub const UNREGISTERED: Self = Self::Broadcast;
+    /// All known values
+    pub const ALL: &'static [Self] = &[
+        Self::Tv,
+        Self::RecordingDevice1,
+        Self::RecordingDevice2,
+        Self::Tuner1,
+        Self::PlaybackDevice1,
+        Self::AudioSystem,
+        Self::Tuner2,
+        Self::Tuner3,
+        Self::PlaybackDevice2,
+        Self::RecordingDevice3,
+        Self::Tuner4,
+        Self::PlaybackDevice3,
+        Self::Reserved1,
+        Self::Reserved2,
+        Self::FreeUse,
+        Self::Broadcast,
+    ];
+    const NAMED_UNKNOWN: Option<Self> = Some(Self::UNKNOWN);
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &[
+        "Tv",
+        "RecordingDevice1",
+        "RecordingDevice2",
+        "Tuner1",
+        "PlaybackDevice1",
+        "AudioSystem",
+        "Tuner2",
+        "Tuner3",
+        "PlaybackDevice2",
+        "RecordingDevice3",
+        "Tuner4",
+        "PlaybackDevice3",
+        "Reserved1",
+        "Reserved2",
+        "FreeUse",
+        "Broadcast",
+        "UNKNOWN",
+        "Unknown",
+    ];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Tv => "TV",
+            Self::RecordingDevice1 => "Recorder 1",
+            Self::RecordingDevice2 => "Recorder 2",
+            Self::Tuner1 => "Tuner 1",
+            Self::PlaybackDevice1 => "Playback 1",
+            Self::AudioSystem => "Audio",
+            Self::Tuner2 => "Tuner 2",
+            Self::Tuner3 => "Tuner 3",
+            Self::PlaybackDevice2 => "Playback 2",
+            Self::RecordingDevice3 => "Recorder 3",
+            Self::Tuner4 => "Tuner 4",
+            Self::PlaybackDevice3 => "Playback 3",
+            Self::Reserved1 => "Reserved 1",
+            Self::Reserved2 => "Reserved 2",
+            Self::FreeUse => "Free use",
+            Self::Broadcast => "Broadcast",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_logical_address) -> Self {
+        match c_enum_value!(raw) {
+            0 => Self::Tv,
+            1 => Self::RecordingDevice1,
+            2 => Self::RecordingDevice2,
+            3 => Self::Tuner1,
+            4 => Self::PlaybackDevice1,
+            5 => Self::AudioSystem,
+            6 => Self::Tuner2,
+            7 => Self::Tuner3,
+            8 => Self::PlaybackDevice2,
+            9 => Self::RecordingDevice3,
+            10 => Self::Tuner4,
+            11 => Self::PlaybackDevice3,
+            12 => Self::Reserved1,
+            13 => Self::Reserved2,
+            14 => Self::FreeUse,
+            15 => Self::Broadcast,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_logical_address {
+        match self {
+            Self::Tv => c_enum!(cec_logical_address, 0),
+            Self::RecordingDevice1 => c_enum!(cec_logical_address, 1),
+            Self::RecordingDevice2 => c_enum!(cec_logical_address, 2),
+            Self::Tuner1 => c_enum!(cec_logical_address, 3),
+            Self::PlaybackDevice1 => c_enum!(cec_logical_address, 4),
+            Self::AudioSystem => c_enum!(cec_logical_address, 5),
+            Self::Tuner2 => c_enum!(cec_logical_address, 6),
+            Self::Tuner3 => c_enum!(cec_logical_address, 7),
+            Self::PlaybackDevice2 => c_enum!(cec_logical_address, 8),
+            Self::RecordingDevice3 => c_enum!(cec_logical_address, 9),
+            Self::Tuner4 => c_enum!(cec_logical_address, 10),
+            Self::PlaybackDevice3 => c_enum!(cec_logical_address, 11),
+            Self::Reserved1 => c_enum!(cec_logical_address, 12),
+            Self::Reserved2 => c_enum!(cec_logical_address, 13),
+            Self::FreeUse => c_enum!(cec_logical_address, 14),
+            Self::Broadcast => c_enum!(cec_logical_address, 15),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(LogicalAddress, cec_logical_address);
+/// `cec_opcode`
+#[derive(Debug, Clone, Copy)]
+pub enum Opcode {
+    /// `cec_opcode_ACTIVE_SOURCE`
+    ActiveSource,
+    /// `cec_opcode_IMAGE_VIEW_ON`
+    ImageViewOn,
+    /// `cec_opcode_TEXT_VIEW_ON`
+    TextViewOn,
+    /// `cec_opcode_INACTIVE_SOURCE`
+    InactiveSource,
+    /// `cec_opcode_REQUEST_ACTIVE_SOURCE`
+    RequestActiveSource,
+    /// `cec_opcode_ROUTING_CHANGE`
+    RoutingChange,
+    /// `cec_opcode_ROUTING_INFORMATION`
+    RoutingInformation,
+    /// `cec_opcode_SET_STREAM_PATH`
+    SetStreamPath,
+    /// `cec_opcode_STANDBY`
+    Standby,
+    /// `cec_opcode_RECORD_OFF`
+    RecordOff,
+    /// `cec_opcode_RECORD_ON`
+    RecordOn,
+    /// `cec_opcode_RECORD_STATUS`
+    RecordStatus,
+    /// `cec_opcode_RECORD_TV_SCREEN`
+    RecordTvScreen,
+    /// `cec_opcode_CLEAR_ANALOGUE_TIMER`
+    ClearAnalogueTimer,
+    /// `cec_opcode_CLEAR_DIGITAL_TIMER`
+    ClearDigitalTimer,
+    /// `cec_opcode_CLEAR_EXTERNAL_TIMER`
+    ClearExternalTimer,
+    /// `cec_opcode_SET_ANALOGUE_TIMER`
+    SetAnalogueTimer,
+    /// `cec_opcode_SET_DIGITAL_TIMER`
+    SetDigitalTimer,
+    /// `cec_opcode_SET_EXTERNAL_TIMER`
+    SetExternalTimer,
+    /// `cec_opcode_SET_TIMER_PROGRAM_TITLE`
+    SetTimerProgramTitle,
+    /// `cec_opcode_TIMER_CLEARED_STATUS`
+    TimerClearedStatus,
+    /// `cec_opcode_TIMER_STATUS`
+    TimerStatus,
+    /// `cec_opcode_CEC_VERSION`
+    CecVersion,
+    /// `cec_opcode_GET_CEC_VERSION`
+    GetCecVersion,
+    /// `cec_opcode_GIVE_PHYSICAL_ADDRESS`
+    GivePhysicalAddress,
+    /// `cec_opcode_GET_MENU_LANGUAGE`
+    GetMenuLanguage,
+    /// `cec_opcode_REPORT_PHYSICAL_ADDRESS`
+    ReportPhysicalAddress,
+    /// `cec_opcode_SET_MENU_LANGUAGE`
+    SetMenuLanguage,
+    /// `cec_opcode_DECK_CONTROL`
+    DeckControl,
+    /// `cec_opcode_DECK_STATUS`
+    DeckStatus,
+    /// `cec_opcode_GIVE_DECK_STATUS`
+    GiveDeckStatus,
+    /// `cec_opcode_PLAY`
+    Play,
+    /// `cec_opcode_GIVE_TUNER_DEVICE_STATUS`
+    GiveTunerDeviceStatus,
+    /// `cec_opcode_SELECT_ANALOGUE_SERVICE`
+    SelectAnalogueService,
+    /// `cec_opcode_SELECT_DIGITAL_SERVICE`
+    SelectDigitalService,
+    /// `cec_opcode_TUNER_DEVICE_STATUS`
+    TunerDeviceStatus,
+    /// `cec_opcode_TUNER_STEP_DECREMENT`
+    TunerStepDecrement,
+    /// `cec_opcode_TUNER_STEP_INCREMENT`
+    TunerStepIncrement,
+    /// `cec_opcode_DEVICE_VENDOR_ID`
+    DeviceVendorId,
+    /// `cec_opcode_GIVE_DEVICE_VENDOR_ID`
+    GiveDeviceVendorId,
+    /// `cec_opcode_VENDOR_COMMAND`
+    VendorCommand,
+    /// `cec_opcode_VENDOR_COMMAND_WITH_ID`
+    VendorCommandWithId,
+    /// `cec_opcode_VENDOR_REMOTE_BUTTON_DOWN`
+    VendorRemoteButtonDown,
+    /// `cec_opcode_VENDOR_REMOTE_BUTTON_UP`
+    VendorRemoteButtonUp,
+    /// `cec_opcode_SET_OSD_STRING`
+    SetOsdString,
+    /// `cec_opcode_GIVE_OSD_NAME`
+    GiveOsdName,
+    /// `cec_opcode_SET_OSD_NAME`
+    SetOsdName,
+    /// `cec_opcode_MENU_REQUEST`
+    MenuRequest,
+    /// `cec_opcode_MENU_STATUS`
+    MenuStatus,
+    /// `cec_opcode_USER_CONTROL_PRESSED`
+    UserControlPressed,
+    /// `cec_opcode_USER_CONTROL_RELEASE`
+    UserControlRelease,
+    /// `cec_opcode_GIVE_DEVICE_POWER_STATUS`
+    GiveDevicePowerStatus,
+    /// `cec_opcode_REPORT_POWER_STATUS`
+    ReportPowerStatus,
+    /// `cec_opcode_FEATURE_ABORT`
+    FeatureAbort,
+    /// `cec_opcode_ABORT`
+    Abort,
+    /// `cec_opcode_GIVE_AUDIO_STATUS`
+    GiveAudioStatus,
+    /// `cec_opcode_GIVE_SYSTEM_AUDIO_MODE_STATUS`
+    GiveSystemAudioModeStatus,
+    /// `cec_opcode_REPORT_AUDIO_STATUS`
+    ReportAudioStatus,
+    /// `cec_opcode_SET_SYSTEM_AUDIO_MODE`
+    SetSystemAudioMode,
+    /// `cec_opcode_SYSTEM_AUDIO_MODE_REQUEST`
+    SystemAudioModeRequest,
+    /// `cec_opcode_SYSTEM_AUDIO_MODE_STATUS`
+    SystemAudioModeStatus,
+    /// `cec_opcode_SET_AUDIO_RATE`
+    SetAudioRate,
+    /// `cec_opcode_REPORT_SHORT_AUDIO_DESCRIPTORS`
+    ReportShortAudioDescriptors,
+    /// `cec_opcode_REQUEST_SHORT_AUDIO_DESCRIPTORS`
+    RequestShortAudioDescriptors,
+    /// `cec_opcode_START_ARC`
+    StartArc,
+    /// `cec_opcode_REPORT_ARC_STARTED`
+    ReportArcStarted,
+    /// `cec_opcode_REPORT_ARC_ENDED`
+    ReportArcEnded,
+    /// `cec_opcode_REQUEST_ARC_START`
+    RequestArcStart,
+    /// `cec_opcode_REQUEST_ARC_END`
+    RequestArcEnd,
+    /// `cec_opcode_END_ARC`
+    EndArc,
+    /// `cec_opcode_CDC`
+    Cdc,
+    /// `cec_opcode_NONE`
+    None,
+    /// Value not known to libcec
+    Unknown(cec_opcode),
+}
+impl Opcode {
+    /// All known values
+    pub const ALL: &'static [Self] = &[
+        Self::ActiveSource,
+        Self::ImageViewOn,
+        Self::TextViewOn,
+        Self::InactiveSource,
+        Self::RequestActiveSource,
+        Self::RoutingChange,
+        Self::RoutingInformation,
+        Self::SetStreamPath,
+        Self::Standby,
+        Self::RecordOff,
+        Self::RecordOn,
+        Self::RecordStatus,
+        Self::RecordTvScreen,
+        Self::ClearAnalogueTimer,
+        Self::ClearDigitalTimer,
+        Self::ClearExternalTimer,
+        Self::SetAnalogueTimer,
+        Self::SetDigitalTimer,
+        Self::SetExternalTimer,
+        Self::SetTimerProgramTitle,
+        Self::TimerClearedStatus,
+        Self::TimerStatus,
+        Self::CecVersion,
+        Self::GetCecVersion,
+        Self::GivePhysicalAddress,
+        Self::GetMenuLanguage,
+        Self::ReportPhysicalAddress,
+        Self::SetMenuLanguage,
+        Self::DeckControl,
+        Self::DeckStatus,
+        Self::GiveDeckStatus,
+        Self::Play,
+        Self::GiveTunerDeviceStatus,
+        Self::SelectAnalogueService,
+        Self::SelectDigitalService,
+        Self::TunerDeviceStatus,
+        Self::TunerStepDecrement,
+        Self::TunerStepIncrement,
+        Self::DeviceVendorId,
+        Self::GiveDeviceVendorId,
+        Self::VendorCommand,
+        Self::VendorCommandWithId,
+        Self::VendorRemoteButtonDown,
+        Self::VendorRemoteButtonUp,
+        Self::SetOsdString,
+        Self::GiveOsdName,
+        Self::SetOsdName,
+        Self::MenuRequest,
+        Self::MenuStatus,
+        Self::UserControlPressed,
+        Self::UserControlRelease,
+        Self::GiveDevicePowerStatus,
+        Self::ReportPowerStatus,
+        Self::FeatureAbort,
+        Self::Abort,
+        Self::GiveAudioStatus,
+        Self::GiveSystemAudioModeStatus,
+        Self::ReportAudioStatus,
+        Self::SetSystemAudioMode,
+        Self::SystemAudioModeRequest,
+        Self::SystemAudioModeStatus,
+        Self::SetAudioRate,
+        Self::ReportShortAudioDescriptors,
+        Self::RequestShortAudioDescriptors,
+        Self::StartArc,
+        Self::ReportArcStarted,
+        Self::ReportArcEnded,
+        Self::RequestArcStart,
+        Self::RequestArcEnd,
+        Self::EndArc,
+        Self::Cdc,
+        Self::None,
+    ];
+    const NAMED_UNKNOWN: Option<Self> = None;
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &[
+        "ActiveSource",
+        "ImageViewOn",
+        "TextViewOn",
+        "InactiveSource",
+        "RequestActiveSource",
+        "RoutingChange",
+        "RoutingInformation",
+        "SetStreamPath",
+        "Standby",
+        "RecordOff",
+        "RecordOn",
+        "RecordStatus",
+        "RecordTvScreen",
+        "ClearAnalogueTimer",
+        "ClearDigitalTimer",
+        "ClearExternalTimer",
+        "SetAnalogueTimer",
+        "SetDigitalTimer",
+        "SetExternalTimer",
+        "SetTimerProgramTitle",
+        "TimerClearedStatus",
+        "TimerStatus",
+        "CecVersion",
+        "GetCecVersion",
+        "GivePhysicalAddress",
+        "GetMenuLanguage",
+        "ReportPhysicalAddress",
+        "SetMenuLanguage",
+        "DeckControl",
+        "DeckStatus",
+        "GiveDeckStatus",
+        "Play",
+        "GiveTunerDeviceStatus",
+        "SelectAnalogueService",
+        "SelectDigitalService",
+        "TunerDeviceStatus",
+        "TunerStepDecrement",
+        "TunerStepIncrement",
+        "DeviceVendorId",
+        "GiveDeviceVendorId",
+        "VendorCommand",
+        "VendorCommandWithId",
+        "VendorRemoteButtonDown",
+        "VendorRemoteButtonUp",
+        "SetOsdString",
+        "GiveOsdName",
+        "SetOsdName",
+        "MenuRequest",
+        "MenuStatus",
+        "UserControlPressed",
+        "UserControlRelease",
+        "GiveDevicePowerStatus",
+        "ReportPowerStatus",
+        "FeatureAbort",
+        "Abort",
+        "GiveAudioStatus",
+        "GiveSystemAudioModeStatus",
+        "ReportAudioStatus",
+        "SetSystemAudioMode",
+        "SystemAudioModeRequest",
+        "SystemAudioModeStatus",
+        "SetAudioRate",
+        "ReportShortAudioDescriptors",
+        "RequestShortAudioDescriptors",
+        "StartArc",
+        "ReportArcStarted",
+        "ReportArcEnded",
+        "RequestArcStart",
+        "RequestArcEnd",
+        "EndArc",
+        "Cdc",
+        "None",
+        "Unknown",
+    ];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::ActiveSource => "active source",
+            Self::ImageViewOn => "image view on",
+            Self::TextViewOn => "text view on",
+            Self::InactiveSource => "inactive source",
+            Self::RequestActiveSource => "request active source",
+            Self::RoutingChange => "routing change",
+            Self::RoutingInformation => "routing information",
+            Self::SetStreamPath => "set stream path",
+            Self::Standby => "standby",
+            Self::RecordOff => "record off",
+            Self::RecordOn => "record on",
+            Self::RecordStatus => "record status",
+            Self::RecordTvScreen => "record tv screen",
+            Self::ClearAnalogueTimer => "clear analogue timer",
+            Self::ClearDigitalTimer => "clear digital timer",
+            Self::ClearExternalTimer => "clear external timer",
+            Self::SetAnalogueTimer => "set analogue timer",
+            Self::SetDigitalTimer => "set digital timer",
+            Self::SetExternalTimer => "set external timer",
+            Self::SetTimerProgramTitle => "set timer program title",
+            Self::TimerClearedStatus => "timer cleared status",
+            Self::TimerStatus => "timer status",
+            Self::CecVersion => "cec version",
+            Self::GetCecVersion => "get cec version",
+            Self::GivePhysicalAddress => "give physical address",
+            Self::GetMenuLanguage => "get menu language",
+            Self::ReportPhysicalAddress => "report physical address",
+            Self::SetMenuLanguage => "set menu language",
+            Self::DeckControl => "deck control",
+            Self::DeckStatus => "deck status",
+            Self::GiveDeckStatus => "give deck status",
+            Self::Play => "play",
+            Self::GiveTunerDeviceStatus => "give tuner status",
+            Self::SelectAnalogueService => "select analogue service",
+            Self::SelectDigitalService => "set digital service",
+            Self::TunerDeviceStatus => "tuner device status",
+            Self::TunerStepDecrement => "tuner step decrement",
+            Self::TunerStepIncrement => "tuner step increment",
+            Self::DeviceVendorId => "device vendor id",
+            Self::GiveDeviceVendorId => "give device vendor id",
+            Self::VendorCommand => "vendor command",
+            Self::VendorCommandWithId => "vendor command with id",
+            Self::VendorRemoteButtonDown => "vendor remote button down",
+            Self::VendorRemoteButtonUp => "vendor remote button up",
+            Self::SetOsdString => "set osd string",
+            Self::GiveOsdName => "give osd name",
+            Self::SetOsdName => "set osd name",
+            Self::MenuRequest => "menu request",
+            Self::MenuStatus => "menu status",
+            Self::UserControlPressed => "user control pressed",
+            Self::UserControlRelease => "user control release",
+            Self::GiveDevicePowerStatus => "give device power status",
+            Self::ReportPowerStatus => "report power status",
+            Self::FeatureAbort => "feature abort",
+            Self::Abort => "abort",
+            Self::GiveAudioStatus => "give audio status",
+            Self::GiveSystemAudioModeStatus => "give audio mode status",
+            Self::ReportAudioStatus => "report audio status",
+            Self::SetSystemAudioMode => "set system audio mode",
+            Self::SystemAudioModeRequest => "system audio mode request",
+            Self::SystemAudioModeStatus => "system audio mode status",
+            Self::SetAudioRate => "set audio rate",
+            Self::ReportShortAudioDescriptors => "report short audio descriptors",
+            Self::RequestShortAudioDescriptors => "request short audio descriptors",
+            Self::StartArc => "start ARC",
+            Self::ReportArcStarted => "report ARC started",
+            Self::ReportArcEnded => "report ARC ended",
+            Self::RequestArcStart => "request ARC start",
+            Self::RequestArcEnd => "request ARC end",
+            Self::EndArc => "end ARC",
+            Self::Cdc => "CDC",
+            Self::None => "poll",
+            Self::Unknown(_) => "UNKNOWN",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_opcode) -> Self {
+        match c_enum_value!(raw) {
+            130 => Self::ActiveSource,
+            4 => Self::ImageViewOn,
+            13 => Self::TextViewOn,
+            157 => Self::InactiveSource,
+            133 => Self::RequestActiveSource,
+            128 => Self::RoutingChange,
+            129 => Self::RoutingInformation,
+            134 => Self::SetStreamPath,
+            54 => Self::Standby,
+            11 => Self::RecordOff,
+            9 => Self::RecordOn,
+            10 => Self::RecordStatus,
+            15 => Self::RecordTvScreen,
+            51 => Self::ClearAnalogueTimer,
+            153 => Self::ClearDigitalTimer,
+            161 => Self::ClearExternalTimer,
+            52 => Self::SetAnalogueTimer,
+            151 => Self::SetDigitalTimer,
+            162 => Self::SetExternalTimer,
+            103 => Self::SetTimerProgramTitle,
+            67 => Self::TimerClearedStatus,
+            53 => Self::TimerStatus,
+            158 => Self::CecVersion,
+            159 => Self::GetCecVersion,
+            131 => Self::GivePhysicalAddress,
+            145 => Self::GetMenuLanguage,
+            132 => Self::ReportPhysicalAddress,
+            50 => Self::SetMenuLanguage,
+            66 => Self::DeckControl,
+            27 => Self::DeckStatus,
+            26 => Self::GiveDeckStatus,
+            65 => Self::Play,
+            8 => Self::GiveTunerDeviceStatus,
+            146 => Self::SelectAnalogueService,
+            147 => Self::SelectDigitalService,
+            7 => Self::TunerDeviceStatus,
+            6 => Self::TunerStepDecrement,
+            5 => Self::TunerStepIncrement,
+            135 => Self::DeviceVendorId,
+            140 => Self::GiveDeviceVendorId,
+            137 => Self::VendorCommand,
+            160 => Self::VendorCommandWithId,
+            138 => Self::VendorRemoteButtonDown,
+            139 => Self::VendorRemoteButtonUp,
+            100 => Self::SetOsdString,
+            70 => Self::GiveOsdName,
+            71 => Self::SetOsdName,
+            141 => Self::MenuRequest,
+            142 => Self::MenuStatus,
+            68 => Self::UserControlPressed,
+            69 => Self::UserControlRelease,
+            143 => Self::GiveDevicePowerStatus,
+            144 => Self::ReportPowerStatus,
+            0 => Self::FeatureAbort,
+            255 => Self::Abort,
+            113 => Self::GiveAudioStatus,
+            125 => Self::GiveSystemAudioModeStatus,
+            122 => Self::ReportAudioStatus,
+            114 => Self::SetSystemAudioMode,
+            112 => Self::SystemAudioModeRequest,
+            126 => Self::SystemAudioModeStatus,
+            154 => Self::SetAudioRate,
+            163 => Self::ReportShortAudioDescriptors,
+            164 => Self::RequestShortAudioDescriptors,
+            192 => Self::StartArc,
+            193 => Self::ReportArcStarted,
+            194 => Self::ReportArcEnded,
+            195 => Self::RequestArcStart,
+            196 => Self::RequestArcEnd,
+            197 => Self::EndArc,
+            248 => Self::Cdc,
+            253 => Self::None,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_opcode {
+        match self {
+            Self::ActiveSource => c_enum!(cec_opcode, 130),
+            Self::ImageViewOn => c_enum!(cec_opcode, 4),
+            Self::TextViewOn => c_enum!(cec_opcode, 13),
+            Self::InactiveSource => c_enum!(cec_opcode, 157),
+            Self::RequestActiveSource => c_enum!(cec_opcode, 133),
+            Self::RoutingChange => c_enum!(cec_opcode, 128),
+            Self::RoutingInformation => c_enum!(cec_opcode, 129),
+            Self::SetStreamPath => c_enum!(cec_opcode, 134),
+            Self::Standby => c_enum!(cec_opcode, 54),
+            Self::RecordOff => c_enum!(cec_opcode, 11),
+            Self::RecordOn => c_enum!(cec_opcode, 9),
+            Self::RecordStatus => c_enum!(cec_opcode, 10),
+            Self::RecordTvScreen => c_enum!(cec_opcode, 15),
+            Self::ClearAnalogueTimer => c_enum!(cec_opcode, 51),
+            Self::ClearDigitalTimer => c_enum!(cec_opcode, 153),
+            Self::ClearExternalTimer => c_enum!(cec_opcode, 161),
+            Self::SetAnalogueTimer => c_enum!(cec_opcode, 52),
+            Self::SetDigitalTimer => c_enum!(cec_opcode, 151),
+            Self::SetExternalTimer => c_enum!(cec_opcode, 162),
+            Self::SetTimerProgramTitle => c_enum!(cec_opcode, 103),
+            Self::TimerClearedStatus => c_enum!(cec_opcode, 67),
+            Self::TimerStatus => c_enum!(cec_opcode, 53),
+            Self::CecVersion => c_enum!(cec_opcode, 158),
+            Self::GetCecVersion => c_enum!(cec_opcode, 159),
+            Self::GivePhysicalAddress => c_enum!(cec_opcode, 131),
+            Self::GetMenuLanguage => c_enum!(cec_opcode, 145),
+            Self::ReportPhysicalAddress => c_enum!(cec_opcode, 132),
+            Self::SetMenuLanguage => c_enum!(cec_opcode, 50),
+            Self::DeckControl => c_enum!(cec_opcode, 66),
+            Self::DeckStatus => c_enum!(cec_opcode, 27),
+            Self::GiveDeckStatus => c_enum!(cec_opcode, 26),
+            Self::Play => c_enum!(cec_opcode, 65),
+            Self::GiveTunerDeviceStatus => c_enum!(cec_opcode, 8),
+            Self::SelectAnalogueService => c_enum!(cec_opcode, 146),
+            Self::SelectDigitalService => c_enum!(cec_opcode, 147),
+            Self::TunerDeviceStatus => c_enum!(cec_opcode, 7),
+            Self::TunerStepDecrement => c_enum!(cec_opcode, 6),
+            Self::TunerStepIncrement => c_enum!(cec_opcode, 5),
+            Self::DeviceVendorId => c_enum!(cec_opcode, 135),
+            Self::GiveDeviceVendorId => c_enum!(cec_opcode, 140),
+            Self::VendorCommand => c_enum!(cec_opcode, 137),
+            Self::VendorCommandWithId => c_enum!(cec_opcode, 160),
+            Self::VendorRemoteButtonDown => c_enum!(cec_opcode, 138),
+            Self::VendorRemoteButtonUp => c_enum!(cec_opcode, 139),
+            Self::SetOsdString => c_enum!(cec_opcode, 100),
+            Self::GiveOsdName => c_enum!(cec_opcode, 70),
+            Self::SetOsdName => c_enum!(cec_opcode, 71),
+            Self::MenuRequest => c_enum!(cec_opcode, 141),
+            Self::MenuStatus => c_enum!(cec_opcode, 142),
+            Self::UserControlPressed => c_enum!(cec_opcode, 68),
+            Self::UserControlRelease => c_enum!(cec_opcode, 69),
+            Self::GiveDevicePowerStatus => c_enum!(cec_opcode, 143),
+            Self::ReportPowerStatus => c_enum!(cec_opcode, 144),
+            Self::FeatureAbort => c_enum!(cec_opcode, 0),
+            Self::Abort => c_enum!(cec_opcode, 255),
+            Self::GiveAudioStatus => c_enum!(cec_opcode, 113),
+            Self::GiveSystemAudioModeStatus => c_enum!(cec_opcode, 125),
+            Self::ReportAudioStatus => c_enum!(cec_opcode, 122),
+            Self::SetSystemAudioMode => c_enum!(cec_opcode, 114),
+            Self::SystemAudioModeRequest => c_enum!(cec_opcode, 112),
+            Self::SystemAudioModeStatus => c_enum!(cec_opcode, 126),
+            Self::SetAudioRate => c_enum!(cec_opcode, 154),
+            Self::ReportShortAudioDescriptors => c_enum!(cec_opcode, 163),
+            Self::RequestShortAudioDescriptors => c_enum!(cec_opcode, 164),
+            Self::StartArc => c_enum!(cec_opcode, 192),
+            Self::ReportArcStarted => c_enum!(cec_opcode, 193),
+            Self::ReportArcEnded => c_enum!(cec_opcode, 194),
+            Self::RequestArcStart => c_enum!(cec_opcode, 195),
+            Self::RequestArcEnd => c_enum!(cec_opcode, 196),
+            Self::EndArc => c_enum!(cec_opcode, 197),
+            Self::Cdc => c_enum!(cec_opcode, 248),
+            Self::None => c_enum!(cec_opcode, 253),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(Opcode, cec_opcode);
+/// `cec_log_level`
+#[derive(Debug, Clone, Copy)]
+pub enum LogLevel {
+    /// `cec_log_level_CEC_LOG_ERROR`
+    Error,
+    /// `cec_log_level_CEC_LOG_WARNING`
+    Warning,
+    /// `cec_log_level_CEC_LOG_NOTICE`
+    Notice,
+    /// `cec_log_level_CEC_LOG_TRAFFIC`
+    Traffic,
+    /// `cec_log_level_CEC_LOG_DEBUG`
+    Debug,
+    /// `cec_log_level_CEC_LOG_ALL`
+    All,
+    /// Value not known to libcec
+    Unknown(cec_log_level),
+}
+impl LogLevel {
+    /// All known values
+    pub const ALL: &'static [Self] = &[
+        Self::Error,
+        Self::Warning,
+        Self::Notice,
+        Self::Traffic,
+        Self::Debug,
+        Self::All,
+    ];
+    const NAMED_UNKNOWN: Option<Self> = None;
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &[
+        "Error", "Warning", "Notice", "Traffic", "Debug", "All", "Unknown",
+    ];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Error => "cec log error",
+            Self::Warning => "cec log warning",
+            Self::Notice => "cec log notice",
+            Self::Traffic => "cec log traffic",
+            Self::Debug => "cec log debug",
+            Self::All => "cec log all",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_log_level) -> Self {
+        match c_enum_value!(raw) {
+            1 => Self::Error,
+            2 => Self::Warning,
+            4 => Self::Notice,
+            8 => Self::Traffic,
+            16 => Self::Debug,
+            31 => Self::All,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_log_level {
+        match self {
+            Self::Error => c_enum!(cec_log_level, 1),
+            Self::Warning => c_enum!(cec_log_level, 2),
+            Self::Notice => c_enum!(cec_log_level, 4),
+            Self::Traffic => c_enum!(cec_log_level, 8),
+            Self::Debug => c_enum!(cec_log_level, 16),
+            Self::All => c_enum!(cec_log_level, 31),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(LogLevel, cec_log_level);
+/// `cec_bus_device_status`
+#[derive(Debug, Clone, Copy)]
+pub enum BusDeviceStatus {
+    /// `cec_bus_device_status_PRESENT`
+    Present,
+    /// `cec_bus_device_status_NOT_PRESENT`
+    NotPresent,
+    /// `cec_bus_device_status_HANDLED_BY_LIBCEC`
+    HandledByLibcec,
+    /// Value not known to libcec
+    Unknown(cec_bus_device_status),
+}
+impl BusDeviceStatus {
+    /// `cec_bus_device_status_UNKNOWN`
+    pub const UNKNOWN: Self = Self::Unknown(c_enum!(cec_bus_device_status, 0));
+    /// All known values
+    pub const ALL: &'static [Self] = &[Self::Present, Self::NotPresent, Self::HandledByLibcec];
+    const NAMED_UNKNOWN: Option<Self> = Some(Self::UNKNOWN);
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &[
+        "Present",
+        "NotPresent",
+        "HandledByLibcec",
+        "UNKNOWN",
+        "Unknown",
+    ];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Present => "present",
+            Self::NotPresent => "not present",
+            Self::HandledByLibcec => "handled by libCEC",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_bus_device_status) -> Self {
+        match c_enum_value!(raw) {
+            1 => Self::Present,
+            2 => Self::NotPresent,
+            3 => Self::HandledByLibcec,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_bus_device_status {
+        match self {
+            Self::Present => c_enum!(cec_bus_device_status, 1),
+            Self::NotPresent => c_enum!(cec_bus_device_status, 2),
+            Self::HandledByLibcec => c_enum!(cec_bus_device_status, 3),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(BusDeviceStatus, cec_bus_device_status);
+/// `cec_vendor_id`
+#[derive(Debug, Clone, Copy)]
+pub enum VendorId {
+    /// `cec_vendor_id_TOSHIBA`
+    Toshiba,
+    /// `cec_vendor_id_SAMSUNG`
+    Samsung,
+    /// `cec_vendor_id_DENON`
+    Denon,
+    /// `cec_vendor_id_MARANTZ`
+    Marantz,
+    /// `cec_vendor_id_LOEWE`
+    Loewe,
+    /// `cec_vendor_id_ONKYO`
+    Onkyo,
+    /// `cec_vendor_id_MEDION`
+    Medion,
+    /// `cec_vendor_id_TOSHIBA2`
+    Toshiba2,
+    /// `cec_vendor_id_APPLE`
+    Apple,
+    /// `cec_vendor_id_PULSE_EIGHT`
+    PulseEight,
+    /// `cec_vendor_id_HARMAN_KARDON2`
+    HarmanKardon2,
+    /// `cec_vendor_id_GOOGLE`
+    Google,
+    /// `cec_vendor_id_AKAI`
+    Akai,
+    /// `cec_vendor_id_AOC`
+    Aoc,
+    /// `cec_vendor_id_PANASONIC`
+    Panasonic,
+    /// `cec_vendor_id_PHILIPS`
+    Philips,
+    /// `cec_vendor_id_DAEWOO`
+    Daewoo,
+    /// `cec_vendor_id_YAMAHA`
+    Yamaha,
+    /// `cec_vendor_id_GRUNDIG`
+    Grundig,
+    /// `cec_vendor_id_PIONEER`
+    Pioneer,
+    /// `cec_vendor_id_LG`
+    Lg,
+    /// `cec_vendor_id_SHARP`
+    Sharp,
+    /// `cec_vendor_id_SONY`
+    Sony,
+    /// `cec_vendor_id_TEUFEL`
+    Teufel,
+    /// `cec_vendor_id_BROADCOM`
+    Broadcom,
+    /// `cec_vendor_id_SHARP2`
+    Sharp2,
+    /// `cec_vendor_id_VIZIO`
+    Vizio,
+    /// `cec_vendor_id_BENQ`
+    Benq,
+    /// `cec_vendor_id_HARMAN_KARDON`
+    HarmanKardon,
+    /// Value not known to libcec
+    Unknown(cec_vendor_id),
+}
+impl VendorId {
+    /// `cec_vendor_id_UNKNOWN`
+    pub const UNKNOWN: Self = Self::Unknown(c_enum!(cec_vendor_id, 0));
+    /// All known values
+    pub const ALL: &'static [Self] = &[
+        Self::Toshiba,
+        Self::Samsung,
+        Self::Denon,
+        Self::Marantz,
+        Self::Loewe,
+        Self::Onkyo,
+        Self::Medion,
+        Self::Toshiba2,
+        Self::Apple,
+        Self::PulseEight,
+        Self::HarmanKardon2,
+        Self::Google,
+        Self::Akai,
+        Self::Aoc,
+        Self::Panasonic,
+        Self::Philips,
+        Self::Daewoo,
+        Self::Yamaha,
+        Self::Grundig,
+        Self::Pioneer,
+        Self::Lg,
+        Self::Sharp,
+        Self::Sony,
+        Self::Teufel,
+        Self::Broadcom,
+        Self::Sharp2,
+        Self::Vizio,
+        Self::Benq,
+        Self::HarmanKardon,
+    ];
+    const NAMED_UNKNOWN: Option<Self> = Some(Self::UNKNOWN);
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &[
+        "Toshiba",
+        "Samsung",
+        "Denon",
+        "Marantz",
+        "Loewe",
+        "Onkyo",
+        "Medion",
+        "Toshiba2",
+        "Apple",
+        "PulseEight",
+        "HarmanKardon2",
+        "Google",
+        "Akai",
+        "Aoc",
+        "Panasonic",
+        "Philips",
+        "Daewoo",
+        "Yamaha",
+        "Grundig",
+        "Pioneer",
+        "Lg",
+        "Sharp",
+        "Sony",
+        "Teufel",
+        "Broadcom",
+        "Sharp2",
+        "Vizio",
+        "Benq",
+        "HarmanKardon",
+        "UNKNOWN",
+        "Unknown",
+    ];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Toshiba => "Toshiba",
+            Self::Samsung => "Samsung",
+            Self::Denon => "Denon",
+            Self::Marantz => "Marantz",
+            Self::Loewe => "Loewe",
+            Self::Onkyo => "Onkyo",
+            Self::Medion => "Medion",
+            Self::Toshiba2 => "Toshiba",
+            Self::Apple => "Apple",
+            Self::PulseEight => "Pulse Eight",
+            Self::HarmanKardon2 => "Harman/Kardon",
+            Self::Google => "Google",
+            Self::Akai => "Akai",
+            Self::Aoc => "AOC",
+            Self::Panasonic => "Panasonic",
+            Self::Philips => "Philips",
+            Self::Daewoo => "Daewoo",
+            Self::Yamaha => "Yamaha",
+            Self::Grundig => "Grundig",
+            Self::Pioneer => "Pioneer",
+            Self::Lg => "LG",
+            Self::Sharp => "Sharp",
+            Self::Sony => "Sony",
+            Self::Teufel => "Teufel",
+            Self::Broadcom => "Broadcom",
+            Self::Sharp2 => "Sharp",
+            Self::Vizio => "Vizio",
+            Self::Benq => "Benq",
+            Self::HarmanKardon => "Harman/Kardon",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_vendor_id) -> Self {
+        match c_enum_value!(raw) {
+            57 => Self::Toshiba,
+            240 => Self::Samsung,
+            1485 => Self::Denon,
+            1656 => Self::Marantz,
+            2434 => Self::Loewe,
+            2480 => Self::Onkyo,
+            3256 => Self::Medion,
+            3303 => Self::Toshiba2,
+            4346 => Self::Apple,
+            5506 => Self::PulseEight,
+            6480 => Self::HarmanKardon2,
+            6673 => Self::Google,
+            8391 => Self::Akai,
+            9319 => Self::Aoc,
+            32837 => Self::Panasonic,
+            36926 => Self::Philips,
+            36947 => Self::Daewoo,
+            41182 => Self::Yamaha,
+            53461 => Self::Grundig,
+            57398 => Self::Pioneer,
+            57489 => Self::Lg,
+            524319 => Self::Sharp,
+            524358 => Self::Sony,
+            2303013 => Self::Teufel,
+            1622150 => Self::Broadcom,
+            5458000 => Self::Sharp2,
+            7042157 => Self::Vizio,
+            8414697 => Self::Benq,
+            10249310 => Self::HarmanKardon,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_vendor_id {
+        match self {
+            Self::Toshiba => c_enum!(cec_vendor_id, 57),
+            Self::Samsung => c_enum!(cec_vendor_id, 240),
+            Self::Denon => c_enum!(cec_vendor_id, 1485),
+            Self::Marantz => c_enum!(cec_vendor_id, 1656),
+            Self::Loewe => c_enum!(cec_vendor_id, 2434),
+            Self::Onkyo => c_enum!(cec_vendor_id, 2480),
+            Self::Medion => c_enum!(cec_vendor_id, 3256),
+            Self::Toshiba2 => c_enum!(cec_vendor_id, 3303),
+            Self::Apple => c_enum!(cec_vendor_id, 4346),
+            Self::PulseEight => c_enum!(cec_vendor_id, 5506),
+            Self::HarmanKardon2 => c_enum!(cec_vendor_id, 6480),
+            Self::Google => c_enum!(cec_vendor_id, 6673),
+            Self::Akai => c_enum!(cec_vendor_id, 8391),
+            Self::Aoc => c_enum!(cec_vendor_id, 9319),
+            Self::Panasonic => c_enum!(cec_vendor_id, 32837),
+            Self::Philips => c_enum!(cec_vendor_id, 36926),
+            Self::Daewoo => c_enum!(cec_vendor_id, 36947),
+            Self::Yamaha => c_enum!(cec_vendor_id, 41182),
+            Self::Grundig => c_enum!(cec_vendor_id, 53461),
+            Self::Pioneer => c_enum!(cec_vendor_id, 57398),
+            Self::Lg => c_enum!(cec_vendor_id, 57489),
+            Self::Sharp => c_enum!(cec_vendor_id, 524319),
+            Self::Sony => c_enum!(cec_vendor_id, 524358),
+            Self::Teufel => c_enum!(cec_vendor_id, 2303013),
+            Self::Broadcom => c_enum!(cec_vendor_id, 1622150),
+            Self::Sharp2 => c_enum!(cec_vendor_id, 5458000),
+            Self::Vizio => c_enum!(cec_vendor_id, 7042157),
+            Self::Benq => c_enum!(cec_vendor_id, 8414697),
+            Self::HarmanKardon => c_enum!(cec_vendor_id, 10249310),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(VendorId, cec_vendor_id);
+/// `cec_adapter_type`
+#[derive(Debug, Clone, Copy)]
+pub enum AdapterType {
+    /// `cec_adapter_type_P8_EXTERNAL`
+    P8External,
+    /// `cec_adapter_type_P8_DAUGHTERBOARD`
+    P8Daughterboard,
+    /// `cec_adapter_type_RPI`
+    Rpi,
+    /// `cec_adapter_type_TDA995x`
+    Tda995x,
+    /// `cec_adapter_type_EXYNOS`
+    Exynos,
+    /// `cec_adapter_type_LINUX`
+    Linux,
+    /// `cec_adapter_type_AOCEC`
+    Aocec,
+    /// `cec_adapter_type_IMX`
+    Imx,
+    /// `cec_adapter_type_TEGRA`
+    Tegra,
+    /// Value not known to libcec
+    Unknown(cec_adapter_type),
+}
+impl AdapterType {
+    /// `cec_adapter_type_UNKNOWN`
+    pub const UNKNOWN: Self = Self::Unknown(c_enum!(cec_adapter_type, 0));
+    /// All known values
+    pub const ALL: &'static [Self] = &[
+        Self::P8External,
+        Self::P8Daughterboard,
+        Self::Rpi,
+        Self::Tda995x,
+        Self::Exynos,
+        Self::Linux,
+        Self::Aocec,
+        Self::Imx,
+        Self::Tegra,
+    ];
+    const NAMED_UNKNOWN: Option<Self> = Some(Self::UNKNOWN);
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &[
+        "P8External",
+        "P8Daughterboard",
+        "Rpi",
+        "Tda995x",
+        "Exynos",
+        "Linux",
+        "Aocec",
+        "Imx",
+        "Tegra",
+        "UNKNOWN",
+        "Unknown",
+    ];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::P8External => "Pulse-Eight USB-CEC Adapter",
+            Self::P8Daughterboard => "Pulse-Eight USB-CEC Daughterboard",
+            Self::Rpi => "Raspberry Pi",
+            Self::Tda995x => "TDA995x",
+            Self::Exynos => "Exynos",
+            Self::Linux => "Linux",
+            Self::Aocec => "AOCEC",
+            Self::Imx => "i.MX",
+            Self::Tegra => "Tegra",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: cec_adapter_type) -> Self {
+        match c_enum_value!(raw) {
+            1 => Self::P8External,
+            2 => Self::P8Daughterboard,
+            256 => Self::Rpi,
+            512 => Self::Tda995x,
+            768 => Self::Exynos,
+            1024 => Self::Linux,
+            1280 => Self::Aocec,
+            1536 => Self::Imx,
+            1792 => Self::Tegra,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> cec_adapter_type {
+        match self {
+            Self::P8External => c_enum!(cec_adapter_type, 1),
+            Self::P8Daughterboard => c_enum!(cec_adapter_type, 2),
+            Self::Rpi => c_enum!(cec_adapter_type, 256),
+            Self::Tda995x => c_enum!(cec_adapter_type, 512),
+            Self::Exynos => c_enum!(cec_adapter_type, 768),
+            Self::Linux => c_enum!(cec_adapter_type, 1024),
+            Self::Aocec => c_enum!(cec_adapter_type, 1280),
+            Self::Imx => c_enum!(cec_adapter_type, 1536),
+            Self::Tegra => c_enum!(cec_adapter_type, 1792),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(AdapterType, cec_adapter_type);
+/// `libcec_alert`
+#[derive(Debug, Clone, Copy)]
+pub enum Alert {
+    /// `libcec_alert_SERVICE_DEVICE`
+    ServiceDevice,
+    /// `libcec_alert_CONNECTION_LOST`
+    ConnectionLost,
+    /// `libcec_alert_PERMISSION_ERROR`
+    PermissionError,
+    /// `libcec_alert_PORT_BUSY`
+    PortBusy,
+    /// `libcec_alert_PHYSICAL_ADDRESS_ERROR`
+    PhysicalAddressError,
+    /// `libcec_alert_TV_POLL_FAILED`
+    TvPollFailed,
+    /// Value not known to libcec
+    Unknown(libcec_alert),
+}
+impl Alert {
+    /// All known values
+    pub const ALL: &'static [Self] = &[
+        Self::ServiceDevice,
+        Self::ConnectionLost,
+        Self::PermissionError,
+        Self::PortBusy,
+        Self::PhysicalAddressError,
+        Self::TvPollFailed,
+    ];
+    const NAMED_UNKNOWN: Option<Self> = None;
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &[
+        "ServiceDevice",
+        "ConnectionLost",
+        "PermissionError",
+        "PortBusy",
+        "PhysicalAddressError",
+        "TvPollFailed",
+        "Unknown",
+    ];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::ServiceDevice => "service device",
+            Self::ConnectionLost => "connection lost",
+            Self::PermissionError => "permission error",
+            Self::PortBusy => "port busy",
+            Self::PhysicalAddressError => "physical address error",
+            Self::TvPollFailed => "tv poll failed",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: libcec_alert) -> Self {
+        match c_enum_value!(raw) {
+            0 => Self::ServiceDevice,
+            1 => Self::ConnectionLost,
+            2 => Self::PermissionError,
+            3 => Self::PortBusy,
+            4 => Self::PhysicalAddressError,
+            5 => Self::TvPollFailed,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> libcec_alert {
+        match self {
+            Self::ServiceDevice => c_enum!(libcec_alert, 0),
+            Self::ConnectionLost => c_enum!(libcec_alert, 1),
+            Self::PermissionError => c_enum!(libcec_alert, 2),
+            Self::PortBusy => c_enum!(libcec_alert, 3),
+            Self::PhysicalAddressError => c_enum!(libcec_alert, 4),
+            Self::TvPollFailed => c_enum!(libcec_alert, 5),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(Alert, libcec_alert);
+/// `libcec_parameter_type`
+#[derive(Debug, Clone, Copy)]
+pub enum ParameterType {
+    /// `libcec_parameter_type_STRING`
+    String,
+    /// `libcec_parameter_type_UNKOWN`
+    Unkown,
+    /// Value not known to libcec
+    Unknown(libcec_parameter_type),
+}
+impl ParameterType {
+    /// All known values
+    pub const ALL: &'static [Self] = &[Self::String, Self::Unkown];
+    const NAMED_UNKNOWN: Option<Self> = None;
+    /// Variant names for serde: [`Self::ALL`], then `UNKNOWN` for the C value if any,
+    /// and `Unknown`
+    #[cfg(feature = "serde")]
+    const VARIANT_NAMES: &'static [&'static str] = &["String", "Unkown", "Unknown"];
+    /// Name of the value, as used by libcec
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::String => "string",
+            Self::Unkown => "unkown",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+    /// Converts from the C value, keeping values not known to libcec as `Unknown`
+    pub const fn from_raw(raw: libcec_parameter_type) -> Self {
+        match c_enum_value!(raw) {
+            0 => Self::String,
+            1 => Self::Unkown,
+            _ => Self::Unknown(raw),
+        }
+    }
+    /// Converts to the C value
+    pub const fn raw(self) -> libcec_parameter_type {
+        match self {
+            Self::String => c_enum!(libcec_parameter_type, 0),
+            Self::Unkown => c_enum!(libcec_parameter_type, 1),
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+impl_enum_conversions!(ParameterType, libcec_parameter_type);
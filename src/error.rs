@@ -0,0 +1,307 @@
+//! Errors of libcec
+//!
+//! The C API signals failure in several ways: a 0 / 1 `c_int`, -1 as `i8`, a NULL handle, or
+//! asynchronously with a `libcec_alert`. [`CecError`] has a distinct variant for each, and
+//! the `check_*` functions map the raw results of the corresponding functions.
+//!
+//! ```
+//! use libcec_sys::enums::Alert;
+//! use libcec_sys::error::CecError;
+//!
+//! assert_eq!(CecError::check_transmit(0), Err(CecError::TransmitFailed));
+//! assert_eq!(CecError::check_detect_adapters(-1), Err(CecError::DetectAdaptersFailed));
+//! assert_eq!(
+//!     CecError::from_alert(Alert::PortBusy, Some("/dev/cec0")),
+//!     Some(CecError::PortBusy { message: Some("/dev/cec0".to_owned()) })
+//! );
+//! ```
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::os::raw::c_int;
+use std::path::PathBuf;
+
+use crate::config::ConfigurationError;
+use crate::enums::{AbortReason, Alert, LogicalAddress, Opcode};
+use crate::handler::parameter_str;
+use crate::validation::Violation;
+use crate::*;
+
+/// Error of libcec, or of the arguments passed to it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CecError {
+    /// `libcec_initialise` returned a NULL connection
+    InitialiseFailed,
+    /// `libcec_open` could not open the adapter
+    OpenFailed { port: String },
+    /// `libcec_transmit` could not send the command
+    TransmitFailed,
+    /// `libcec_detect_adapters` returned -1
+    DetectAdaptersFailed,
+    /// libcec reported failure, with the name of the failed function
+    Failed(&'static str),
+    /// `CONNECTION_LOST` alert: the connection to the adapter was lost
+    ConnectionLost { message: Option<String> },
+    /// `PERMISSION_ERROR` alert: no permission to open the adapter
+    PermissionError { message: Option<String> },
+    /// `PORT_BUSY` alert: the adapter is in use by another process
+    PortBusy { message: Option<String> },
+    /// `PHYSICAL_ADDRESS_ERROR` alert: the physical address could not be determined
+    PhysicalAddressError { message: Option<String> },
+    /// `TV_POLL_FAILED` alert: the TV does not respond to polls
+    TvPollFailed { message: Option<String> },
+    /// String argument with a NUL byte, which cannot be passed to libcec
+    NulInString,
+    /// Path which is not valid UTF-8, which libcec cannot take
+    InvalidPath(PathBuf),
+    /// Invalid configuration, see [`crate::config::ConfigurationBuilder`]
+    InvalidConfiguration(ConfigurationError),
+    /// Command which breaks the CEC specification, see [`crate::validation`]
+    InvalidCommand(Vec<Violation>),
+    /// The destination refused the opcode before, see [`crate::feature_abort::AbortTracker`]
+    Refused {
+        destination: LogicalAddress,
+        opcode: Opcode,
+        reason: AbortReason,
+    },
+    /// The operation did not complete in time
+    Timeout,
+    /// The worker thread running the operation has stopped
+    WorkerStopped,
+    /// A thread, e.g. the worker of an `AsyncConnection` or the supervisor, could not be started
+    SpawnFailed(io::ErrorKind),
+}
+
+impl CecError {
+    /// Map the 0 (failure) / 1 (success) result of `function`
+    pub fn check(function: &'static str, result: c_int) -> Result<(), CecError> {
+        if result == 0 {
+            Err(CecError::Failed(function))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Map the handle returned by `libcec_initialise`
+    pub fn check_initialise(
+        connection: libcec_connection_t,
+    ) -> Result<libcec_connection_t, CecError> {
+        if connection.is_null() {
+            Err(CecError::InitialiseFailed)
+        } else {
+            Ok(connection)
+        }
+    }
+
+    /// Map the result of `libcec_open` for `port`
+    pub fn check_open(result: c_int, port: &str) -> Result<(), CecError> {
+        if result == 0 {
+            Err(CecError::OpenFailed {
+                port: port.to_owned(),
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn check_transmit(result: c_int) -> Result<(), CecError> {
+        if result == 0 {
+            Err(CecError::TransmitFailed)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Map the result of `libcec_detect_adapters` to the number of adapters found
+    pub fn check_detect_adapters(result: i8) -> Result<u8, CecError> {
+        u8::try_from(result).map_err(|_| CecError::DetectAdaptersFailed)
+    }
+
+    /// Error of an alert with its string parameter, `None` for `SERVICE_DEVICE` and unknown
+    /// alerts
+    pub fn from_alert(alert: Alert, message: Option<&str>) -> Option<CecError> {
+        let message = message.map(str::to_owned);
+        match alert {
+            Alert::ConnectionLost => Some(CecError::ConnectionLost { message }),
+            Alert::PermissionError => Some(CecError::PermissionError { message }),
+            Alert::PortBusy => Some(CecError::PortBusy { message }),
+            Alert::PhysicalAddressError => Some(CecError::PhysicalAddressError { message }),
+            Alert::TvPollFailed => Some(CecError::TvPollFailed { message }),
+            Alert::ServiceDevice | Alert::Unknown(_) => None,
+        }
+    }
+
+    /// [`CecError::from_alert`] with the raw arguments of the `alert` callback
+    ///
+    /// # Safety
+    ///
+    /// `paramData` of a `STRING` parameter must be NULL or point to a NUL-terminated string.
+    pub unsafe fn from_raw_alert(
+        alert: libcec_alert,
+        param: &libcec_parameter,
+    ) -> Option<CecError> {
+        Self::from_alert(Alert::from_raw(alert), parameter_str(param).as_deref())
+    }
+
+    /// Alert of the error, if it comes from one
+    pub fn alert(&self) -> Option<Alert> {
+        match self {
+            CecError::ConnectionLost { .. } => Some(Alert::ConnectionLost),
+            CecError::PermissionError { .. } => Some(Alert::PermissionError),
+            CecError::PortBusy { .. } => Some(Alert::PortBusy),
+            CecError::PhysicalAddressError { .. } => Some(Alert::PhysicalAddressError),
+            CecError::TvPollFailed { .. } => Some(Alert::TvPollFailed),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for CecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CecError::InitialiseFailed => f.write_str("failed to initialise libcec"),
+            CecError::OpenFailed { port } => write!(f, "failed to open adapter `{port}`"),
+            CecError::TransmitFailed => f.write_str("failed to transmit command"),
+            CecError::DetectAdaptersFailed => f.write_str("failed to detect adapters"),
+            CecError::Failed(function) => write!(f, "{function} failed"),
+            CecError::ConnectionLost { message }
+            | CecError::PermissionError { message }
+            | CecError::PortBusy { message }
+            | CecError::PhysicalAddressError { message }
+            | CecError::TvPollFailed { message } => {
+                let alert = self.alert().map_or("alert", Alert::name);
+                match message {
+                    Some(message) => write!(f, "{alert}: {message}"),
+                    None => f.write_str(alert),
+                }
+            }
+            CecError::NulInString => f.write_str("string contains a NUL byte"),
+            CecError::InvalidPath(path) => write!(f, "path `{}` is not UTF-8", path.display()),
+            CecError::InvalidConfiguration(err) => write!(f, "invalid configuration: {err}"),
+            CecError::InvalidCommand(violations) => {
+                f.write_str("invalid command")?;
+                for (i, violation) in violations.iter().enumerate() {
+                    f.write_str(if i == 0 { ": " } else { ", " })?;
+                    write!(f, "{violation}")?;
+                }
+                Ok(())
+            }
+            CecError::Refused {
+                destination,
+                opcode,
+                reason,
+            } => write!(f, "{destination} refused `{opcode}`: {reason}"),
+            CecError::Timeout => f.write_str("operation timed out"),
+            CecError::WorkerStopped => f.write_str("worker thread stopped"),
+            CecError::SpawnFailed(kind) => write!(f, "failed to spawn thread: {kind}"),
+        }
+    }
+}
+
+impl Error for CecError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CecError::InvalidConfiguration(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ConfigurationError> for CecError {
+    fn from(err: ConfigurationError) -> Self {
+        CecError::InvalidConfiguration(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+    use std::os::raw::c_void;
+    use std::ptr;
+
+    use super::*;
+    use crate::enums::ParameterType;
+
+    #[test]
+    fn test_check() {
+        assert_eq!(CecError::check("libcec_ping_adapters", 1), Ok(()));
+        assert_eq!(
+            CecError::check("libcec_ping_adapters", 0),
+            Err(CecError::Failed("libcec_ping_adapters"))
+        );
+        assert_eq!(CecError::check_transmit(1), Ok(()));
+        assert_eq!(
+            CecError::check_open(0, "RPI"),
+            Err(CecError::OpenFailed {
+                port: "RPI".to_owned()
+            })
+        );
+        assert_eq!(CecError::check_open(1, "RPI"), Ok(()));
+        assert_eq!(CecError::check_detect_adapters(2), Ok(2));
+        assert_eq!(
+            CecError::check_initialise(ptr::null_mut()),
+            Err(CecError::InitialiseFailed)
+        );
+    }
+
+    #[test]
+    fn test_alerts() {
+        for alert in Alert::ALL {
+            let err = CecError::from_alert(*alert, None);
+            assert_eq!(err.as_ref().and_then(CecError::alert), {
+                Some(*alert).filter(|alert| *alert != Alert::ServiceDevice)
+            });
+        }
+        assert_eq!(
+            CecError::from_alert(Alert::Unknown(c_enum!(libcec_alert, 42)), None),
+            None
+        );
+        assert_eq!(
+            CecError::from_alert(Alert::TvPollFailed, None)
+                .unwrap()
+                .to_string(),
+            "tv poll failed"
+        );
+    }
+
+    #[test]
+    fn test_raw_alert() {
+        let message = CString::new("connection lost").unwrap();
+        let param = libcec_parameter {
+            paramType: ParameterType::String.raw(),
+            paramData: message.as_ptr() as *mut c_void,
+        };
+        let err = unsafe { CecError::from_raw_alert(Alert::ConnectionLost.raw(), &param) };
+        assert_eq!(
+            err,
+            Some(CecError::ConnectionLost {
+                message: Some("connection lost".to_owned())
+            })
+        );
+
+        let param = libcec_parameter {
+            paramType: ParameterType::Unkown.raw(),
+            paramData: message.as_ptr() as *mut c_void,
+        };
+        let err = unsafe { CecError::from_raw_alert(Alert::PortBusy.raw(), &param) };
+        assert_eq!(err, Some(CecError::PortBusy { message: None }));
+    }
+
+    #[test]
+    fn test_invalid_command() {
+        let err = CecError::InvalidCommand(vec![
+            Violation::SameInitiatorAndDestination,
+            Violation::OperandsWithoutOpcode,
+        ]);
+        assert_eq!(
+            err.to_string(),
+            "invalid command: initiator and destination are the same, operands without an opcode"
+        );
+    }
+
+    #[test]
+    fn test_configuration_error() {
+        let err = CecError::from(ConfigurationError::InvalidHdmiPort(0));
+        assert!(err.source().is_some());
+    }
+}
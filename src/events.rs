@@ -0,0 +1,598 @@
+//! Callbacks as a bounded queue of owned events
+//!
+//! Doing real work in the callbacks stalls the reader thread of libcec. [`channel`] returns an
+//! [`EventSender`], which is a [`CecHandler`] queueing every callback as a [`CecEvent`], and an
+//! [`EventReceiver`] with the interface of [`std::sync::mpsc::Receiver`]. When the queue is
+//! full, the [`OverflowPolicy`] decides which event is lost, and the losses are counted.
+//!
+//! ```
+//! use libcec_sys::events::{self, CecEvent, OverflowPolicy};
+//! use libcec_sys::handler::{CecHandler, KeyPress};
+//! use libcec_sys::enums::UserControlCode;
+//! use std::time::Duration;
+//!
+//! let (sender, receiver) = events::channel(1, OverflowPolicy::DropOldest);
+//! // with the safe feature: Connection::init_with_handler(configuration, sender)
+//! for code in [UserControlCode::Up, UserControlCode::Down] {
+//!     sender.key_press(KeyPress { code, duration: Duration::ZERO });
+//! }
+//! assert!(matches!(
+//!     receiver.try_recv(),
+//!     Ok(CecEvent::KeyPress(KeyPress { code: UserControlCode::Down, .. }))
+//! ));
+//! assert_eq!(receiver.dropped(), 1);
+//! ```
+use std::collections::VecDeque;
+use std::ops::Deref;
+use std::ptr;
+use std::sync::mpsc::{RecvError, RecvTimeoutError, TryRecvError};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, Instant};
+
+use crate::enums::{Alert, LogLevel, LogicalAddress, MenuState};
+use crate::feature_abort::{AbortTracker, Aborted};
+use crate::handler::{CecHandler, KeyPress, LogMessage};
+use crate::*;
+
+/// Owned copy of a libcec callback
+#[derive(Debug, Clone)]
+pub enum CecEvent {
+    Log {
+        message: String,
+        level: LogLevel,
+        /// Milliseconds since libcec was initialised
+        time: i64,
+    },
+    KeyPress(KeyPress),
+    Command(cec_command),
+    /// Received `<Feature Abort>`, instead of a `Command`, with the aborted command when the
+    /// sender has an abort tracker, see [`EventSender::with_abort_tracker`]
+    FeatureAbort(Aborted),
+    /// The new configuration
+    ConfigurationChanged(ChangedConfiguration),
+    Alert {
+        alert: Alert,
+        param: Option<String>,
+    },
+    MenuState(MenuState),
+    SourceActivated {
+        address: LogicalAddress,
+        activated: bool,
+    },
+}
+
+/// Owned copy of a `libcec_configuration`, with `callbacks` and `callbackParam` set to NULL
+#[derive(Debug, Clone)]
+pub struct ChangedConfiguration(Box<libcec_configuration>);
+
+impl ChangedConfiguration {
+    /// Copy of `configuration`, without its callbacks
+    pub fn new(configuration: &libcec_configuration) -> Self {
+        let mut configuration = Box::new(*configuration);
+        configuration.callbacks = ptr::null_mut();
+        configuration.callbackParam = ptr::null_mut();
+        ChangedConfiguration(configuration)
+    }
+
+    pub fn into_inner(self) -> libcec_configuration {
+        *self.0
+    }
+}
+
+impl Deref for ChangedConfiguration {
+    type Target = libcec_configuration;
+
+    fn deref(&self) -> &libcec_configuration {
+        &self.0
+    }
+}
+
+// SAFETY: `new` sets the only pointers, `callbacks` and `callbackParam`, to NULL, and there is
+// no mutable access to them afterwards; the other fields are plain data
+unsafe impl Send for ChangedConfiguration {}
+unsafe impl Sync for ChangedConfiguration {}
+
+impl From<LogMessage<'_>> for CecEvent {
+    fn from(message: LogMessage<'_>) -> Self {
+        CecEvent::Log {
+            message: message.message.to_owned(),
+            level: message.level,
+            time: message.time,
+        }
+    }
+}
+
+impl From<&libcec_configuration> for CecEvent {
+    fn from(configuration: &libcec_configuration) -> Self {
+        CecEvent::ConfigurationChanged(ChangedConfiguration::new(configuration))
+    }
+}
+
+/// What to do with a new event when the queue is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OverflowPolicy {
+    /// Wait for the receiver, stalling libcec. Events are dropped once the receiver is gone.
+    Block,
+    /// Drop the oldest queued event
+    DropOldest,
+    /// Drop the new event
+    DropNewest,
+}
+
+struct State {
+    events: VecDeque<CecEvent>,
+    dropped: u64,
+    sender_alive: bool,
+    receiver_alive: bool,
+    /// Task waiting in [`EventReceiver::poll_recv`]
+    waker: Option<Waker>,
+}
+
+impl State {
+    fn wake(&mut self) {
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+struct Shared {
+    state: Mutex<State>,
+    not_empty: Condvar,
+    not_full: Condvar,
+    capacity: usize,
+    policy: OverflowPolicy,
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        // events are plain data, so a panic while holding the lock cannot break them
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+/// Queue of up to `capacity` events
+///
+/// # Panics
+///
+/// Panics if `capacity` is 0.
+pub fn channel(capacity: usize, policy: OverflowPolicy) -> (EventSender, EventReceiver) {
+    assert!(capacity > 0, "capacity must be at least 1");
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            events: VecDeque::with_capacity(capacity),
+            dropped: 0,
+            sender_alive: true,
+            receiver_alive: true,
+            waker: None,
+        }),
+        not_empty: Condvar::new(),
+        not_full: Condvar::new(),
+        capacity,
+        policy,
+    });
+    (
+        EventSender {
+            shared: Arc::clone(&shared),
+            aborts: None,
+        },
+        EventReceiver { shared },
+    )
+}
+
+/// Sending half of [`channel`], to be registered as the handler of a connection
+pub struct EventSender {
+    shared: Arc<Shared>,
+    aborts: Option<Arc<AbortTracker>>,
+}
+
+impl EventSender {
+    /// Pass the log messages and commands to `tracker` as well, so that
+    /// [`CecEvent::FeatureAbort`] events carry the command which was aborted
+    pub fn with_abort_tracker(mut self, tracker: Arc<AbortTracker>) -> Self {
+        self.aborts = Some(tracker);
+        self
+    }
+
+    /// Queue `event` following the overflow policy
+    pub fn send(&self, event: CecEvent) {
+        let shared = &*self.shared;
+        let mut state = shared.lock();
+        if shared.policy == OverflowPolicy::Block {
+            while state.events.len() >= shared.capacity && state.receiver_alive {
+                state = shared
+                    .not_full
+                    .wait(state)
+                    .unwrap_or_else(|err| err.into_inner());
+            }
+        }
+        if !state.receiver_alive {
+            state.dropped += 1;
+            return;
+        }
+        if state.events.len() >= shared.capacity {
+            state.dropped += 1;
+            match shared.policy {
+                OverflowPolicy::DropNewest => return,
+                OverflowPolicy::Block | OverflowPolicy::DropOldest => {
+                    state.events.pop_front();
+                }
+            }
+        }
+        state.events.push_back(event);
+        state.wake();
+        shared.not_empty.notify_one();
+    }
+
+    /// Number of events dropped so far
+    pub fn dropped(&self) -> u64 {
+        self.shared.lock().dropped
+    }
+}
+
+impl Drop for EventSender {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.sender_alive = false;
+        state.wake();
+        self.shared.not_empty.notify_all();
+    }
+}
+
+impl CecHandler for EventSender {
+    fn log_message(&self, message: LogMessage<'_>) {
+        if let Some(tracker) = &self.aborts {
+            tracker.log_message(message);
+        }
+        self.send(message.into());
+    }
+
+    fn key_press(&self, key: KeyPress) {
+        self.send(CecEvent::KeyPress(key));
+    }
+
+    fn command_received(&self, command: &cec_command) {
+        let aborted = match &self.aborts {
+            Some(tracker) => AbortTracker::command_received(tracker, command),
+            None => Aborted::from_command(command),
+        };
+        match aborted {
+            Some(aborted) => self.send(CecEvent::FeatureAbort(aborted)),
+            None => self.send(CecEvent::Command(*command)),
+        }
+    }
+
+    fn configuration_changed(&self, configuration: &libcec_configuration) {
+        self.send(configuration.into());
+    }
+
+    fn alert(&self, alert: Alert, param: Option<&str>) {
+        self.send(CecEvent::Alert {
+            alert,
+            param: param.map(str::to_owned),
+        });
+    }
+
+    fn menu_state_changed(&self, state: MenuState) -> bool {
+        self.send(CecEvent::MenuState(state));
+        true
+    }
+
+    fn source_activated(&self, address: LogicalAddress, activated: bool) {
+        self.send(CecEvent::SourceActivated { address, activated });
+    }
+}
+
+/// Receiving half of [`channel`]
+///
+/// Once the sender is dropped, e.g. with its connection, the queued events can still be
+/// received, after which receiving fails with `Disconnected`.
+pub struct EventReceiver {
+    shared: Arc<Shared>,
+}
+
+impl EventReceiver {
+    fn pop(&self, state: &mut State) -> Option<CecEvent> {
+        let event = state.events.pop_front();
+        if event.is_some() {
+            self.shared.not_full.notify_one();
+        }
+        event
+    }
+
+    pub fn try_recv(&self) -> Result<CecEvent, TryRecvError> {
+        let mut state = self.shared.lock();
+        match self.pop(&mut state) {
+            Some(event) => Ok(event),
+            None if state.sender_alive => Err(TryRecvError::Empty),
+            None => Err(TryRecvError::Disconnected),
+        }
+    }
+
+    /// Wait for an event
+    pub fn recv(&self) -> Result<CecEvent, RecvError> {
+        let mut state = self.shared.lock();
+        loop {
+            if let Some(event) = self.pop(&mut state) {
+                return Ok(event);
+            }
+            if !state.sender_alive {
+                return Err(RecvError);
+            }
+            state = self
+                .shared
+                .not_empty
+                .wait(state)
+                .unwrap_or_else(|err| err.into_inner());
+        }
+    }
+
+    /// Wait for an event for at most `timeout`
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<CecEvent, RecvTimeoutError> {
+        let deadline = Instant::now().checked_add(timeout);
+        let mut state = self.shared.lock();
+        loop {
+            if let Some(event) = self.pop(&mut state) {
+                return Ok(event);
+            }
+            if !state.sender_alive {
+                return Err(RecvTimeoutError::Disconnected);
+            }
+            state = match deadline {
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return Err(RecvTimeoutError::Timeout);
+                    }
+                    self.shared
+                        .not_empty
+                        .wait_timeout(state, deadline - now)
+                        .unwrap_or_else(|err| err.into_inner())
+                        .0
+                }
+                None => self
+                    .shared
+                    .not_empty
+                    .wait(state)
+                    .unwrap_or_else(|err| err.into_inner()),
+            };
+        }
+    }
+
+    /// Poll for an event, `None` once the sender is dropped and the queue is empty
+    pub fn poll_recv(&self, cx: &mut Context<'_>) -> Poll<Option<CecEvent>> {
+        let mut state = self.shared.lock();
+        if let Some(event) = self.pop(&mut state) {
+            return Poll::Ready(Some(event));
+        }
+        if !state.sender_alive {
+            return Poll::Ready(None);
+        }
+        state.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+
+    /// Blocking iterator over the events, ending when the sender is dropped
+    pub fn iter(&self) -> impl Iterator<Item = CecEvent> + '_ {
+        std::iter::from_fn(|| self.recv().ok())
+    }
+
+    /// Non-blocking iterator over the queued events
+    pub fn try_iter(&self) -> impl Iterator<Item = CecEvent> + '_ {
+        std::iter::from_fn(|| self.try_recv().ok())
+    }
+
+    /// Number of events dropped so far
+    pub fn dropped(&self) -> u64 {
+        self.shared.lock().dropped
+    }
+
+    /// Number of queued events
+    pub fn len(&self) -> usize {
+        self.shared.lock().events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Stream of the events, ending when the sender is dropped
+#[cfg(feature = "async")]
+impl futures_core::Stream for EventReceiver {
+    type Item = CecEvent;
+
+    fn poll_next(self: std::pin::Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<CecEvent>> {
+        self.poll_recv(cx)
+    }
+}
+
+impl Drop for EventReceiver {
+    fn drop(&mut self) {
+        self.shared.lock().receiver_alive = false;
+        self.shared.not_full.notify_all();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+    use crate::enums::UserControlCode;
+
+    fn key(code: UserControlCode) -> KeyPress {
+        KeyPress {
+            code,
+            duration: Duration::ZERO,
+        }
+    }
+
+    fn received_keys(receiver: &EventReceiver) -> Vec<UserControlCode> {
+        receiver
+            .try_iter()
+            .map(|event| match event {
+                CecEvent::KeyPress(key) => key.code,
+                other => panic!("unexpected event {other:?}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_drop_oldest() {
+        let (sender, receiver) = channel(2, OverflowPolicy::DropOldest);
+        for code in [
+            UserControlCode::Up,
+            UserControlCode::Down,
+            UserControlCode::Left,
+        ] {
+            sender.key_press(key(code));
+        }
+        assert_eq!(
+            received_keys(&receiver),
+            [UserControlCode::Down, UserControlCode::Left]
+        );
+        assert_eq!(receiver.dropped(), 1);
+        assert_eq!(sender.dropped(), 1);
+    }
+
+    #[test]
+    fn test_drop_newest() {
+        let (sender, receiver) = channel(2, OverflowPolicy::DropNewest);
+        for code in [
+            UserControlCode::Up,
+            UserControlCode::Down,
+            UserControlCode::Left,
+        ] {
+            sender.key_press(key(code));
+        }
+        assert_eq!(receiver.len(), 2);
+        assert_eq!(
+            received_keys(&receiver),
+            [UserControlCode::Up, UserControlCode::Down]
+        );
+        assert_eq!(receiver.dropped(), 1);
+    }
+
+    #[test]
+    fn test_block() {
+        let (sender, receiver) = channel(1, OverflowPolicy::Block);
+        let producer = thread::spawn(move || {
+            for _ in 0..100 {
+                sender.key_press(key(UserControlCode::Select));
+            }
+        });
+        assert_eq!(receiver.iter().count(), 100);
+        producer.join().unwrap();
+        assert_eq!(receiver.dropped(), 0);
+        assert!(matches!(receiver.recv(), Err(RecvError)));
+    }
+
+    #[test]
+    fn test_block_without_receiver() {
+        let (sender, receiver) = channel(1, OverflowPolicy::Block);
+        sender.key_press(key(UserControlCode::Select));
+        drop(receiver);
+        sender.key_press(key(UserControlCode::Select));
+        assert_eq!(sender.dropped(), 1);
+    }
+
+    #[test]
+    fn test_disconnected() {
+        let (sender, receiver) = channel(4, OverflowPolicy::DropNewest);
+        assert!(matches!(receiver.try_recv(), Err(TryRecvError::Empty)));
+        assert!(matches!(
+            receiver.recv_timeout(Duration::from_millis(1)),
+            Err(RecvTimeoutError::Timeout)
+        ));
+        sender.source_activated(LogicalAddress::PlaybackDevice1, true);
+        drop(sender);
+        assert!(matches!(
+            receiver.recv_timeout(Duration::from_secs(1)),
+            Ok(CecEvent::SourceActivated {
+                address: LogicalAddress::PlaybackDevice1,
+                activated: true,
+            })
+        ));
+        assert!(matches!(
+            receiver.try_recv(),
+            Err(TryRecvError::Disconnected)
+        ));
+    }
+
+    #[test]
+    fn test_events() {
+        let (sender, receiver) = channel(8, OverflowPolicy::DropNewest);
+        sender.log_message(LogMessage {
+            message: "hello",
+            level: LogLevel::Notice,
+            time: 1,
+        });
+        sender.alert(Alert::PortBusy, Some("busy"));
+        assert!(sender.menu_state_changed(MenuState::Deactivated));
+        let mut configuration = libcec_configuration::default();
+        configuration.callbackParam = &mut configuration as *mut _ as *mut std::ffi::c_void;
+        sender.configuration_changed(&configuration);
+
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(CecEvent::Log { message, level: LogLevel::Notice, time: 1 }) if message == "hello"
+        ));
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(CecEvent::Alert { alert: Alert::PortBusy, param: Some(param) }) if param == "busy"
+        ));
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(CecEvent::MenuState(MenuState::Deactivated))
+        ));
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(CecEvent::ConfigurationChanged(configuration))
+                if configuration.callbackParam.is_null()
+        ));
+    }
+
+    #[test]
+    fn test_feature_abort() {
+        let tracker = Arc::new(AbortTracker::new());
+        let (sender, receiver) = channel(8, OverflowPolicy::DropNewest);
+        let sender = sender.with_abort_tracker(Arc::clone(&tracker));
+        sender.log_message(LogMessage {
+            message: "<< 04:8f",
+            level: LogLevel::Traffic,
+            time: 1,
+        });
+        sender.command_received(&crate::frame::parse_command("40:00:8f:04").unwrap());
+        sender.command_received(&crate::frame::parse_command("40:90:00").unwrap());
+
+        assert!(matches!(receiver.try_recv(), Ok(CecEvent::Log { .. })));
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(CecEvent::FeatureAbort(Aborted {
+                initiator: LogicalAddress::PlaybackDevice1,
+                request: Some(_),
+                ..
+            }))
+        ));
+        assert!(matches!(receiver.try_recv(), Ok(CecEvent::Command(_))));
+        assert!(tracker.is_refused(
+            LogicalAddress::PlaybackDevice1,
+            crate::enums::Opcode::GiveDevicePowerStatus
+        ));
+
+        // without a tracker, aborts are not correlated
+        let (sender, receiver) = channel(8, OverflowPolicy::DropNewest);
+        sender.command_received(&crate::frame::parse_command("40:00:8f:04").unwrap());
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(CecEvent::FeatureAbort(Aborted {
+                opcode: crate::enums::Opcode::GiveDevicePowerStatus,
+                reason: crate::enums::AbortReason::Refused,
+                request: None,
+                ..
+            }))
+        ));
+    }
+}
@@ -0,0 +1,418 @@
+//! `<Feature Abort>` handling
+//!
+//! Devices answer directed messages which they do not support, or do not want to act upon, with
+//! a `<Feature Abort>` carrying the aborted opcode and an [`AbortReason`]. [`Aborted`] is the
+//! decoded message, with the command which caused it when it is known.
+//!
+//! An [`AbortTracker`] remembers the commands sent recently, to correlate the aborts with them,
+//! and records per device the opcodes which it refuses: the ones aborted as
+//! `UnrecognizedOpcode` or `Refused`. The other reasons depend on the state of the device or the
+//! operands, so the opcode may succeed later. [`AbortTracker::transmit`] fails instead of
+//! sending refused opcodes again.
+//!
+//! As a [`CecHandler`], the tracker sees the aborts in `commandReceived` and the commands sent
+//! by libcec itself in the `CEC_LOG_TRAFFIC` log messages. The log message of a command sent
+//! through [`AbortTracker::transmit`] is not recorded again. With
+//! [`crate::events::EventSender::with_abort_tracker`], the
+//! [`crate::events::CecEvent::FeatureAbort`] events carry the command which caused them.
+//!
+//! ```
+//! use libcec_sys::enums::{AbortReason, LogicalAddress, Opcode};
+//! use libcec_sys::feature_abort::AbortTracker;
+//! use libcec_sys::frame;
+//!
+//! let tracker = AbortTracker::new();
+//! let request = frame::parse_command("04:8f").unwrap();
+//! tracker.transmit(&request, |_command| Ok(()))?;
+//! let aborted = tracker
+//!     .command_received(&frame::parse_command("40:00:8f:00").unwrap())
+//!     .unwrap();
+//! assert_eq!(aborted.opcode, Opcode::GiveDevicePowerStatus);
+//! assert_eq!(aborted.reason, AbortReason::UnrecognizedOpcode);
+//! assert!(aborted.request.is_some());
+//! assert!(tracker.is_refused(LogicalAddress::PlaybackDevice1, Opcode::GiveDevicePowerStatus));
+//! assert!(tracker.transmit(&request, |_command| Ok(())).is_err());
+//! # Ok::<(), libcec_sys::error::CecError>(())
+//! ```
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use crate::enums::{AbortReason, LogicalAddress, Opcode};
+use crate::error::CecError;
+use crate::handler::{CecHandler, LogMessage};
+use crate::message::{self, FeatureAbort, Message};
+use crate::query::DEFAULT_TIMEOUT;
+use crate::traffic::{Direction, Traffic};
+use crate::*;
+
+/// Number of sent commands kept for correlating aborts
+pub const MAX_SENT: usize = 32;
+
+/// Received `<Feature Abort>`
+#[derive(Debug, Clone, Copy)]
+pub struct Aborted {
+    /// Device which aborted the message
+    pub initiator: LogicalAddress,
+    pub destination: LogicalAddress,
+    /// Opcode of the aborted message
+    pub opcode: Opcode,
+    pub reason: AbortReason,
+    /// The command which was aborted, if it was sent recently
+    pub request: Option<cec_command>,
+}
+
+impl Aborted {
+    /// Abort of `command`, `None` when it is not a valid `<Feature Abort>`
+    pub fn from_command(command: &cec_command) -> Option<Self> {
+        let abort = FeatureAbort::from_command(command).ok()?;
+        Some(Aborted {
+            initiator: LogicalAddress::from_raw(command.initiator),
+            destination: LogicalAddress::from_raw(command.destination),
+            opcode: abort.opcode,
+            reason: abort.reason,
+            request: None,
+        })
+    }
+
+    /// Whether the device will not act upon the opcode, whatever its state or the operands
+    pub fn is_refusal(&self) -> bool {
+        matches!(
+            self.reason,
+            AbortReason::UnrecognizedOpcode | AbortReason::Refused
+        )
+    }
+}
+
+impl fmt::Display for Aborted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} aborted `{}`: {}",
+            self.initiator, self.opcode, self.reason
+        )
+    }
+}
+
+struct Sent {
+    id: u64,
+    command: cec_command,
+    time: Instant,
+    /// Sent through [`AbortTracker::transmit`], and not seen in the traffic log yet
+    unlogged: bool,
+}
+
+/// Whether `a` and `b` have the same destination, opcode and operands
+fn same_command(a: &cec_command, b: &cec_command) -> bool {
+    a.destination == b.destination
+        && a.opcode_set == b.opcode_set
+        && a.opcode == b.opcode
+        && message::operands(a).ok() == message::operands(b).ok()
+}
+
+struct State {
+    sent: VecDeque<Sent>,
+    next_id: u64,
+    /// Refused opcodes by logical address
+    refused: [Vec<(Opcode, AbortReason)>; 16],
+}
+
+/// Correlates aborts with the commands sent, and records the opcodes refused by each device
+pub struct AbortTracker {
+    state: Mutex<State>,
+    window: Duration,
+}
+
+impl AbortTracker {
+    /// Tracker correlating aborts with commands sent up to [`DEFAULT_TIMEOUT`] earlier
+    pub fn new() -> Self {
+        AbortTracker {
+            state: Mutex::new(State {
+                sent: VecDeque::with_capacity(MAX_SENT),
+                next_id: 0,
+                refused: Default::default(),
+            }),
+            window: DEFAULT_TIMEOUT,
+        }
+    }
+
+    /// Correlate aborts with commands sent up to `window` earlier
+    pub fn with_window(mut self, window: Duration) -> Self {
+        self.window = window;
+        self
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        // the state is plain data, so a panic while holding the lock cannot break it
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// Remember that `command` was sent
+    pub fn transmitted(&self, command: &cec_command) {
+        self.record(command, false);
+    }
+
+    /// Id of the record of `command`, `None` for POLL commands, which are not recorded
+    fn record(&self, command: &cec_command, unlogged: bool) -> Option<u64> {
+        if command.opcode_set == 0 {
+            return None;
+        }
+        let mut state = self.lock();
+        if state.sent.len() >= MAX_SENT {
+            state.sent.pop_front();
+        }
+        let id = state.next_id;
+        state.next_id += 1;
+        state.sent.push_back(Sent {
+            id,
+            command: *command,
+            time: Instant::now(),
+            unlogged,
+        });
+        Some(id)
+    }
+
+    /// Remember `command` seen in the traffic log, unless it was sent through [`Self::transmit`]
+    fn logged(&self, command: &cec_command) {
+        {
+            let mut state = self.lock();
+            let sent = state
+                .sent
+                .iter_mut()
+                .find(|sent| sent.unlogged && same_command(&sent.command, command));
+            if let Some(sent) = sent {
+                sent.unlogged = false;
+                return;
+            }
+        }
+        self.transmitted(command);
+    }
+
+    /// Send `command` with `transmit`, unless its destination refused its opcode
+    pub fn transmit<F>(&self, command: &cec_command, transmit: F) -> Result<(), CecError>
+    where
+        F: FnOnce(&cec_command) -> Result<(), CecError>,
+    {
+        if command.opcode_set != 0 {
+            let destination = LogicalAddress::from_raw(command.destination);
+            let opcode = Opcode::from_raw(command.opcode);
+            if let Some(reason) = self.refusal(destination, opcode) {
+                return Err(CecError::Refused {
+                    destination,
+                    opcode,
+                    reason,
+                });
+            }
+        }
+        // recorded before, as the abort may arrive while `transmit` waits for the ack
+        let id = self.record(command, true);
+        let result = transmit(command);
+        if let (Err(_), Some(id)) = (&result, id) {
+            self.lock().sent.retain(|sent| sent.id != id);
+        }
+        result
+    }
+
+    /// Abort of a received `command`, correlated with the command which caused it
+    ///
+    /// Returns `None` when `command` is no `<Feature Abort>`.
+    pub fn command_received(&self, command: &cec_command) -> Option<Aborted> {
+        let mut aborted = Aborted::from_command(command)?;
+        let mut state = self.lock();
+        let now = Instant::now();
+        let position = state.sent.iter().rposition(|sent| {
+            now.duration_since(sent.time) <= self.window
+                && LogicalAddress::from_raw(sent.command.destination) == aborted.initiator
+                && Opcode::from_raw(sent.command.opcode) == aborted.opcode
+        });
+        aborted.request = position
+            .and_then(|position| state.sent.remove(position))
+            .map(|sent| sent.command);
+        if aborted.is_refusal() {
+            if let Some(index) = aborted.initiator.index() {
+                let refused = &mut state.refused[usize::from(index)];
+                refused.retain(|(opcode, _)| *opcode != aborted.opcode);
+                refused.push((aborted.opcode, aborted.reason));
+            }
+        }
+        Some(aborted)
+    }
+
+    /// Reason why `device` refused `opcode`, `None` unless it did
+    pub fn refusal(&self, device: LogicalAddress, opcode: Opcode) -> Option<AbortReason> {
+        let index = device.index()?;
+        self.lock().refused[usize::from(index)]
+            .iter()
+            .find(|(refused, _)| *refused == opcode)
+            .map(|(_, reason)| *reason)
+    }
+
+    pub fn is_refused(&self, device: LogicalAddress, opcode: Opcode) -> bool {
+        self.refusal(device, opcode).is_some()
+    }
+
+    /// Opcodes refused by `device`, with the reasons, in the order they were refused
+    pub fn refused(&self, device: LogicalAddress) -> Vec<(Opcode, AbortReason)> {
+        device
+            .index()
+            .map(|index| self.lock().refused[usize::from(index)].clone())
+            .unwrap_or_default()
+    }
+
+    /// Forget the opcodes refused by `device`, e.g. when another device takes its address
+    pub fn forget(&self, device: LogicalAddress) {
+        if let Some(index) = device.index() {
+            self.lock().refused[usize::from(index)].clear();
+        }
+    }
+}
+
+impl Default for AbortTracker {
+    fn default() -> Self {
+        AbortTracker::new()
+    }
+}
+
+impl fmt::Debug for AbortTracker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.lock();
+        f.debug_struct("AbortTracker")
+            .field("sent", &state.sent.len())
+            .field("refused", &state.refused)
+            .field("window", &self.window)
+            .finish()
+    }
+}
+
+impl CecHandler for AbortTracker {
+    fn log_message(&self, message: LogMessage<'_>) {
+        if let Some(Ok(traffic)) = Traffic::from_log(&message) {
+            if traffic.direction == Direction::Transmitted {
+                self.logged(&traffic.command);
+            }
+        }
+    }
+
+    fn command_received(&self, command: &cec_command) {
+        AbortTracker::command_received(self, command);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::LogLevel;
+    use crate::frame;
+
+    fn command(hex: &str) -> cec_command {
+        frame::parse_command(hex).unwrap()
+    }
+
+    #[test]
+    fn test_correlation() {
+        let tracker = AbortTracker::new();
+        // sent by libcec, seen in the traffic log
+        tracker.log_message(LogMessage {
+            message: "<< 04:1a:01",
+            level: LogLevel::Traffic,
+            time: 0,
+        });
+        tracker.transmitted(&command("05:1a:01"));
+        let aborted = tracker.command_received(&command("40:00:1a:02")).unwrap();
+        assert_eq!(aborted.initiator, LogicalAddress::PlaybackDevice1);
+        assert_eq!(aborted.reason, AbortReason::CannotProvideSource);
+        assert_eq!(
+            aborted.request.map(|request| request.destination),
+            Some(LogicalAddress::PlaybackDevice1.raw())
+        );
+        // transient reasons are not recorded
+        assert!(!aborted.is_refusal());
+        assert!(tracker.refused(LogicalAddress::PlaybackDevice1).is_empty());
+        // the request was taken by the first abort
+        let aborted = tracker.command_received(&command("40:00:1a:04")).unwrap();
+        assert!(aborted.request.is_none());
+        assert_eq!(
+            tracker.refused(LogicalAddress::PlaybackDevice1),
+            [(Opcode::GiveDeckStatus, AbortReason::Refused)]
+        );
+        assert!(tracker.command_received(&command("40:8f")).is_none());
+    }
+
+    #[test]
+    fn test_transmit_logged() {
+        let tracker = AbortTracker::new();
+        let log = |message| {
+            tracker.log_message(LogMessage {
+                message,
+                level: LogLevel::Traffic,
+                time: 0,
+            })
+        };
+        tracker.transmit(&command("04:8f"), |_| Ok(())).unwrap();
+        log("<< 14:8f");
+        // sent by libcec as well
+        log("<< 14:8f");
+        assert_eq!(tracker.lock().sent.len(), 2);
+        assert!(tracker
+            .command_received(&command("40:00:8f:03"))
+            .unwrap()
+            .request
+            .is_some());
+        assert!(tracker
+            .command_received(&command("40:00:8f:03"))
+            .unwrap()
+            .request
+            .is_some());
+        assert!(tracker
+            .command_received(&command("40:00:8f:03"))
+            .unwrap()
+            .request
+            .is_none());
+        // other operands are other commands
+        tracker.transmit(&command("04:1a:01"), |_| Ok(())).unwrap();
+        log("<< 14:1a:02");
+        assert_eq!(tracker.lock().sent.len(), 2);
+    }
+
+    #[test]
+    fn test_window() {
+        let tracker = AbortTracker::new().with_window(Duration::ZERO);
+        tracker.transmitted(&command("04:8f"));
+        std::thread::sleep(Duration::from_millis(5));
+        let aborted = tracker.command_received(&command("40:00:8f:00")).unwrap();
+        assert!(aborted.request.is_none());
+    }
+
+    #[test]
+    fn test_refused() {
+        let tracker = AbortTracker::new();
+        tracker.command_received(&command("40:00:8f:00"));
+        let request = command("04:8f");
+        assert_eq!(
+            tracker.transmit(&request, |_| Ok(())),
+            Err(CecError::Refused {
+                destination: LogicalAddress::PlaybackDevice1,
+                opcode: Opcode::GiveDevicePowerStatus,
+                reason: AbortReason::UnrecognizedOpcode,
+            })
+        );
+        // other devices and opcodes are still sent
+        assert_eq!(tracker.transmit(&command("08:8f"), |_| Ok(())), Ok(()));
+        assert_eq!(tracker.transmit(&command("04:83"), |_| Ok(())), Ok(()));
+        tracker.forget(LogicalAddress::PlaybackDevice1);
+        assert_eq!(tracker.transmit(&request, |_| Ok(())), Ok(()));
+    }
+
+    #[test]
+    fn test_transmit_failed() {
+        let tracker = AbortTracker::new();
+        assert_eq!(
+            tracker.transmit(&command("04:8f"), |_| Err(CecError::TransmitFailed)),
+            Err(CecError::TransmitFailed)
+        );
+        assert_eq!(tracker.lock().sent.len(), 0);
+        let aborted = tracker.command_received(&command("40:00:8f:04")).unwrap();
+        assert!(aborted.request.is_none());
+    }
+}
@@ -0,0 +1,570 @@
+//! Human readable commands, in the style of `cec-ctl --monitor`
+//!
+//! [`format_command`] names the initiator and destination, the opcode, and each operand of the
+//! decoded [`CecMessage`] with its symbolic value, such as the power status, deck info, vendor
+//! id, user control code or the mute and volume bits of the audio status. [`Style::Compact`]
+//! gives a single line, [`Style::Verbose`] the addresses with their numbers and one operand
+//! per line.
+//!
+//! Operands which do not decode are shown as hex, with the decoding error.
+//!
+//! ```
+//! use libcec_sys::format::{self, Style};
+//! use libcec_sys::frame;
+//!
+//! let command = frame::parse_command("40:82:10:00").unwrap();
+//! assert_eq!(
+//!     format::format_command(&command, Style::Compact),
+//!     "Playback Device 1 -> TV: ACTIVE_SOURCE (0x82): phys-addr: 1.0.0.0"
+//! );
+//! assert_eq!(
+//!     format::format_command(&command, Style::Verbose),
+//!     "Playback Device 1 (4) -> TV (0): ACTIVE_SOURCE (0x82)\n\tphys-addr: 1.0.0.0"
+//! );
+//! ```
+use std::fmt;
+use std::os::raw::{c_int, c_uint};
+
+use crate::address::PhysicalAddress;
+use crate::enums::{
+    self, AbortReason, AnalogueBroadcastType, AudioRate, BroadcastSystem, DeckControlMode,
+    DeckInfo, DeviceType, DisplayControl, LogicalAddress, MenuRequestType, MenuState, Opcode,
+    PlayMode, PowerStatus, RecordStatusInfo, StatusRequest, SystemAudioStatus,
+    TimerClearedStatusData, UserControlCode, VendorId,
+};
+use crate::frame;
+use crate::message::{
+    self, AnalogueService, AudioStatus, CecMessage, ExternalSource, RecordSource,
+    ShortAudioDescriptor, TimerSchedule, TunerInfo, TunerService,
+};
+use crate::*;
+
+/// Names of the logical addresses, as used by `cec-ctl`
+const ADDRESS_NAMES: [&str; 16] = [
+    "TV",
+    "Recording Device 1",
+    "Recording Device 2",
+    "Tuner 1",
+    "Playback Device 1",
+    "Audio System",
+    "Tuner 2",
+    "Tuner 3",
+    "Playback Device 2",
+    "Recording Device 3",
+    "Tuner 4",
+    "Playback Device 3",
+    "Reserved 1",
+    "Reserved 2",
+    "Specific",
+    "Unregistered",
+];
+
+/// Layout of a formatted command
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Style {
+    /// One line, e.g. `Playback Device 1 -> TV: ACTIVE_SOURCE (0x82): phys-addr: 1.0.0.0`
+    #[default]
+    Compact,
+    /// The addresses with their numbers, then one operand per line, indented with a tab
+    Verbose,
+}
+
+/// Operand of a message with its symbolic value
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operand {
+    /// Name of the operand, e.g. `phys-addr`
+    pub name: String,
+    /// Value of the operand, e.g. `1.0.0.0` or `on (0x00)`
+    pub value: String,
+}
+
+impl Operand {
+    fn new(name: &str, value: impl Into<String>) -> Self {
+        Operand {
+            name: label(name),
+            value: value.into(),
+        }
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.name, self.value)
+    }
+}
+
+/// `cec-ctl` name of the operand of a message field, the field name in kebab case otherwise
+fn label(field: &str) -> String {
+    match field {
+        "physical_address" => "phys-addr".to_owned(),
+        "original_address" => "orig-phys-addr".to_owned(),
+        "new_address" => "new-phys-addr".to_owned(),
+        "device_type" => "prim-devtype".to_owned(),
+        "vendor" => "vendor-id".to_owned(),
+        "code" => "ui-cmd".to_owned(),
+        "opcode" => "abort-msg".to_owned(),
+        _ => field.replace('_', "-"),
+    }
+}
+
+/// Symbolic value of an operand type
+pub(crate) trait Describe {
+    /// Appends the operand called `name`, or its parts, to `operands`
+    fn describe(&self, name: &'static str, operands: &mut Vec<Operand>);
+}
+
+/// Operands of a message, implemented by `message!`
+pub(crate) trait Operands {
+    fn describe_operands(&self, operands: &mut Vec<Operand>);
+}
+
+/// Operands of `message` with their symbolic values
+///
+/// Optional operands which are left out, and empty lists, are not included.
+pub fn operands(message: &CecMessage) -> Vec<Operand> {
+    let mut operands = Vec::new();
+    message.describe_operands(&mut operands);
+    operands
+}
+
+/// `cec-ctl` name of a logical address, e.g. `"Playback Device 1"`
+///
+/// Address 15 is `"Unregistered"`, its meaning as an initiator. Commands to it are shown as
+/// sent to `Broadcast`.
+pub fn address_name(address: LogicalAddress) -> &'static str {
+    address
+        .index()
+        .map_or("Unknown", |index| ADDRESS_NAMES[usize::from(index)])
+}
+
+fn destination_name(address: LogicalAddress) -> &'static str {
+    if address == LogicalAddress::Broadcast {
+        "Broadcast"
+    } else {
+        address_name(address)
+    }
+}
+
+/// Name of `opcode` in the CEC specification, e.g. `"ACTIVE_SOURCE"`
+///
+/// This is the name of the `cec_opcode` constant, where [`Opcode::name`] is the libcec name.
+pub fn opcode_name(opcode: Opcode) -> String {
+    if let Opcode::Unknown(_) = opcode {
+        return "UNKNOWN".to_owned();
+    }
+    let mut name = String::new();
+    for (index, c) in format!("{opcode:?}").chars().enumerate() {
+        if index > 0 && c.is_ascii_uppercase() {
+            name.push('_');
+        }
+        name.push(c.to_ascii_uppercase());
+    }
+    name
+}
+
+fn opcode_value(opcode: Opcode) -> String {
+    let raw: c_uint = c_enum_value!(opcode.raw());
+    format!("{} ({raw:#04x})", opcode_name(opcode))
+}
+
+/// Formats `command` in `style`, see [`display`]
+pub fn format_command(command: &cec_command, style: Style) -> String {
+    display(command, style).to_string()
+}
+
+/// `command`, displayed in `style`
+pub fn display(command: &cec_command, style: Style) -> DisplayCommand<'_> {
+    DisplayCommand { command, style }
+}
+
+/// Display of a command, returned by [`display`]
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayCommand<'a> {
+    command: &'a cec_command,
+    style: Style,
+}
+
+impl DisplayCommand<'_> {
+    fn operands(&self) -> Vec<Operand> {
+        let mut operands = Vec::new();
+        let bytes = match message::operands(self.command) {
+            Ok(bytes) => bytes,
+            Err(err) => {
+                operands.push(Operand::new("error", err.to_string()));
+                return operands;
+            }
+        };
+        if self.command.opcode_set == 0 {
+            return operands;
+        }
+        let opcode = Opcode::from_raw(self.command.opcode);
+        match CecMessage::decode(opcode, bytes) {
+            Ok(message) => message.describe_operands(&mut operands),
+            Err(err) => {
+                if !bytes.is_empty() {
+                    operands.push(Operand::new("operands", frame::format_hex(bytes)));
+                }
+                if !matches!(err, message::MessageError::UnknownOpcode(_)) {
+                    operands.push(Operand::new("error", err.to_string()));
+                }
+            }
+        }
+        operands
+    }
+}
+
+impl fmt::Display for DisplayCommand<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let initiator = LogicalAddress::from_raw(self.command.initiator);
+        let destination = LogicalAddress::from_raw(self.command.destination);
+        let opcode = if self.command.opcode_set == 0 {
+            "POLL".to_owned()
+        } else {
+            opcode_value(Opcode::from_raw(self.command.opcode))
+        };
+        let operands = self.operands();
+        match self.style {
+            Style::Compact => {
+                write!(
+                    f,
+                    "{} -> {}: {opcode}",
+                    address_name(initiator),
+                    destination_name(destination)
+                )?;
+                for (index, operand) in operands.iter().enumerate() {
+                    f.write_str(if index == 0 { ": " } else { ", " })?;
+                    write!(f, "{operand}")?;
+                }
+            }
+            Style::Verbose => {
+                let initiator_index: c_int = c_enum_value!(initiator.raw());
+                let destination_index: c_int = c_enum_value!(destination.raw());
+                write!(
+                    f,
+                    "{} ({initiator_index}) -> {} ({destination_index}): {opcode}",
+                    address_name(initiator),
+                    destination_name(destination)
+                )?;
+                for operand in &operands {
+                    write!(f, "\n\t{operand}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Enums formatted as their libcec name and value, e.g. `on (0x00)`
+macro_rules! describe_enums {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Describe for $ty {
+                fn describe(&self, name: &'static str, operands: &mut Vec<Operand>) {
+                    let raw: c_uint = c_enum_value!(self.raw());
+                    operands.push(Operand::new(name, format!("{} ({raw:#04x})", self.name())));
+                }
+            }
+        )*
+    };
+}
+
+describe_enums!(
+    AbortReason,
+    AnalogueBroadcastType,
+    AudioRate,
+    BroadcastSystem,
+    enums::CecVersion,
+    DeckControlMode,
+    DeckInfo,
+    DeviceType,
+    DisplayControl,
+    MenuRequestType,
+    MenuState,
+    PlayMode,
+    PowerStatus,
+    RecordStatusInfo,
+    StatusRequest,
+    SystemAudioStatus,
+    TimerClearedStatusData,
+    UserControlCode,
+);
+
+impl Describe for Opcode {
+    fn describe(&self, name: &'static str, operands: &mut Vec<Operand>) {
+        operands.push(Operand::new(name, opcode_value(*self)));
+    }
+}
+
+impl Describe for VendorId {
+    fn describe(&self, name: &'static str, operands: &mut Vec<Operand>) {
+        let raw: c_uint = c_enum_value!(self.raw());
+        operands.push(Operand::new(name, format!("{} ({raw:#08x})", self.name())));
+    }
+}
+
+impl Describe for u8 {
+    fn describe(&self, name: &'static str, operands: &mut Vec<Operand>) {
+        operands.push(Operand::new(name, format!("{self:#04x}")));
+    }
+}
+
+impl<const N: usize> Describe for [u8; N] {
+    fn describe(&self, name: &'static str, operands: &mut Vec<Operand>) {
+        operands.push(Operand::new(name, frame::format_hex(self)));
+    }
+}
+
+impl Describe for Vec<u8> {
+    fn describe(&self, name: &'static str, operands: &mut Vec<Operand>) {
+        if !self.is_empty() {
+            operands.push(Operand::new(name, frame::format_hex(self)));
+        }
+    }
+}
+
+/// One operand per descriptor
+impl Describe for Vec<ShortAudioDescriptor> {
+    fn describe(&self, name: &'static str, operands: &mut Vec<Operand>) {
+        for descriptor in self {
+            descriptor.describe(name, operands);
+        }
+    }
+}
+
+impl<T: Describe> Describe for Option<T> {
+    fn describe(&self, name: &'static str, operands: &mut Vec<Operand>) {
+        if let Some(value) = self {
+            value.describe(name, operands);
+        }
+    }
+}
+
+impl Describe for String {
+    fn describe(&self, name: &'static str, operands: &mut Vec<Operand>) {
+        operands.push(Operand::new(name, self.as_str()));
+    }
+}
+
+impl Describe for PhysicalAddress {
+    fn describe(&self, name: &'static str, operands: &mut Vec<Operand>) {
+        operands.push(Operand::new(name, self.to_string()));
+    }
+}
+
+impl Describe for AnalogueService {
+    fn describe(&self, _name: &'static str, operands: &mut Vec<Operand>) {
+        self.broadcast_type.describe("ana_bcast_type", operands);
+        // in units of 62.5 kHz
+        let mhz = f64::from(self.frequency) / 16.0;
+        operands.push(Operand::new(
+            "ana_freq",
+            format!("{} ({mhz:.2} MHz)", self.frequency),
+        ));
+        self.broadcast_system.describe("bcast_system", operands);
+    }
+}
+
+impl Describe for TimerSchedule {
+    fn describe(&self, _name: &'static str, operands: &mut Vec<Operand>) {
+        operands.push(Operand::new("day", self.day_of_month.to_string()));
+        operands.push(Operand::new("month", self.month_of_year.to_string()));
+        operands.push(Operand::new(
+            "start",
+            format!("{:02}:{:02}", self.start_hour, self.start_minute),
+        ));
+        operands.push(Operand::new(
+            "duration",
+            format!("{:02}:{:02}", self.duration_hours, self.duration_minutes),
+        ));
+        self.recording_sequence.describe("rec_seq", operands);
+    }
+}
+
+impl Describe for ExternalSource {
+    fn describe(&self, _name: &'static str, operands: &mut Vec<Operand>) {
+        match self {
+            ExternalSource::Plug(plug) => operands.push(Operand::new("plug", plug.to_string())),
+            ExternalSource::PhysicalAddress(address) => address.describe("ext_phys_addr", operands),
+        }
+    }
+}
+
+impl Describe for RecordSource {
+    fn describe(&self, name: &'static str, operands: &mut Vec<Operand>) {
+        match self {
+            RecordSource::OwnSource => operands.push(Operand::new(name, "own source")),
+            RecordSource::DigitalService(service) => service.describe("digital_service", operands),
+            RecordSource::AnalogueService(service) => service.describe(name, operands),
+            RecordSource::ExternalPlug(plug) => {
+                ExternalSource::Plug(*plug).describe(name, operands)
+            }
+            RecordSource::ExternalPhysicalAddress(address) => {
+                ExternalSource::PhysicalAddress(*address).describe(name, operands)
+            }
+        }
+    }
+}
+
+impl Describe for TunerInfo {
+    fn describe(&self, _name: &'static str, operands: &mut Vec<Operand>) {
+        let recording = if self.recording { "used" } else { "not used" };
+        operands.push(Operand::new("rec_flag", recording));
+        let raw: c_uint = c_enum_value!(self.display.raw());
+        operands.push(Operand::new(
+            "tuner_display_info",
+            format!("{} ({raw:#04x})", self.display.name()),
+        ));
+    }
+}
+
+impl Describe for TunerService {
+    fn describe(&self, name: &'static str, operands: &mut Vec<Operand>) {
+        match self {
+            TunerService::Analogue(service) => service.describe(name, operands),
+            TunerService::Digital(service) => service.describe("digital_service", operands),
+        }
+    }
+}
+
+/// The mute and volume bits as separate operands
+impl Describe for AudioStatus {
+    fn describe(&self, _name: &'static str, operands: &mut Vec<Operand>) {
+        let muted = if self.muted { "on" } else { "off" };
+        operands.push(Operand::new("aud_mute_status", muted));
+        let volume = match self.volume {
+            AudioStatus::VOLUME_UNKNOWN => "unknown".to_owned(),
+            volume if volume > AudioStatus::VOLUME_MAX => format!("reserved ({volume:#04x})"),
+            volume => volume.to_string(),
+        };
+        operands.push(Operand::new("aud_vol_status", volume));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::{
+        DeckStatus, FeatureAbort, Message, ReportAudioStatus, ReportPowerStatus, UserControlPressed,
+    };
+
+    fn compact(command: &str) -> String {
+        format_command(&frame::parse_command(command).unwrap(), Style::Compact)
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(
+            address_name(LogicalAddress::PlaybackDevice1),
+            "Playback Device 1"
+        );
+        assert_eq!(address_name(LogicalAddress::Broadcast), "Unregistered");
+        assert_eq!(address_name(LogicalAddress::UNKNOWN), "Unknown");
+        assert_eq!(opcode_name(Opcode::ActiveSource), "ACTIVE_SOURCE");
+        assert_eq!(
+            opcode_name(Opcode::GiveTunerDeviceStatus),
+            "GIVE_TUNER_DEVICE_STATUS"
+        );
+        assert_eq!(opcode_name(Opcode::Cdc), "CDC");
+        assert_eq!(
+            opcode_name(Opcode::Unknown(c_enum!(cec_opcode, 0x01))),
+            "UNKNOWN"
+        );
+    }
+
+    #[test]
+    fn test_symbolic_values() {
+        let command = ReportPowerStatus {
+            status: PowerStatus::On,
+        }
+        .to_command(LogicalAddress::Tv, LogicalAddress::PlaybackDevice1)
+        .unwrap();
+        assert_eq!(
+            format_command(&command, Style::Compact),
+            "TV -> Playback Device 1: REPORT_POWER_STATUS (0x90): status: on (0x00)"
+        );
+        let command = DeckStatus {
+            info: DeckInfo::Play,
+        }
+        .to_command(LogicalAddress::PlaybackDevice1, LogicalAddress::Tv)
+        .unwrap();
+        assert!(format_command(&command, Style::Compact).ends_with("info: play (0x11)"));
+        let command = UserControlPressed {
+            code: UserControlCode::Select,
+            parameters: Vec::new(),
+        }
+        .to_command(LogicalAddress::Tv, LogicalAddress::PlaybackDevice1)
+        .unwrap();
+        assert!(format_command(&command, Style::Compact).ends_with(": ui-cmd: select (0x00)"));
+        let command = FeatureAbort {
+            opcode: Opcode::GiveDeckStatus,
+            reason: AbortReason::Refused,
+        }
+        .to_command(LogicalAddress::PlaybackDevice1, LogicalAddress::Tv)
+        .unwrap();
+        assert!(format_command(&command, Style::Compact)
+            .ends_with(": abort-msg: GIVE_DECK_STATUS (0x1a), reason: refused (0x04)"));
+        assert_eq!(
+            compact("4f:87:00:15:82"),
+            "Playback Device 1 -> Broadcast: DEVICE_VENDOR_ID (0x87): \
+             vendor-id: Pulse Eight (0x001582)"
+        );
+    }
+
+    #[test]
+    fn test_audio_status() {
+        let status = |byte| {
+            let command = frame::decode(&[0x50, 0x7a, byte]).unwrap();
+            let message = ReportAudioStatus::from_command(&command).unwrap();
+            operands(&message.into())
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        assert_eq!(status(0x32), "aud-mute-status: off, aud-vol-status: 50");
+        assert_eq!(status(0xff), "aud-mute-status: on, aud-vol-status: unknown");
+        assert_eq!(
+            status(0x65),
+            "aud-mute-status: off, aud-vol-status: reserved (0x65)"
+        );
+    }
+
+    #[test]
+    fn test_undecoded() {
+        assert_eq!(
+            compact("11"),
+            "Recording Device 1 -> Recording Device 1: POLL"
+        );
+        assert_eq!(
+            compact("0f:01:02"),
+            "TV -> Broadcast: UNKNOWN (0x01): operands: 02"
+        );
+        assert_eq!(
+            compact("04:90"),
+            "TV -> Playback Device 1: REPORT_POWER_STATUS (0x90): \
+             error: invalid length 0 of `report power status` operands"
+        );
+        assert_eq!(
+            format_command(
+                &frame::parse_command("04:47:4b:0a").unwrap(),
+                Style::Verbose
+            ),
+            "TV (0) -> Playback Device 1 (4): SET_OSD_NAME (0x47)\n\
+             \toperands: 4b:0a\n\
+             \terror: invalid `set osd name` operand `name`"
+        );
+    }
+
+    #[test]
+    fn test_verbose() {
+        let command = frame::parse_command("4f:84:10:00:04").unwrap();
+        assert_eq!(
+            format!("{}", display(&command, Style::Verbose)),
+            "Playback Device 1 (4) -> Broadcast (15): REPORT_PHYSICAL_ADDRESS (0x84)\n\
+             \tphys-addr: 1.0.0.0\n\
+             \tprim-devtype: playback device (0x04)"
+        );
+    }
+}
@@ -0,0 +1,269 @@
+//! Raw CEC frames
+//!
+//! A frame is the bytes of a message on the bus: a header block with the initiator and
+//! destination addresses in its high and low nibbles, then the opcode and operands. A frame of
+//! only the header is a POLL message, a `cec_command` with `opcode_set = 0`.
+//!
+//! Frames are written as hex bytes separated by `:`, as with `tx` of `cec-client`.
+//!
+//! ```
+//! use libcec_sys::enums::{LogicalAddress, Opcode};
+//! use libcec_sys::frame;
+//!
+//! let command = frame::parse_command("4f:82:10:00").unwrap();
+//! assert_eq!(LogicalAddress::from_raw(command.initiator), LogicalAddress::PlaybackDevice1);
+//! assert_eq!(LogicalAddress::from_raw(command.destination), LogicalAddress::Broadcast);
+//! assert_eq!(Opcode::from_raw(command.opcode), Opcode::ActiveSource);
+//! assert_eq!(frame::format_command(&command).unwrap(), "4f:82:10:00");
+//! ```
+use std::error::Error;
+use std::fmt;
+use std::os::raw::{c_int, c_uint};
+
+use crate::enums::{LogicalAddress, Opcode};
+use crate::message;
+use crate::*;
+
+/// Maximum size of a frame on the bus: 16 blocks, the header, the opcode and 14 operands
+///
+/// This is less than the header, the opcode and the [`message::MAX_OPERANDS_SIZE`] operands
+/// which fit a `cec_command`.
+pub const MAX_FRAME_SIZE: usize = 16;
+
+/// Error converting a frame
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FrameError {
+    /// Frame without a header block
+    Empty,
+    /// Frame longer than [`MAX_FRAME_SIZE`]
+    TooLong { length: usize },
+    /// Hex string which is not bytes separated by `:`
+    InvalidHex(String),
+    /// Initiator or destination which is not a logical address from 0 to 15
+    InvalidAddress(c_int),
+    /// Opcode which does not fit a byte
+    InvalidOpcode(c_uint),
+    /// POLL command, with `opcode_set = 0`, with operands
+    OperandsWithoutOpcode,
+}
+
+impl fmt::Display for FrameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FrameError::Empty => f.write_str("empty frame"),
+            FrameError::TooLong { length } => write!(
+                f,
+                "frame of {length} bytes, more than the maximum of {MAX_FRAME_SIZE}"
+            ),
+            FrameError::InvalidHex(s) => {
+                write!(
+                    f,
+                    "invalid frame `{s}`, expected hex bytes such as `4f:82:10:00`"
+                )
+            }
+            FrameError::InvalidAddress(address) => write!(f, "invalid logical address {address}"),
+            FrameError::InvalidOpcode(opcode) => write!(f, "invalid opcode {opcode:#x}"),
+            FrameError::OperandsWithoutOpcode => f.write_str("operands without an opcode"),
+        }
+    }
+}
+
+impl Error for FrameError {}
+
+fn address_index(address: cec_logical_address) -> Result<u8, FrameError> {
+    LogicalAddress::from_raw(address)
+        .index()
+        .ok_or(FrameError::InvalidAddress(c_enum_value!(address)))
+}
+
+fn nibble_address(nibble: u8) -> cec_logical_address {
+    c_enum!(cec_logical_address, c_int::from(nibble))
+}
+
+/// Frame of `command`
+///
+/// `ack`, `eom` and `transmit_timeout` are not part of the frame and are ignored.
+pub fn encode(command: &cec_command) -> Result<Vec<u8>, FrameError> {
+    let header = (address_index(command.initiator)? << 4) | address_index(command.destination)?;
+    let operands = message::operands(command).map_err(|_| FrameError::TooLong {
+        length: 2 + usize::from(command.parameters.size),
+    })?;
+    if command.opcode_set == 0 {
+        if !operands.is_empty() {
+            return Err(FrameError::OperandsWithoutOpcode);
+        }
+        return Ok(vec![header]);
+    }
+    let opcode: c_uint = c_enum_value!(command.opcode);
+    let opcode = u8::try_from(opcode).map_err(|_| FrameError::InvalidOpcode(opcode))?;
+    if 2 + operands.len() > MAX_FRAME_SIZE {
+        return Err(FrameError::TooLong {
+            length: 2 + operands.len(),
+        });
+    }
+    let mut frame = Vec::with_capacity(2 + operands.len());
+    frame.extend_from_slice(&[header, opcode]);
+    frame.extend_from_slice(operands);
+    Ok(frame)
+}
+
+/// Command of a complete received `frame`
+///
+/// `eom` is set, as the frame is complete. The frame does not say whether it was
+/// acknowledged, so `ack` is left unset.
+pub fn decode(frame: &[u8]) -> Result<cec_command, FrameError> {
+    let (&header, rest) = frame.split_first().ok_or(FrameError::Empty)?;
+    if frame.len() > MAX_FRAME_SIZE {
+        return Err(FrameError::TooLong {
+            length: frame.len(),
+        });
+    }
+    let mut command = cec_command {
+        initiator: nibble_address(header >> 4),
+        destination: nibble_address(header & 0xf),
+        eom: 1,
+        opcode: Opcode::None.raw(),
+        ..Default::default()
+    };
+    if let Some((&opcode, operands)) = rest.split_first() {
+        command.opcode = c_enum!(cec_opcode, opcode.into());
+        command.opcode_set = 1;
+        command.parameters.data[..operands.len()].copy_from_slice(operands);
+        command.parameters.size = operands.len() as u8;
+    }
+    Ok(command)
+}
+
+/// Bytes of a hex frame such as `"4f:82:10:00"`
+///
+/// Bytes are separated by `:` or spaces, and a leading `tx` is ignored, so that `cec-client`
+/// commands can be pasted.
+pub fn parse_hex(s: &str) -> Result<Vec<u8>, FrameError> {
+    let invalid = || FrameError::InvalidHex(s.to_owned());
+    let hex = s.trim();
+    let hex = match hex.strip_prefix("tx") {
+        Some(rest) if rest.is_empty() || rest.starts_with(char::is_whitespace) => rest,
+        _ => hex,
+    };
+    let frame = hex
+        .split(|c: char| c == ':' || c.is_ascii_whitespace())
+        .filter(|byte| !byte.is_empty())
+        .map(|byte| match byte.len() {
+            1 | 2 => u8::from_str_radix(byte, 16).map_err(|_| invalid()),
+            _ => Err(invalid()),
+        })
+        .collect::<Result<Vec<u8>, FrameError>>()?;
+    if frame.is_empty() {
+        return Err(FrameError::Empty);
+    }
+    if frame.len() > MAX_FRAME_SIZE {
+        return Err(FrameError::TooLong {
+            length: frame.len(),
+        });
+    }
+    Ok(frame)
+}
+
+/// Hex frame of `frame`, e.g. `"4f:82:10:00"`
+pub fn format_hex(frame: &[u8]) -> String {
+    frame
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+/// Command of a hex frame, see [`parse_hex`] and [`decode`]
+pub fn parse_command(s: &str) -> Result<cec_command, FrameError> {
+    decode(&parse_hex(s)?)
+}
+
+/// Hex frame of `command`, see [`encode`] and [`format_hex`]
+pub fn format_command(command: &cec_command) -> Result<String, FrameError> {
+    encode(command).map(|frame| format_hex(&frame))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::{CecMessage, Message, Standby};
+
+    #[test]
+    fn test_poll() {
+        let command = parse_command("11").unwrap();
+        assert_eq!(command.opcode_set, 0);
+        assert_eq!(Opcode::from_raw(command.opcode), Opcode::None);
+        assert_eq!(
+            LogicalAddress::from_raw(command.destination),
+            LogicalAddress::RecordingDevice1
+        );
+        assert_eq!(encode(&command), Ok(vec![0x11]));
+
+        let mut invalid = command;
+        invalid.parameters.size = 1;
+        assert_eq!(encode(&invalid), Err(FrameError::OperandsWithoutOpcode));
+    }
+
+    #[test]
+    fn test_message() {
+        let command = Standby
+            .to_command(LogicalAddress::Tv, LogicalAddress::Broadcast)
+            .unwrap();
+        assert_eq!(format_command(&command).unwrap(), "0f:36");
+        let parsed = parse_command("tx 0F:36").unwrap();
+        assert_eq!(parsed.eom, 1);
+        assert_eq!(parsed.ack, 0);
+        assert_eq!(
+            CecMessage::from_command(&parsed),
+            Ok(CecMessage::Standby(Standby))
+        );
+    }
+
+    #[test]
+    fn test_hex() {
+        assert_eq!(parse_hex("4f:82:10:00"), Ok(vec![0x4f, 0x82, 0x10, 0x00]));
+        assert_eq!(parse_hex(" 4f 82 1:0 "), Ok(vec![0x4f, 0x82, 0x01, 0x00]));
+        assert_eq!(parse_hex(""), Err(FrameError::Empty));
+        assert_eq!(parse_hex("tx "), Err(FrameError::Empty));
+        assert_eq!(
+            parse_hex("4f:823"),
+            Err(FrameError::InvalidHex("4f:823".to_owned()))
+        );
+        assert_eq!(
+            parse_hex("4g"),
+            Err(FrameError::InvalidHex("4g".to_owned()))
+        );
+        assert_eq!(
+            parse_hex(&format_hex(&[0; 16])).map(|frame| frame.len()),
+            Ok(16)
+        );
+        assert_eq!(
+            parse_hex(&format_hex(&[0; 17])),
+            Err(FrameError::TooLong { length: 17 })
+        );
+        assert_eq!(format_hex(&[0x4f, 0x82, 0x10, 0x00]), "4f:82:10:00");
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(decode(&[]).err(), Some(FrameError::Empty));
+        assert_eq!(
+            decode(&[0; 17]).err(),
+            Some(FrameError::TooLong { length: 17 })
+        );
+        // fits a `cec_command`, but not the bus
+        let mut command = parse_command("10:04").unwrap();
+        command.parameters.size = 15;
+        assert_eq!(encode(&command), Err(FrameError::TooLong { length: 17 }));
+        let command = cec_command {
+            initiator: LogicalAddress::UNKNOWN.raw(),
+            ..parse_command("10:04").unwrap()
+        };
+        assert_eq!(encode(&command), Err(FrameError::InvalidAddress(-1)));
+        let command = cec_command {
+            opcode: Opcode::Unknown(c_enum!(cec_opcode, 0x100)).raw(),
+            ..parse_command("10:04").unwrap()
+        };
+        assert_eq!(encode(&command), Err(FrameError::InvalidOpcode(0x100)));
+    }
+}
@@ -0,0 +1,449 @@
+//! Trait based callbacks
+//!
+//! Implement [`CecHandler`] instead of writing `extern "C"` functions for each
+//! [`ICECCallbacks`] slot. [`callbacks`] returns trampolines which convert the raw pointers
+//! of libcec to borrowed safe types, and catch panics so that they never unwind into C. With
+//! the `safe` feature, `Connection::init_with_handler` and `Connection::set_handler`
+//! register a handler.
+//!
+//! ```
+//! use libcec_sys::enums::LogLevel;
+//! use libcec_sys::handler::{CecHandler, KeyPress, LogMessage};
+//!
+//! struct Printer;
+//!
+//! impl CecHandler for Printer {
+//!     fn log_message(&self, message: LogMessage<'_>) {
+//!         if message.level == LogLevel::Error {
+//!             eprintln!("{}", message.message);
+//!         }
+//!     }
+//!
+//!     fn key_press(&self, key: KeyPress) {
+//!         println!("{} pressed", key.code);
+//!     }
+//! }
+//!
+//! let callbacks = libcec_sys::handler::callbacks::<Printer>();
+//! assert!(callbacks.keyPress.is_some());
+//! ```
+use std::borrow::Cow;
+use std::ffi::CStr;
+use std::os::raw::{c_int, c_void};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::enums::{Alert, LogLevel, LogicalAddress, MenuState, ParameterType, UserControlCode};
+use crate::*;
+
+/// Log message of libcec
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogMessage<'a> {
+    /// The message, with invalid UTF-8 replaced
+    pub message: &'a str,
+    pub level: LogLevel,
+    /// Milliseconds since libcec was initialised
+    pub time: i64,
+}
+
+/// Key press received from the CEC bus
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyPress {
+    pub code: UserControlCode,
+    /// How long the key was held, zero when it was just pressed
+    pub duration: Duration,
+}
+
+impl From<&cec_keypress> for KeyPress {
+    fn from(key: &cec_keypress) -> Self {
+        KeyPress {
+            code: UserControlCode::from_raw(key.keycode),
+            duration: Duration::from_millis(key.duration.into()),
+        }
+    }
+}
+
+/// Handler of the libcec callbacks
+///
+/// The methods are called from the threads of libcec, and should return quickly. Every
+/// method has a default implementation ignoring the callback.
+pub trait CecHandler: Send + Sync {
+    fn log_message(&self, message: LogMessage<'_>) {
+        let _ = message;
+    }
+
+    fn key_press(&self, key: KeyPress) {
+        let _ = key;
+    }
+
+    /// Command received from the CEC bus, after libcec has handled it
+    fn command_received(&self, command: &cec_command) {
+        let _ = command;
+    }
+
+    fn configuration_changed(&self, configuration: &libcec_configuration) {
+        let _ = configuration;
+    }
+
+    /// Alert of libcec, with the string parameter of the alert if any
+    fn alert(&self, alert: Alert, param: Option<&str>) {
+        let _ = (alert, param);
+    }
+
+    /// Menu state change requested by the TV, returning whether libcec should apply it
+    fn menu_state_changed(&self, state: MenuState) -> bool {
+        let _ = state;
+        true
+    }
+
+    /// Source handled by this client was activated or deactivated
+    fn source_activated(&self, address: LogicalAddress, activated: bool) {
+        let _ = (address, activated);
+    }
+
+    /// Command received from the CEC bus, before libcec handles it. Returns whether the command
+    /// was handled, in which case libcec takes no action.
+    #[cfg(abi7)]
+    fn command_handler(&self, command: &cec_command) -> bool {
+        let _ = command;
+        false
+    }
+}
+
+/// Shared handler, e.g. one which is also used outside the callbacks
+impl<H: CecHandler + ?Sized> CecHandler for Arc<H> {
+    fn log_message(&self, message: LogMessage<'_>) {
+        (**self).log_message(message)
+    }
+
+    fn key_press(&self, key: KeyPress) {
+        (**self).key_press(key)
+    }
+
+    fn command_received(&self, command: &cec_command) {
+        (**self).command_received(command)
+    }
+
+    fn configuration_changed(&self, configuration: &libcec_configuration) {
+        (**self).configuration_changed(configuration)
+    }
+
+    fn alert(&self, alert: Alert, param: Option<&str>) {
+        (**self).alert(alert, param)
+    }
+
+    fn menu_state_changed(&self, state: MenuState) -> bool {
+        (**self).menu_state_changed(state)
+    }
+
+    fn source_activated(&self, address: LogicalAddress, activated: bool) {
+        (**self).source_activated(address, activated)
+    }
+
+    #[cfg(abi7)]
+    fn command_handler(&self, command: &cec_command) -> bool {
+        (**self).command_handler(command)
+    }
+}
+
+/// String of a `libcec_parameter`, `None` unless it is of type `STRING`
+///
+/// # Safety
+///
+/// `paramData` of a `STRING` parameter must be NULL or point to a NUL-terminated string,
+/// which is valid for `'a`.
+pub(crate) unsafe fn parameter_str<'a>(param: &libcec_parameter) -> Option<Cow<'a, str>> {
+    if ParameterType::from_raw(param.paramType) != ParameterType::String
+        || param.paramData.is_null()
+    {
+        return None;
+    }
+    Some(CStr::from_ptr(param.paramData.cast()).to_string_lossy())
+}
+
+/// Call `f` with the handler behind `cbparam`, returning `default` when it is NULL or `f`
+/// panics
+///
+/// # Safety
+///
+/// `cbparam` must be NULL or point to a live `H`.
+unsafe fn with_handler<H: CecHandler, R>(
+    cbparam: *mut c_void,
+    default: R,
+    f: impl FnOnce(&H) -> R,
+) -> R {
+    let Some(handler) = cbparam.cast::<H>().cast_const().as_ref() else {
+        return default;
+    };
+    panic::catch_unwind(AssertUnwindSafe(|| f(handler))).unwrap_or(default)
+}
+
+unsafe extern "C" fn log_message<H: CecHandler>(
+    cbparam: *mut c_void,
+    message: *const cec_log_message,
+) {
+    let Some(message) = message.as_ref() else {
+        return;
+    };
+    with_handler(cbparam, (), |handler: &H| {
+        let text = if message.message.is_null() {
+            Cow::Borrowed("")
+        } else {
+            CStr::from_ptr(message.message).to_string_lossy()
+        };
+        handler.log_message(LogMessage {
+            message: &text,
+            level: LogLevel::from_raw(message.level),
+            time: message.time,
+        })
+    })
+}
+
+unsafe extern "C" fn key_press<H: CecHandler>(cbparam: *mut c_void, key: *const cec_keypress) {
+    let Some(key) = key.as_ref() else {
+        return;
+    };
+    with_handler(cbparam, (), |handler: &H| handler.key_press(key.into()))
+}
+
+unsafe extern "C" fn command_received<H: CecHandler>(
+    cbparam: *mut c_void,
+    command: *const cec_command,
+) {
+    let Some(command) = command.as_ref() else {
+        return;
+    };
+    with_handler(cbparam, (), |handler: &H| handler.command_received(command))
+}
+
+unsafe extern "C" fn configuration_changed<H: CecHandler>(
+    cbparam: *mut c_void,
+    configuration: *const libcec_configuration,
+) {
+    let Some(configuration) = configuration.as_ref() else {
+        return;
+    };
+    with_handler(cbparam, (), |handler: &H| {
+        handler.configuration_changed(configuration)
+    })
+}
+
+unsafe extern "C" fn alert<H: CecHandler>(
+    cbparam: *mut c_void,
+    alert: libcec_alert,
+    param: libcec_parameter,
+) {
+    with_handler(cbparam, (), |handler: &H| {
+        let text = parameter_str(&param);
+        handler.alert(Alert::from_raw(alert), text.as_deref())
+    })
+}
+
+unsafe extern "C" fn menu_state_changed<H: CecHandler>(
+    cbparam: *mut c_void,
+    state: cec_menu_state,
+) -> c_int {
+    with_handler(cbparam, true, |handler: &H| {
+        handler.menu_state_changed(MenuState::from_raw(state))
+    })
+    .into()
+}
+
+unsafe extern "C" fn source_activated<H: CecHandler>(
+    cbparam: *mut c_void,
+    address: cec_logical_address,
+    activated: u8,
+) {
+    with_handler(cbparam, (), |handler: &H| {
+        handler.source_activated(LogicalAddress::from_raw(address), activated != 0)
+    })
+}
+
+#[cfg(abi7)]
+unsafe extern "C" fn command_handler<H: CecHandler>(
+    cbparam: *mut c_void,
+    command: *const cec_command,
+) -> c_int {
+    let Some(command) = command.as_ref() else {
+        return 0;
+    };
+    with_handler(cbparam, false, |handler: &H| {
+        handler.command_handler(command)
+    })
+    .into()
+}
+
+/// Callbacks calling `H`, which expect `cbparam` to point to the handler
+pub fn callbacks<H: CecHandler>() -> ICECCallbacks {
+    ICECCallbacks {
+        logMessage: Some(log_message::<H>),
+        keyPress: Some(key_press::<H>),
+        commandReceived: Some(command_received::<H>),
+        configurationChanged: Some(configuration_changed::<H>),
+        alert: Some(alert::<H>),
+        menuStateChanged: Some(menu_state_changed::<H>),
+        sourceActivated: Some(source_activated::<H>),
+        #[cfg(abi7)]
+        commandHandler: Some(command_handler::<H>),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+    use std::ptr;
+    use std::sync::Mutex;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Recorder {
+        calls: Mutex<Vec<String>>,
+    }
+
+    impl Recorder {
+        fn record(&self, call: String) {
+            self.calls.lock().unwrap().push(call);
+        }
+    }
+
+    impl CecHandler for Recorder {
+        fn log_message(&self, message: LogMessage<'_>) {
+            self.record(format!(
+                "{} {} {}",
+                message.level, message.time, message.message
+            ));
+        }
+
+        fn key_press(&self, key: KeyPress) {
+            self.record(format!("{} {:?}", key.code, key.duration));
+        }
+
+        fn alert(&self, alert: Alert, param: Option<&str>) {
+            self.record(format!("{alert} {param:?}"));
+        }
+
+        fn menu_state_changed(&self, state: MenuState) -> bool {
+            self.record(state.to_string());
+            false
+        }
+
+        fn source_activated(&self, address: LogicalAddress, activated: bool) {
+            self.record(format!("{address} {activated}"));
+        }
+    }
+
+    struct Panicking;
+
+    impl CecHandler for Panicking {
+        fn key_press(&self, _key: KeyPress) {
+            panic!("key press");
+        }
+
+        fn menu_state_changed(&self, _state: MenuState) -> bool {
+            panic!("menu state");
+        }
+    }
+
+    #[test]
+    fn test_callbacks() {
+        let recorder = Recorder::default();
+        let cbparam = &recorder as *const Recorder as *mut c_void;
+        let callbacks = callbacks::<Recorder>();
+
+        let text = CString::new("hello").unwrap();
+        let message = cec_log_message {
+            message: text.as_ptr(),
+            level: LogLevel::Warning.raw(),
+            time: 42,
+        };
+        let key = cec_keypress {
+            keycode: UserControlCode::Select.raw(),
+            duration: 500,
+        };
+        let alert_text = CString::new("port busy").unwrap();
+        let param = libcec_parameter {
+            paramType: ParameterType::String.raw(),
+            paramData: alert_text.as_ptr() as *mut c_void,
+        };
+        let no_param = libcec_parameter {
+            paramType: ParameterType::Unkown.raw(),
+            paramData: ptr::null_mut(),
+        };
+        unsafe {
+            callbacks.logMessage.unwrap()(cbparam, &message);
+            callbacks.keyPress.unwrap()(cbparam, &key);
+            callbacks.keyPress.unwrap()(cbparam, ptr::null());
+            callbacks.alert.unwrap()(cbparam, Alert::PortBusy.raw(), param);
+            callbacks.alert.unwrap()(cbparam, Alert::ConnectionLost.raw(), no_param);
+            assert_eq!(
+                callbacks.menuStateChanged.unwrap()(cbparam, MenuState::Activated.raw()),
+                0
+            );
+            callbacks.sourceActivated.unwrap()(cbparam, LogicalAddress::Tv.raw(), 1);
+            // default implementations
+            callbacks.commandReceived.unwrap()(cbparam, &cec_command::default());
+        }
+        assert_eq!(
+            *recorder.calls.lock().unwrap(),
+            [
+                "cec log warning 42 hello",
+                "select 500ms",
+                "port busy Some(\"port busy\")",
+                "connection lost None",
+                "activated",
+                "TV true",
+            ]
+        );
+    }
+
+    #[cfg(abi7)]
+    #[test]
+    fn test_command_handler() {
+        struct Handled;
+
+        impl CecHandler for Handled {
+            fn command_handler(&self, _command: &cec_command) -> bool {
+                true
+            }
+        }
+
+        let command = cec_command::default();
+        let handled = callbacks::<Handled>().commandHandler.unwrap();
+        let default = callbacks::<Recorder>().commandHandler.unwrap();
+        let recorder = Recorder::default();
+        unsafe {
+            assert_eq!(
+                handled(&Handled as *const Handled as *mut c_void, &command),
+                1
+            );
+            assert_eq!(
+                default(&recorder as *const Recorder as *mut c_void, &command),
+                0
+            );
+        }
+    }
+
+    #[test]
+    fn test_null_handler() {
+        let callbacks = callbacks::<Recorder>();
+        let state = MenuState::Activated.raw();
+        assert_eq!(
+            unsafe { callbacks.menuStateChanged.unwrap()(ptr::null_mut(), state) },
+            1
+        );
+    }
+
+    #[test]
+    fn test_panics_are_caught() {
+        let callbacks = callbacks::<Panicking>();
+        let cbparam = &Panicking as *const Panicking as *mut c_void;
+        let key = cec_keypress::default();
+        let state = MenuState::Activated.raw();
+        unsafe {
+            callbacks.keyPress.unwrap()(cbparam, &key);
+            assert_eq!(callbacks.menuStateChanged.unwrap()(cbparam, state), 1);
+        }
+    }
+}
@@ -0,0 +1,499 @@
+//! Adapter hot-plug events
+//!
+//! A [`Watcher`] follows the adapters listed by [`Enumerator`] as they are plugged in and out,
+//! reporting each change as a [`HotplugEvent`], e.g. to open a connection again once the
+//! adapter is back. On Linux, it is woken by inotify on `/dev`, `/sys/class/cec` and
+//! `/sys/bus/usb/devices`, and it lists the adapters again on every wake up. Elsewhere, when
+//! inotify is not available, or with [`Watcher::with_polling`], it lists them every
+//! [`Watcher::with_poll_interval`] instead. sysfs does not report changes through inotify, so
+//! the adapters are listed at that interval with inotify as well.
+//!
+//! [`Watch::wait`] returns the changes one batch at a time, and [`Watch::spawn`] reports them
+//! to a callback from a thread.
+//!
+//! ```no_run
+//! use libcec_sys::hotplug::{HotplugEvent, Watcher};
+//!
+//! let watch = Watcher::new().start()?;
+//! for adapter in watch.adapters() {
+//!     println!("present: {}", adapter.path);
+//! }
+//! let _thread = watch.spawn(|event| match event {
+//!     HotplugEvent::AdapterAdded(adapter) => println!("added: {}", adapter.path),
+//!     HotplugEvent::AdapterRemoved(adapter) => println!("removed: {}", adapter.path),
+//! })?;
+//! # Ok::<(), std::io::Error>(())
+//! ```
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use crate::adapter::AdapterDescriptor;
+use crate::sysfs::Enumerator;
+
+/// Interval between listings of the adapters
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// sysfs directories watched with inotify besides `dev`, relative to the root
+#[cfg(target_os = "linux")]
+const WATCHED_SYS: [&str; 2] = ["sys/class/cec", "sys/bus/usb/devices"];
+
+/// Change of the adapters
+///
+/// An adapter whose description changes, e.g. when its USB ids become readable, is removed and
+/// added again.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HotplugEvent {
+    AdapterAdded(AdapterDescriptor),
+    AdapterRemoved(AdapterDescriptor),
+}
+
+impl HotplugEvent {
+    /// The adapter which was added or removed
+    pub fn adapter(&self) -> &AdapterDescriptor {
+        match self {
+            HotplugEvent::AdapterAdded(adapter) | HotplugEvent::AdapterRemoved(adapter) => adapter,
+        }
+    }
+}
+
+/// Changes from `old` to `new`, removals first
+fn changes(old: &[AdapterDescriptor], new: &[AdapterDescriptor]) -> Vec<HotplugEvent> {
+    let removed = old
+        .iter()
+        .filter(|adapter| !new.contains(adapter))
+        .cloned()
+        .map(HotplugEvent::AdapterRemoved);
+    let added = new
+        .iter()
+        .filter(|adapter| !old.contains(adapter))
+        .cloned()
+        .map(HotplugEvent::AdapterAdded);
+    removed.chain(added).collect()
+}
+
+/// Configuration of a [`Watch`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Watcher {
+    enumerator: Enumerator,
+    poll_interval: Duration,
+    polling: bool,
+}
+
+impl Watcher {
+    /// Watcher of the adapters of this system, using inotify when available
+    pub fn new() -> Self {
+        Watcher {
+            enumerator: Enumerator::new(),
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            polling: false,
+        }
+    }
+
+    /// Watch `root/dev` and `root/sys` instead of `/dev` and `/sys`
+    pub fn with_root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.enumerator = Enumerator::with_root(root);
+        self
+    }
+
+    /// List the adapters every `poll_interval`, [`DEFAULT_POLL_INTERVAL`] by default
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Only poll, without inotify
+    pub fn with_polling(mut self, polling: bool) -> Self {
+        self.polling = polling;
+        self
+    }
+
+    /// List the present adapters and start watching for changes
+    ///
+    /// Falls back to polling when inotify cannot be set up, e.g. when `dev` does not exist
+    /// under the root.
+    pub fn start(self) -> io::Result<Watch> {
+        let wakeup = if self.polling {
+            Wakeup::Poll
+        } else {
+            Wakeup::new(self.enumerator.root())
+        };
+        let adapters = self.enumerator.adapters()?;
+        Ok(Watch {
+            watcher: self,
+            wakeup,
+            adapters,
+        })
+    }
+}
+
+impl Default for Watcher {
+    fn default() -> Self {
+        Watcher::new()
+    }
+}
+
+enum Wakeup {
+    #[cfg(target_os = "linux")]
+    Inotify(inotify::Inotify),
+    Poll,
+}
+
+impl Wakeup {
+    #[cfg(target_os = "linux")]
+    fn new(root: &Path) -> Self {
+        let watch = || -> io::Result<inotify::Inotify> {
+            let inotify = inotify::Inotify::new()?;
+            inotify.add(&root.join("dev"))?;
+            for dir in WATCHED_SYS {
+                // missing without the kernel CEC framework or USB
+                let _ = inotify.add(&root.join(dir));
+            }
+            Ok(inotify)
+        };
+        watch().map_or(Wakeup::Poll, Wakeup::Inotify)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn new(_root: &Path) -> Self {
+        Wakeup::Poll
+    }
+
+    /// Wait for a change in the watched directories, or `timeout`
+    ///
+    /// Polls from then on when inotify fails, so that the error is not returned again at once.
+    fn wait(&mut self, timeout: Duration) -> io::Result<()> {
+        match self {
+            #[cfg(target_os = "linux")]
+            Wakeup::Inotify(inotify) => {
+                let result = inotify.wait(timeout);
+                if result.is_err() {
+                    *self = Wakeup::Poll;
+                }
+                result
+            }
+            Wakeup::Poll => {
+                thread::sleep(timeout);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Adapters being watched
+pub struct Watch {
+    watcher: Watcher,
+    wakeup: Wakeup,
+    adapters: Vec<AdapterDescriptor>,
+}
+
+impl Watch {
+    /// The adapters present, as of the last change
+    pub fn adapters(&self) -> &[AdapterDescriptor] {
+        &self.adapters
+    }
+
+    /// Whether changes are only found by polling
+    pub fn is_polling(&self) -> bool {
+        matches!(self.wakeup, Wakeup::Poll)
+    }
+
+    /// List the adapters again, returning the changes since the last listing
+    pub fn refresh(&mut self) -> io::Result<Vec<HotplugEvent>> {
+        let adapters = self.watcher.enumerator.adapters()?;
+        let changes = changes(&self.adapters, &adapters);
+        self.adapters = adapters;
+        Ok(changes)
+    }
+
+    /// Wait up to the poll interval for changes
+    ///
+    /// Returns as soon as inotify reports a change, otherwise after the poll interval. The
+    /// returned changes may be empty. When inotify fails, its error is returned and the watch
+    /// polls from then on.
+    pub fn wait(&mut self) -> io::Result<Vec<HotplugEvent>> {
+        self.wakeup.wait(self.watcher.poll_interval)?;
+        self.refresh()
+    }
+
+    /// Report the changes to `on_event` from a new thread
+    ///
+    /// Errors listing the adapters are retried at the next poll interval. The thread stops when
+    /// the returned [`WatchThread`] is dropped, within the poll interval. Fails when the thread
+    /// cannot be spawned.
+    pub fn spawn<F>(mut self, mut on_event: F) -> io::Result<WatchThread>
+    where
+        F: FnMut(HotplugEvent) + Send + 'static,
+    {
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = Arc::clone(&stop);
+        let thread = thread::Builder::new()
+            .name("libcec-hotplug".to_owned())
+            .spawn(move || {
+                while !thread_stop.load(Ordering::Relaxed) {
+                    // errors are e.g. a directory removed while it was read
+                    if let Ok(changes) = self.wait() {
+                        changes.into_iter().for_each(&mut on_event);
+                    }
+                }
+            })?;
+        Ok(WatchThread {
+            stop,
+            thread: Some(thread),
+        })
+    }
+}
+
+impl std::fmt::Debug for Watch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Watch")
+            .field("watcher", &self.watcher)
+            .field("polling", &self.is_polling())
+            .field("adapters", &self.adapters)
+            .finish()
+    }
+}
+
+/// Thread started by [`Watch::spawn`], stopped on drop
+#[derive(Debug)]
+pub struct WatchThread {
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Drop for WatchThread {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// inotify through the C library, which is linked anyway
+#[cfg(target_os = "linux")]
+mod inotify {
+    use std::ffi::CString;
+    use std::fs::File;
+    use std::io::{self, Read};
+    use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+    use std::os::raw::{c_char, c_int, c_short, c_ulong};
+    use std::os::unix::ffi::OsStrExt;
+    use std::path::Path;
+    use std::time::Duration;
+
+    // the same on all Linux architectures supported by the bindings
+    const IN_NONBLOCK: c_int = 0o4000;
+    const IN_CLOEXEC: c_int = 0o2000000;
+    const IN_ATTRIB: u32 = 0x004;
+    const IN_MOVED_FROM: u32 = 0x040;
+    const IN_MOVED_TO: u32 = 0x080;
+    const IN_CREATE: u32 = 0x100;
+    const IN_DELETE: u32 = 0x200;
+    const POLLIN: c_short = 0x001;
+
+    #[repr(C)]
+    struct PollFd {
+        fd: c_int,
+        events: c_short,
+        revents: c_short,
+    }
+
+    extern "C" {
+        fn inotify_init1(flags: c_int) -> c_int;
+        fn inotify_add_watch(fd: c_int, pathname: *const c_char, mask: u32) -> c_int;
+        fn poll(fds: *mut PollFd, nfds: c_ulong, timeout: c_int) -> c_int;
+    }
+
+    pub(super) struct Inotify {
+        file: File,
+    }
+
+    /// Any file, e.g. one for which `wait` fails
+    #[cfg(test)]
+    impl From<File> for Inotify {
+        fn from(file: File) -> Self {
+            Inotify { file }
+        }
+    }
+
+    impl Inotify {
+        pub(super) fn new() -> io::Result<Self> {
+            // SAFETY: no pointers are passed
+            let fd = unsafe { inotify_init1(IN_NONBLOCK | IN_CLOEXEC) };
+            if fd < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            // SAFETY: the descriptor was just opened and is owned by nothing else
+            let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+            Ok(Inotify {
+                file: File::from(fd),
+            })
+        }
+
+        /// Watch entries created, removed or changed in `dir`
+        pub(super) fn add(&self, dir: &Path) -> io::Result<()> {
+            let path = CString::new(dir.as_os_str().as_bytes())
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+            let mask = IN_CREATE | IN_DELETE | IN_MOVED_FROM | IN_MOVED_TO | IN_ATTRIB;
+            // SAFETY: path is a NUL-terminated string, which outlives the call
+            let watch = unsafe { inotify_add_watch(self.file.as_raw_fd(), path.as_ptr(), mask) };
+            if watch < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(())
+        }
+
+        /// Wait for events, or `timeout`, and discard them
+        pub(super) fn wait(&mut self, timeout: Duration) -> io::Result<()> {
+            let mut fd = PollFd {
+                fd: self.file.as_raw_fd(),
+                events: POLLIN,
+                revents: 0,
+            };
+            let timeout = c_int::try_from(timeout.as_millis()).unwrap_or(c_int::MAX);
+            // SAFETY: fd points to one pollfd, which outlives the call
+            if unsafe { poll(&mut fd, 1, timeout) } < 0 {
+                let err = io::Error::last_os_error();
+                // interrupted by a signal, the caller lists the adapters anyway
+                return match err.kind() {
+                    io::ErrorKind::Interrupted => Ok(()),
+                    _ => Err(err),
+                };
+            }
+            // the events only tell that something changed
+            let mut buffer = [0; 4096];
+            loop {
+                match self.file.read(&mut buffer) {
+                    Ok(0) => return Ok(()),
+                    Ok(_) => continue,
+                    Err(err) if err.kind() == io::ErrorKind::WouldBlock => return Ok(()),
+                    Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                    Err(err) => return Err(err),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::sync::mpsc;
+
+    fn root(name: &str) -> PathBuf {
+        let root =
+            std::env::temp_dir().join(format!("libcec-sys-hotplug-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("dev")).unwrap();
+        root
+    }
+
+    fn paths(events: &[HotplugEvent]) -> Vec<(bool, String)> {
+        events
+            .iter()
+            .map(|event| {
+                let added = matches!(event, HotplugEvent::AdapterAdded(_));
+                let name = Path::new(&event.adapter().path).file_name().unwrap();
+                (added, name.to_string_lossy().into_owned())
+            })
+            .collect()
+    }
+
+    fn plug_in_and_out(watcher: Watcher, name: &str) {
+        let root = root(name);
+        fs::write(root.join("dev/cec0"), "").unwrap();
+        let mut watch = watcher
+            .with_root(&root)
+            .with_poll_interval(Duration::from_millis(10))
+            .start()
+            .unwrap();
+        assert_eq!(watch.adapters().len(), 1);
+        assert_eq!(watch.wait().unwrap(), []);
+
+        fs::write(root.join("dev/cec1"), "").unwrap();
+        fs::remove_file(root.join("dev/cec0")).unwrap();
+        assert_eq!(
+            paths(&watch.wait().unwrap()),
+            [(false, "cec0".to_owned()), (true, "cec1".to_owned())]
+        );
+        assert_eq!(watch.adapters().len(), 1);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_polling() {
+        plug_in_and_out(Watcher::new().with_polling(true), "polling");
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_inotify() {
+        let root = root("inotify");
+        let mut watch = Watcher::new()
+            .with_root(&root)
+            .with_poll_interval(Duration::from_secs(60))
+            .start()
+            .unwrap();
+        assert!(!watch.is_polling());
+        fs::write(root.join("dev/cec0"), "").unwrap();
+        // woken by inotify, long before the poll interval
+        let start = std::time::Instant::now();
+        assert_eq!(paths(&watch.wait().unwrap()), [(true, "cec0".to_owned())]);
+        assert!(start.elapsed() < Duration::from_secs(30));
+        fs::remove_dir_all(&root).unwrap();
+
+        plug_in_and_out(Watcher::new(), "inotify-changes");
+    }
+
+    #[test]
+    fn test_fallback() {
+        let root = std::env::temp_dir().join("libcec-sys-hotplug-missing");
+        let watch = Watcher::new().with_root(root).start().unwrap();
+        assert!(watch.is_polling());
+        assert_eq!(watch.adapters(), []);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_inotify_error() {
+        let root = root("inotify-error");
+        let mut watch = Watcher::new()
+            .with_root(&root)
+            .with_poll_interval(Duration::from_millis(50))
+            .start()
+            .unwrap();
+        // reading a directory fails
+        let dir = fs::File::open(&root).unwrap();
+        watch.wakeup = Wakeup::Inotify(inotify::Inotify::from(dir));
+        assert!(watch.wait().is_err());
+        assert!(watch.is_polling());
+        let start = std::time::Instant::now();
+        assert_eq!(watch.wait().unwrap(), []);
+        assert!(start.elapsed() >= Duration::from_millis(50));
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_spawn() {
+        let root = root("spawn");
+        let (sender, receiver) = mpsc::channel();
+        let thread = Watcher::new()
+            .with_root(&root)
+            .with_poll_interval(Duration::from_millis(10))
+            .start()
+            .unwrap()
+            .spawn(move |event| sender.send(event).unwrap())
+            .unwrap();
+        fs::write(root.join("dev/cec3"), "").unwrap();
+        let event = receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+        assert_eq!(paths(&[event]), [(true, "cec3".to_owned())]);
+        drop(thread);
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -4,55 +4,72 @@ mod bindings {
     // Note how armv7 is coalesced to arm, and gnueabihf and gnueabi is coalesced to gnu
     //
     #![allow(non_upper_case_globals, non_camel_case_types, non_snake_case)]
+
+    // With `newtype-enums`, C enums are distinct types (e.g. `cec_opcode::ACTIVE_SOURCE`)
+    // instead of integer aliases with free constants (e.g. `cec_opcode_ACTIVE_SOURCE`).
+    // Both bindings are generated from the same headers and are ABI-compatible.
+    #[cfg(not(feature = "newtype-enums"))]
+    macro_rules! include_bindings {
+        ($file:literal) => {
+            include!($file);
+        };
+    }
+    #[cfg(feature = "newtype-enums")]
+    macro_rules! include_bindings {
+        ($file:literal) => {
+            include!(concat!("newtype/", $file));
+        };
+    }
+
     cfg_if::cfg_if! {
         if #[cfg(all(abi4, target_os = "windows", target_arch = "x86_64", target_env = "msvc"))] {
-            include!("lib_abi4_x86_64-pc-windows-msvc.rs");
+            include_bindings!("lib_abi4_x86_64-pc-windows-msvc.rs");
         } else if #[cfg(all(abi5, target_os = "windows", target_arch = "x86_64", target_env = "msvc"))] {
-            include!("lib_abi5_x86_64-pc-windows-msvc.rs");
+            include_bindings!("lib_abi5_x86_64-pc-windows-msvc.rs");
         } else if #[cfg(all(abi6, target_os = "windows", target_arch = "x86_64", target_env = "msvc"))] {
-            include!("lib_abi6_x86_64-pc-windows-msvc.rs");
+            include_bindings!("lib_abi6_x86_64-pc-windows-msvc.rs");
         } else if #[cfg(all(abi7, target_os = "windows", target_arch = "x86_64", target_env = "msvc"))] {
-            include!("lib_abi7_x86_64-pc-windows-msvc.rs");
+            include_bindings!("lib_abi7_x86_64-pc-windows-msvc.rs");
         }else if #[cfg(all(abi4, target_os = "linux", target_arch = "x86_64", target_env = "gnu"))] {
-            include!("lib_abi4_x86_64-unknown-linux-gnu.rs");
+            include_bindings!("lib_abi4_x86_64-unknown-linux-gnu.rs");
         } else if #[cfg(all(abi5, target_os = "linux", target_arch = "x86_64", target_env = "gnu"))] {
-            include!("lib_abi5_x86_64-unknown-linux-gnu.rs");
+            include_bindings!("lib_abi5_x86_64-unknown-linux-gnu.rs");
         } else if #[cfg(all(abi6, target_os = "linux", target_arch = "x86_64", target_env = "gnu"))] {
-            include!("lib_abi6_x86_64-unknown-linux-gnu.rs");
+            include_bindings!("lib_abi6_x86_64-unknown-linux-gnu.rs");
         } else if #[cfg(all(abi7, target_os = "linux", target_arch = "x86_64", target_env = "gnu"))] {
-            include!("lib_abi7_x86_64-unknown-linux-gnu.rs");
+            include_bindings!("lib_abi7_x86_64-unknown-linux-gnu.rs");
         }else if #[cfg(all(abi4, target_os = "linux", target_arch = "arm", target_env = "gnu"))] {
-            include!("lib_abi4_armv7-unknown-linux-gnueabihf.rs");
+            include_bindings!("lib_abi4_armv7-unknown-linux-gnueabihf.rs");
         } else if #[cfg(all(abi5, target_os = "linux", target_arch = "arm", target_env = "gnu"))] {
-            include!("lib_abi5_armv7-unknown-linux-gnueabihf.rs");
+            include_bindings!("lib_abi5_armv7-unknown-linux-gnueabihf.rs");
         } else if #[cfg(all(abi6, target_os = "linux", target_arch = "arm", target_env = "gnu"))] {
-            include!("lib_abi6_armv7-unknown-linux-gnueabihf.rs");
+            include_bindings!("lib_abi6_armv7-unknown-linux-gnueabihf.rs");
         } else if #[cfg(all(abi7, target_os = "linux", target_arch = "arm", target_env = "gnu"))] {
-            include!("lib_abi7_armv7-unknown-linux-gnueabihf.rs");
+            include_bindings!("lib_abi7_armv7-unknown-linux-gnueabihf.rs");
         } else if #[cfg(all(abi4, target_os = "linux", target_arch = "arm", target_env = "gnu"))] {
-            include!("lib_abi4_arm-unknown-linux-gnueabi.rs");
+            include_bindings!("lib_abi4_arm-unknown-linux-gnueabi.rs");
         } else if #[cfg(all(abi5, target_os = "linux", target_arch = "arm", target_env = "gnu"))] {
-            include!("lib_abi5_arm-unknown-linux-gnueabi.rs");
+            include_bindings!("lib_abi5_arm-unknown-linux-gnueabi.rs");
         } else if #[cfg(all(abi6, target_os = "linux", target_arch = "arm", target_env = "gnu"))] {
-            include!("lib_abi6_arm-unknown-linux-gnueabi.rs");
+            include_bindings!("lib_abi6_arm-unknown-linux-gnueabi.rs");
         } else if #[cfg(all(abi7, target_os = "linux", target_arch = "arm", target_env = "gnu"))] {
-            include!("lib_abi7_arm-unknown-linux-gnueabi.rs");
+            include_bindings!("lib_abi7_arm-unknown-linux-gnueabi.rs");
         } else if #[cfg(all(abi4, target_os = "linux", target_arch = "aarch64", target_env = "gnu"))] {
-            include!("lib_abi4_aarch64-unknown-linux-gnu.rs");
+            include_bindings!("lib_abi4_aarch64-unknown-linux-gnu.rs");
         } else if #[cfg(all(abi5, target_os = "linux", target_arch = "aarch64", target_env = "gnu"))] {
-            include!("lib_abi5_aarch64-unknown-linux-gnu.rs");
+            include_bindings!("lib_abi5_aarch64-unknown-linux-gnu.rs");
         } else if #[cfg(all(abi6, target_os = "linux", target_arch = "aarch64", target_env = "gnu"))] {
-            include!("lib_abi6_aarch64-unknown-linux-gnu.rs");
+            include_bindings!("lib_abi6_aarch64-unknown-linux-gnu.rs");
         } else if #[cfg(all(abi7, target_os = "linux", target_arch = "aarch64", target_env = "gnu"))] {
-            include!("lib_abi7_aarch64-unknown-linux-gnu.rs");
+            include_bindings!("lib_abi7_aarch64-unknown-linux-gnu.rs");
         } else if #[cfg(all(abi4, target_os = "macos", target_arch = "aarch64"))] {
-            include!("lib_abi4_aarch64-apple-darwin.rs");
+            include_bindings!("lib_abi4_aarch64-apple-darwin.rs");
         } else if #[cfg(all(abi5, target_os = "macos", target_arch = "aarch64"))] {
-            include!("lib_abi5_aarch64-apple-darwin.rs");
+            include_bindings!("lib_abi5_aarch64-apple-darwin.rs");
         } else if #[cfg(all(abi6, target_os = "macos", target_arch = "aarch64"))] {
-            include!("lib_abi6_aarch64-apple-darwin.rs");
+            include_bindings!("lib_abi6_aarch64-apple-darwin.rs");
         } else if #[cfg(all(abi7, target_os = "macos", target_arch = "aarch64"))] {
-            include!("lib_abi7_aarch64-apple-darwin.rs");
+            include_bindings!("lib_abi7_aarch64-apple-darwin.rs");
         }
         else {
             compile_error!("unsupported platform");
@@ -62,6 +79,35 @@ mod bindings {
 
 pub use crate::bindings::*;
 
+#[macro_use]
+pub mod enums;
+
+pub mod adapter;
+pub mod address;
+#[cfg(feature = "async")]
+pub mod asynchronous;
+pub mod config;
+pub mod error;
+pub mod events;
+pub mod feature_abort;
+pub mod format;
+pub mod frame;
+pub mod handler;
+pub mod hotplug;
+pub mod message;
+pub mod opcode;
+pub mod query;
+#[cfg(feature = "safe")]
+pub mod safe;
+#[cfg(feature = "serde")]
+mod serde_impls;
+pub mod strings;
+#[cfg(feature = "safe")]
+pub mod supervisor;
+pub mod sysfs;
+pub mod traffic;
+pub mod validation;
+
 #[cfg(test)]
 mod tests {
 
@@ -80,7 +126,7 @@ mod tests {
         assert_eq!(
             CEC_LIB_VERSION_MAJOR,
             expected_abi
-                .parse()
+                .parse::<u32>()
                 .expect("Invalid EXPECTED_LIBCEC_VERSION_MAJOR: could not parse to number")
         );
     }
@@ -108,4 +154,87 @@ mod tests {
     fn test_abi7() {
         assert_eq!(CEC_LIB_VERSION_MAJOR, 7);
     }
+
+    #[cfg(feature = "newtype-enums")]
+    #[test]
+    fn test_newtype_enums() {
+        use crate::{cec_logical_address, cec_opcode};
+        use std::mem::size_of;
+        use std::os::raw::{c_int, c_uint};
+
+        assert_eq!(cec_opcode::ACTIVE_SOURCE, cec_opcode(0x82));
+        assert_eq!(cec_logical_address::UNKNOWN.0, -1);
+        assert_eq!(size_of::<cec_opcode>(), size_of::<c_uint>());
+        assert_eq!(size_of::<cec_logical_address>(), size_of::<c_int>());
+    }
+
+    #[test]
+    fn test_enum_names() {
+        use crate::enums::{LogicalAddress, Opcode, UserControlCode, VendorId};
+
+        assert_eq!(Opcode::ActiveSource.name(), "active source");
+        assert_eq!(Opcode::None.to_string(), "poll");
+        assert_eq!(LogicalAddress::Tv.to_string(), "TV");
+        assert_eq!(VendorId::HarmanKardon.to_string(), "Harman/Kardon");
+        assert_eq!(UserControlCode::F1Blue.to_string(), "F1 (blue)");
+
+        assert_eq!("ACTIVE SOURCE".parse(), Ok(Opcode::ActiveSource));
+        assert_eq!("playback 1".parse(), Ok(LogicalAddress::PlaybackDevice1));
+        assert!("no such opcode".parse::<Opcode>().is_err());
+    }
+
+    #[cfg(not(feature = "newtype-enums"))]
+    #[test]
+    fn test_enum_raw() {
+        use crate::enums::{LogicalAddress, Opcode};
+        use crate::{cec_logical_address_UNKNOWN, cec_opcode_ACTIVE_SOURCE};
+
+        assert_eq!(
+            Opcode::from_raw(cec_opcode_ACTIVE_SOURCE),
+            Opcode::ActiveSource
+        );
+        assert_eq!(Opcode::ActiveSource.raw(), cec_opcode_ACTIVE_SOURCE);
+        assert_eq!(Opcode::try_from(0x82), Ok(Opcode::ActiveSource));
+        assert_eq!(u32::from(Opcode::ActiveSource), 0x82);
+        assert_eq!(LogicalAddress::UNKNOWN.raw(), cec_logical_address_UNKNOWN);
+        assert_eq!(LogicalAddress::UNREGISTERED, LogicalAddress::Broadcast);
+    }
+
+    #[test]
+    fn test_enum_unknown() {
+        use crate::enums::{LogicalAddress, Opcode, PowerStatus, UserControlCode};
+        #[cfg(feature = "newtype-enums")]
+        use crate::{cec_logical_address, cec_opcode};
+
+        // distinct known values, without aliases such as `UserControlCode::MAX`
+        assert_eq!(Opcode::ALL.len(), 72);
+        assert_eq!(UserControlCode::ALL.len(), 87);
+        for opcode in Opcode::ALL {
+            assert_eq!(Opcode::try_from(opcode.raw()), Ok(*opcode));
+        }
+
+        let unknown = Opcode::Unknown(c_enum!(cec_opcode, 0x01));
+        assert_eq!(Opcode::from_raw(unknown.raw()), unknown);
+        let err = Opcode::try_from(unknown.raw()).unwrap_err();
+        assert_eq!(err.value(), 0x01);
+        assert_eq!(unknown.to_string(), "UNKNOWN");
+
+        // equal by C value
+        let active_source = Opcode::Unknown(Opcode::ActiveSource.raw());
+        assert_eq!(active_source, Opcode::ActiveSource);
+        assert_ne!(active_source, unknown);
+        let set: std::collections::HashSet<_> = [Opcode::ActiveSource, active_source].into();
+        assert_eq!(set.len(), 1);
+        assert!(matches!(
+            Opcode::from_raw(active_source.raw()),
+            Opcode::ActiveSource
+        ));
+
+        assert_eq!(
+            LogicalAddress::from_raw(LogicalAddress::UNKNOWN.raw()),
+            LogicalAddress::Unknown(c_enum!(cec_logical_address, -1))
+        );
+        assert_eq!("unknown".parse(), Ok(PowerStatus::UNKNOWN));
+        assert!(PowerStatus::try_from(PowerStatus::UNKNOWN.raw()).is_err());
+    }
 }
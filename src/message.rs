@@ -0,0 +1,1446 @@
+//! Typed CEC messages
+//!
+//! Each `cec_opcode` has a struct with its operands, e.g. [`ActiveSource`] or
+//! [`ReportPhysicalAddress`], which encodes into and decodes from `cec_command` with the
+//! [`Message`] trait. [`CecMessage`] is any of them, for decoding received commands.
+//!
+//! Encoding and decoding fail for operands of the wrong length, checked against the
+//! [`crate::opcode::OPCODES`] table first. Decoding keeps enum values which are not known to
+//! libcec as `Unknown`, so that decoding and encoding again gives the same bytes.
+//!
+//! ```
+//! use libcec_sys::address::PhysicalAddress;
+//! use libcec_sys::enums::LogicalAddress;
+//! use libcec_sys::message::{ActiveSource, CecMessage, Message};
+//!
+//! let message = ActiveSource {
+//!     physical_address: "1.0.0.0".parse().unwrap(),
+//! };
+//! let command = message
+//!     .to_command(LogicalAddress::PlaybackDevice1, LogicalAddress::Broadcast)
+//!     .unwrap();
+//! assert_eq!(&command.parameters.data[..2], &[0x10, 0x00]);
+//! assert_eq!(
+//!     CecMessage::from_command(&command),
+//!     Ok(CecMessage::ActiveSource(message))
+//! );
+//! ```
+use std::error::Error;
+use std::fmt;
+use std::os::raw::c_uint;
+
+use crate::address::PhysicalAddress;
+use crate::enums::{
+    self, AbortReason, AnalogueBroadcastType, AudioRate, BroadcastSystem, DeckControlMode,
+    DeckInfo, DeviceType, DisplayControl, LogicalAddress, MenuRequestType, MenuState, Opcode,
+    PlayMode, PowerStatus, RecordStatusInfo, StatusRequest, SystemAudioStatus,
+    TimerClearedStatusData, TunerDisplayInfo, UserControlCode, VendorId,
+};
+use crate::format::{self, Describe, Operands};
+use crate::*;
+
+#[cfg(not(feature = "newtype-enums"))]
+mod audio_status {
+    use crate::*;
+    pub const MUTE_STATUS_MASK: cec_audio_status = cec_audio_status_MUTE_STATUS_MASK;
+    pub const VOLUME_STATUS_MASK: cec_audio_status = cec_audio_status_VOLUME_STATUS_MASK;
+    pub const VOLUME_MAX: cec_audio_status = cec_audio_status_VOLUME_MAX;
+    pub const VOLUME_STATUS_UNKNOWN: cec_audio_status = cec_audio_status_VOLUME_STATUS_UNKNOWN;
+}
+#[cfg(feature = "newtype-enums")]
+use crate::cec_audio_status as audio_status;
+
+/// Maximum size of the operands of a message
+pub const MAX_OPERANDS_SIZE: usize = CEC_MAX_DATA_PACKET_SIZE as usize;
+
+/// Error encoding or decoding a message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageError {
+    /// The command has no opcode, i.e. it is a POLL message
+    NoOpcode,
+    /// The opcode has no message, e.g. `Opcode::None` or a value not known to libcec
+    UnknownOpcode(Opcode),
+    /// The command has another opcode than the decoded message
+    UnexpectedOpcode { expected: Opcode, found: Opcode },
+    /// Operands too short, or too long, for the opcode
+    InvalidLength { opcode: Opcode, length: usize },
+    /// Operand with a value which cannot be encoded or decoded
+    InvalidOperand {
+        opcode: Opcode,
+        operand: &'static str,
+    },
+    /// Operands longer than [`MAX_OPERANDS_SIZE`]
+    TooLong { length: usize },
+}
+
+impl fmt::Display for MessageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MessageError::NoOpcode => f.write_str("command has no opcode"),
+            MessageError::UnknownOpcode(opcode) => {
+                write!(f, "no message for opcode {:#04x}", opcode_value(*opcode))
+            }
+            MessageError::UnexpectedOpcode { expected, found } => {
+                write!(f, "expected opcode `{expected}`, found `{found}`")
+            }
+            MessageError::InvalidLength { opcode, length } => {
+                write!(f, "invalid length {length} of `{opcode}` operands")
+            }
+            MessageError::InvalidOperand { opcode, operand } => {
+                write!(f, "invalid `{opcode}` operand `{operand}`")
+            }
+            MessageError::TooLong { length } => write!(
+                f,
+                "{length} bytes of operands, more than the maximum of {MAX_OPERANDS_SIZE}"
+            ),
+        }
+    }
+}
+
+impl Error for MessageError {}
+
+fn opcode_value(opcode: Opcode) -> c_uint {
+    c_enum_value!(opcode.raw())
+}
+
+/// The used bytes of the parameters of `command`
+pub fn operands(command: &cec_command) -> Result<&[u8], MessageError> {
+    let length = usize::from(command.parameters.size);
+    command
+        .parameters
+        .data
+        .get(..length)
+        .ok_or(MessageError::TooLong { length })
+}
+
+/// Message with a fixed opcode, encoding to and decoding from `cec_command`
+pub trait Message: Sized {
+    const OPCODE: Opcode;
+
+    /// Appends the operands of the message to `operands`
+    fn encode_operands(&self, operands: &mut Vec<u8>) -> Result<(), MessageError>;
+
+    /// Decodes the message from all of `operands`
+    fn decode_operands(operands: &[u8]) -> Result<Self, MessageError>;
+
+    /// Command with the message from `initiator` to `destination`, with the default transmit
+    /// timeout of libcec
+    fn to_command(
+        &self,
+        initiator: LogicalAddress,
+        destination: LogicalAddress,
+    ) -> Result<cec_command, MessageError> {
+        let mut operands = Vec::new();
+        self.encode_operands(&mut operands)?;
+        command(Self::OPCODE, &operands, initiator, destination)
+    }
+
+    /// Decodes the message from `command`, which must have the opcode of the message
+    fn from_command(command: &cec_command) -> Result<Self, MessageError> {
+        let opcode = command_opcode(command)?;
+        if opcode != Self::OPCODE {
+            return Err(MessageError::UnexpectedOpcode {
+                expected: Self::OPCODE,
+                found: opcode,
+            });
+        }
+        Self::decode_operands(operands(command)?)
+    }
+}
+
+fn command_opcode(command: &cec_command) -> Result<Opcode, MessageError> {
+    if command.opcode_set == 0 {
+        return Err(MessageError::NoOpcode);
+    }
+    Ok(Opcode::from_raw(command.opcode))
+}
+
+fn command(
+    opcode: Opcode,
+    operands: &[u8],
+    initiator: LogicalAddress,
+    destination: LogicalAddress,
+) -> Result<cec_command, MessageError> {
+    if operands.len() > MAX_OPERANDS_SIZE {
+        return Err(MessageError::TooLong {
+            length: operands.len(),
+        });
+    }
+    check_length(opcode, operands.len())?;
+    let mut command = cec_command {
+        initiator: initiator.raw(),
+        destination: destination.raw(),
+        opcode: opcode.raw(),
+        opcode_set: 1,
+        transmit_timeout: CEC_DEFAULT_TRANSMIT_TIMEOUT as i32,
+        ..Default::default()
+    };
+    command.parameters.data[..operands.len()].copy_from_slice(operands);
+    command.parameters.size = operands.len() as u8;
+    Ok(command)
+}
+
+/// Check `length` against the operand sizes of `opcode`, if known
+fn check_length(opcode: Opcode, length: usize) -> Result<(), MessageError> {
+    match opcode.info() {
+        Some(info) if !info.accepts_length(length) => {
+            Err(MessageError::InvalidLength { opcode, length })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Why an operand could not be encoded or decoded
+enum OperandError {
+    Length,
+    Value,
+}
+
+/// Encoding of an operand type
+trait Operand: Sized {
+    fn encode(&self, out: &mut Vec<u8>) -> Result<(), OperandError>;
+
+    /// Takes the operand from the front of `input`
+    fn decode(input: &mut &[u8]) -> Result<Self, OperandError>;
+}
+
+fn take<const N: usize>(input: &mut &[u8]) -> Result<[u8; N], OperandError> {
+    if input.len() < N {
+        return Err(OperandError::Length);
+    }
+    let (bytes, rest) = input.split_at(N);
+    *input = rest;
+    Ok(bytes.try_into().unwrap())
+}
+
+impl Operand for u8 {
+    fn encode(&self, out: &mut Vec<u8>) -> Result<(), OperandError> {
+        out.push(*self);
+        Ok(())
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, OperandError> {
+        take::<1>(input).map(|[byte]| byte)
+    }
+}
+
+impl Operand for u16 {
+    fn encode(&self, out: &mut Vec<u8>) -> Result<(), OperandError> {
+        out.extend_from_slice(&self.to_be_bytes());
+        Ok(())
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, OperandError> {
+        take(input).map(u16::from_be_bytes)
+    }
+}
+
+impl<const N: usize> Operand for [u8; N] {
+    fn encode(&self, out: &mut Vec<u8>) -> Result<(), OperandError> {
+        out.extend_from_slice(self);
+        Ok(())
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, OperandError> {
+        take(input)
+    }
+}
+
+/// Trailing operand which may be left out
+impl<T: Operand> Operand for Option<T> {
+    fn encode(&self, out: &mut Vec<u8>) -> Result<(), OperandError> {
+        match self {
+            Some(value) => value.encode(out),
+            None => Ok(()),
+        }
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, OperandError> {
+        if input.is_empty() {
+            Ok(None)
+        } else {
+            T::decode(input).map(Some)
+        }
+    }
+}
+
+/// All the remaining operands
+impl<T: Operand> Operand for Vec<T> {
+    fn encode(&self, out: &mut Vec<u8>) -> Result<(), OperandError> {
+        self.iter().try_for_each(|value| value.encode(out))
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, OperandError> {
+        let mut values = Vec::new();
+        while !input.is_empty() {
+            values.push(T::decode(input)?);
+        }
+        Ok(values)
+    }
+}
+
+/// All the remaining operands, as printable ASCII
+impl Operand for String {
+    fn encode(&self, out: &mut Vec<u8>) -> Result<(), OperandError> {
+        if !self
+            .bytes()
+            .all(|byte| byte.is_ascii_graphic() || byte == b' ')
+        {
+            return Err(OperandError::Value);
+        }
+        out.extend_from_slice(self.as_bytes());
+        Ok(())
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, OperandError> {
+        let bytes = std::mem::take(input);
+        if !bytes
+            .iter()
+            .all(|byte| byte.is_ascii_graphic() || *byte == b' ')
+        {
+            return Err(OperandError::Value);
+        }
+        Ok(String::from_utf8_lossy(bytes).into_owned())
+    }
+}
+
+impl Operand for PhysicalAddress {
+    fn encode(&self, out: &mut Vec<u8>) -> Result<(), OperandError> {
+        self.raw().encode(out)
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, OperandError> {
+        u16::decode(input).map(PhysicalAddress::from_raw)
+    }
+}
+
+/// 24 bit IEEE OUI
+impl Operand for VendorId {
+    fn encode(&self, out: &mut Vec<u8>) -> Result<(), OperandError> {
+        let raw: u32 = c_enum_value!(self.raw());
+        match raw.to_be_bytes() {
+            [0, bytes @ ..] => bytes.encode(out),
+            _ => Err(OperandError::Value),
+        }
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, OperandError> {
+        let [a, b, c] = take(input)?;
+        Ok(VendorId::from_raw(c_enum!(
+            cec_vendor_id,
+            u32::from_be_bytes([0, a, b, c])
+        )))
+    }
+}
+
+/// Enums encoded as one byte
+macro_rules! enum_operands {
+    ($($ty:ty: $c_ty:ident),* $(,)?) => {
+        $(
+            impl Operand for $ty {
+                fn encode(&self, out: &mut Vec<u8>) -> Result<(), OperandError> {
+                    let raw: c_uint = c_enum_value!(self.raw());
+                    out.push(u8::try_from(raw).map_err(|_| OperandError::Value)?);
+                    Ok(())
+                }
+
+                fn decode(input: &mut &[u8]) -> Result<Self, OperandError> {
+                    let raw = c_uint::from(u8::decode(input)?);
+                    Ok(<$ty>::from_raw(c_enum!($c_ty, raw)))
+                }
+            }
+        )*
+    };
+}
+
+enum_operands!(
+    AbortReason: cec_abort_reason,
+    AnalogueBroadcastType: cec_analogue_broadcast_type,
+    AudioRate: cec_audio_rate,
+    BroadcastSystem: cec_broadcast_system,
+    enums::CecVersion: cec_version,
+    DeckControlMode: cec_deck_control_mode,
+    DeckInfo: cec_deck_info,
+    DeviceType: cec_device_type,
+    DisplayControl: cec_display_control,
+    MenuRequestType: cec_menu_request_type,
+    MenuState: cec_menu_state,
+    Opcode: cec_opcode,
+    PlayMode: cec_play_mode,
+    PowerStatus: cec_power_status,
+    RecordStatusInfo: cec_record_status_info,
+    StatusRequest: cec_status_request,
+    SystemAudioStatus: cec_system_audio_status,
+    TimerClearedStatusData: cec_timer_cleared_status_data,
+    UserControlCode: cec_user_control_code,
+);
+/// `[Digital Service Identification]`, kept as the raw 7 bytes
+pub type DigitalServiceId = [u8; 7];
+
+/// `[Short Audio Descriptor]`, kept as the raw 3 bytes
+pub type ShortAudioDescriptor = [u8; 3];
+
+/// `[Analogue Broadcast Type] [Analogue Frequency] [Broadcast System]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnalogueService {
+    pub broadcast_type: AnalogueBroadcastType,
+    /// Frequency in units of 62.5 kHz
+    pub frequency: u16,
+    pub broadcast_system: BroadcastSystem,
+}
+
+impl Operand for AnalogueService {
+    fn encode(&self, out: &mut Vec<u8>) -> Result<(), OperandError> {
+        self.broadcast_type.encode(out)?;
+        self.frequency.encode(out)?;
+        self.broadcast_system.encode(out)
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, OperandError> {
+        Ok(AnalogueService {
+            broadcast_type: Operand::decode(input)?,
+            frequency: Operand::decode(input)?,
+            broadcast_system: Operand::decode(input)?,
+        })
+    }
+}
+
+fn to_bcd(value: u8) -> Result<u8, OperandError> {
+    if value > 99 {
+        return Err(OperandError::Value);
+    }
+    Ok(((value / 10) << 4) | (value % 10))
+}
+
+fn from_bcd(bcd: u8) -> Result<u8, OperandError> {
+    let (tens, units) = (bcd >> 4, bcd & 0xf);
+    if tens > 9 || units > 9 {
+        return Err(OperandError::Value);
+    }
+    Ok(tens * 10 + units)
+}
+
+/// Date, start time, duration and recurrence of a timer
+///
+/// The hours and minutes are plain numbers, encoded as BCD.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimerSchedule {
+    /// 1 to 31
+    pub day_of_month: u8,
+    /// 1 to 12
+    pub month_of_year: u8,
+    pub start_hour: u8,
+    pub start_minute: u8,
+    pub duration_hours: u8,
+    pub duration_minutes: u8,
+    /// Bitmap of `RecordingSequence` days, 0 for once only
+    pub recording_sequence: u8,
+}
+
+impl Operand for TimerSchedule {
+    fn encode(&self, out: &mut Vec<u8>) -> Result<(), OperandError> {
+        out.extend_from_slice(&[
+            self.day_of_month,
+            self.month_of_year,
+            to_bcd(self.start_hour)?,
+            to_bcd(self.start_minute)?,
+            to_bcd(self.duration_hours)?,
+            to_bcd(self.duration_minutes)?,
+            self.recording_sequence,
+        ]);
+        Ok(())
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, OperandError> {
+        let [day, month, start_hour, start_minute, hours, minutes, sequence] = take(input)?;
+        Ok(TimerSchedule {
+            day_of_month: day,
+            month_of_year: month,
+            start_hour: from_bcd(start_hour)?,
+            start_minute: from_bcd(start_minute)?,
+            duration_hours: from_bcd(hours)?,
+            duration_minutes: from_bcd(minutes)?,
+            recording_sequence: sequence,
+        })
+    }
+}
+
+/// `[External Source Specifier]` with the plug or physical address
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ExternalSource {
+    Plug(u8),
+    PhysicalAddress(PhysicalAddress),
+}
+
+impl Operand for ExternalSource {
+    fn encode(&self, out: &mut Vec<u8>) -> Result<(), OperandError> {
+        match self {
+            ExternalSource::Plug(plug) => [4, *plug].encode(out),
+            ExternalSource::PhysicalAddress(address) => {
+                out.push(5);
+                address.encode(out)
+            }
+        }
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, OperandError> {
+        match u8::decode(input)? {
+            4 => u8::decode(input).map(ExternalSource::Plug),
+            5 => PhysicalAddress::decode(input).map(ExternalSource::PhysicalAddress),
+            _ => Err(OperandError::Value),
+        }
+    }
+}
+
+/// `[Record Source]` of `<Record On>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RecordSource {
+    OwnSource,
+    DigitalService(DigitalServiceId),
+    AnalogueService(AnalogueService),
+    ExternalPlug(u8),
+    ExternalPhysicalAddress(PhysicalAddress),
+}
+
+impl Operand for RecordSource {
+    fn encode(&self, out: &mut Vec<u8>) -> Result<(), OperandError> {
+        match self {
+            RecordSource::OwnSource => 1u8.encode(out),
+            RecordSource::DigitalService(service) => {
+                out.push(2);
+                service.encode(out)
+            }
+            RecordSource::AnalogueService(service) => {
+                out.push(3);
+                service.encode(out)
+            }
+            RecordSource::ExternalPlug(plug) => [4, *plug].encode(out),
+            RecordSource::ExternalPhysicalAddress(address) => {
+                out.push(5);
+                address.encode(out)
+            }
+        }
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, OperandError> {
+        match u8::decode(input)? {
+            1 => Ok(RecordSource::OwnSource),
+            2 => Operand::decode(input).map(RecordSource::DigitalService),
+            3 => Operand::decode(input).map(RecordSource::AnalogueService),
+            4 => Operand::decode(input).map(RecordSource::ExternalPlug),
+            5 => Operand::decode(input).map(RecordSource::ExternalPhysicalAddress),
+            _ => Err(OperandError::Value),
+        }
+    }
+}
+
+/// `[Recording Flag]` and `[Tuner Display Info]`, sharing a byte
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TunerInfo {
+    /// Whether the tuner is used for recording
+    pub recording: bool,
+    pub display: TunerDisplayInfo,
+}
+
+impl Operand for TunerInfo {
+    fn encode(&self, out: &mut Vec<u8>) -> Result<(), OperandError> {
+        let display: c_uint = c_enum_value!(self.display.raw());
+        if display > 0x7f {
+            return Err(OperandError::Value);
+        }
+        out.push((u8::from(self.recording) << 7) | display as u8);
+        Ok(())
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, OperandError> {
+        let byte = u8::decode(input)?;
+        Ok(TunerInfo {
+            recording: byte & 0x80 != 0,
+            display: TunerDisplayInfo::from_raw(c_enum!(
+                cec_tuner_display_info,
+                c_uint::from(byte & 0x7f)
+            )),
+        })
+    }
+}
+
+/// Service the tuner is tuned to, told apart by its length
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TunerService {
+    Analogue(AnalogueService),
+    Digital(DigitalServiceId),
+}
+
+impl Operand for TunerService {
+    fn encode(&self, out: &mut Vec<u8>) -> Result<(), OperandError> {
+        match self {
+            TunerService::Analogue(service) => service.encode(out),
+            TunerService::Digital(service) => service.encode(out),
+        }
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, OperandError> {
+        match input.len() {
+            4 => Operand::decode(input).map(TunerService::Analogue),
+            7 => Operand::decode(input).map(TunerService::Digital),
+            _ => Err(OperandError::Length),
+        }
+    }
+}
+
+/// `[Audio Status]`: mute status and volume, sharing a byte
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AudioStatus {
+    pub muted: bool,
+    /// Volume in percent, [`AudioStatus::VOLUME_UNKNOWN`] when unknown
+    pub volume: u8,
+}
+
+impl AudioStatus {
+    /// `cec_audio_status` bit of the mute status
+    pub const MUTE_MASK: u8 = audio_status::MUTE_STATUS_MASK as u8;
+    /// `cec_audio_status` bits of the volume
+    pub const VOLUME_MASK: u8 = audio_status::VOLUME_STATUS_MASK as u8;
+    /// Highest volume, values up to [`AudioStatus::VOLUME_UNKNOWN`] are reserved
+    pub const VOLUME_MAX: u8 = audio_status::VOLUME_MAX as u8;
+    pub const VOLUME_UNKNOWN: u8 = audio_status::VOLUME_STATUS_UNKNOWN as u8;
+}
+
+impl Operand for AudioStatus {
+    fn encode(&self, out: &mut Vec<u8>) -> Result<(), OperandError> {
+        if self.volume & !AudioStatus::VOLUME_MASK != 0 {
+            return Err(OperandError::Value);
+        }
+        let mute = if self.muted {
+            AudioStatus::MUTE_MASK
+        } else {
+            0
+        };
+        out.push(mute | self.volume);
+        Ok(())
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, OperandError> {
+        let byte = u8::decode(input)?;
+        Ok(AudioStatus {
+            muted: byte & AudioStatus::MUTE_MASK != 0,
+            volume: byte & AudioStatus::VOLUME_MASK,
+        })
+    }
+}
+
+fn decode_operand<T: Operand>(
+    input: &mut &[u8],
+    opcode: Opcode,
+    operand: &'static str,
+    length: usize,
+) -> Result<T, MessageError> {
+    T::decode(input).map_err(|err| match err {
+        OperandError::Length => MessageError::InvalidLength { opcode, length },
+        OperandError::Value => MessageError::InvalidOperand { opcode, operand },
+    })
+}
+
+/// Struct of a message, with its `Message` implementation
+macro_rules! message {
+    ($(#[$doc:meta])* $name:ident) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub struct $name;
+
+        impl Message for $name {
+            const OPCODE: Opcode = Opcode::$name;
+
+            fn encode_operands(&self, _operands: &mut Vec<u8>) -> Result<(), MessageError> {
+                Ok(())
+            }
+
+            fn decode_operands(operands: &[u8]) -> Result<Self, MessageError> {
+                check_length(Self::OPCODE, operands.len())?;
+                Ok($name)
+            }
+        }
+
+        impl Operands for $name {
+            fn describe_operands(&self, _operands: &mut Vec<format::Operand>) {}
+        }
+    };
+    (
+        $(#[$doc:meta])*
+        $name:ident { $($(#[$field_doc:meta])* $field:ident: $ty:ty),+ $(,)? }
+    ) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub struct $name {
+            $($(#[$field_doc])* pub $field: $ty,)+
+        }
+
+        impl Message for $name {
+            const OPCODE: Opcode = Opcode::$name;
+
+            fn encode_operands(&self, operands: &mut Vec<u8>) -> Result<(), MessageError> {
+                $(
+                    self.$field.encode(operands).map_err(|_| MessageError::InvalidOperand {
+                        opcode: Self::OPCODE,
+                        operand: stringify!($field),
+                    })?;
+                )+
+                Ok(())
+            }
+
+            fn decode_operands(operands: &[u8]) -> Result<Self, MessageError> {
+                let length = operands.len();
+                check_length(Self::OPCODE, length)?;
+                let mut input = operands;
+                let message = $name {
+                    $($field: decode_operand(&mut input, Self::OPCODE, stringify!($field), length)?,)+
+                };
+                if !input.is_empty() {
+                    return Err(MessageError::InvalidLength {
+                        opcode: Self::OPCODE,
+                        length,
+                    });
+                }
+                Ok(message)
+            }
+        }
+
+        impl Operands for $name {
+            fn describe_operands(&self, operands: &mut Vec<format::Operand>) {
+                $(self.$field.describe(stringify!($field), operands);)+
+            }
+        }
+    };
+}
+
+message!(
+    /// `<Active Source>`: the initiator is the active source
+    ActiveSource { physical_address: PhysicalAddress }
+);
+message!(
+    /// `<Image View On>`: turn on the TV and show the source
+    ImageViewOn
+);
+message!(
+    /// `<Text View On>`: turn on the TV and remove menus from the screen
+    TextViewOn
+);
+message!(
+    /// `<Inactive Source>`: the initiator is no longer the active source
+    InactiveSource { physical_address: PhysicalAddress }
+);
+message!(
+    /// `<Request Active Source>`: ask the active source to announce itself
+    RequestActiveSource
+);
+message!(
+    /// `<Routing Change>`: a switch changed its input
+    RoutingChange {
+        original_address: PhysicalAddress,
+        new_address: PhysicalAddress,
+    }
+);
+message!(
+    /// `<Routing Information>`: the active route below a switch
+    RoutingInformation { physical_address: PhysicalAddress }
+);
+message!(
+    /// `<Set Stream Path>`: make the device at the address the active source
+    SetStreamPath { physical_address: PhysicalAddress }
+);
+message!(
+    /// `<Standby>`: switch to standby
+    Standby
+);
+message!(
+    /// `<Record Off>`: stop recording
+    RecordOff
+);
+message!(
+    /// `<Record On>`: start recording the source
+    RecordOn { source: RecordSource }
+);
+message!(
+    /// `<Record Status>`: result of `<Record On>`
+    RecordStatus { status: RecordStatusInfo }
+);
+message!(
+    /// `<Record TV Screen>`: ask the TV for the source to record
+    RecordTvScreen
+);
+message!(
+    /// `<Clear Analogue Timer>`
+    ClearAnalogueTimer {
+        schedule: TimerSchedule,
+        service: AnalogueService,
+    }
+);
+message!(
+    /// `<Clear Digital Timer>`
+    ClearDigitalTimer {
+        schedule: TimerSchedule,
+        service: DigitalServiceId,
+    }
+);
+message!(
+    /// `<Clear External Timer>`
+    ClearExternalTimer {
+        schedule: TimerSchedule,
+        source: ExternalSource,
+    }
+);
+message!(
+    /// `<Set Analogue Timer>`
+    SetAnalogueTimer {
+        schedule: TimerSchedule,
+        service: AnalogueService,
+    }
+);
+message!(
+    /// `<Set Digital Timer>`
+    SetDigitalTimer {
+        schedule: TimerSchedule,
+        service: DigitalServiceId,
+    }
+);
+message!(
+    /// `<Set External Timer>`
+    SetExternalTimer {
+        schedule: TimerSchedule,
+        source: ExternalSource,
+    }
+);
+message!(
+    /// `<Set Timer Program Title>`
+    SetTimerProgramTitle { title: String }
+);
+message!(
+    /// `<Timer Cleared Status>`: result of a `<Clear ... Timer>`
+    TimerClearedStatus { status: TimerClearedStatusData }
+);
+message!(
+    /// `<Timer Status>`: result of a `<Set ... Timer>`
+    TimerStatus {
+        /// `[Timer Status Data]` bit fields, see `TimerOverlapWarning`, `MediaInfo`,
+        /// `ProgrammedIndicator`, `ProgrammedInfo` and `NotProgrammedErrorInfo`
+        status: u8,
+        /// BCD hours and minutes of recording space available
+        duration_available: Option<[u8; 2]>,
+    }
+);
+message!(
+    /// `<CEC Version>`: the CEC version of the initiator
+    CecVersion { version: enums::CecVersion }
+);
+message!(
+    /// `<Get CEC Version>`
+    GetCecVersion
+);
+message!(
+    /// `<Give Physical Address>`
+    GivePhysicalAddress
+);
+message!(
+    /// `<Get Menu Language>`
+    GetMenuLanguage
+);
+message!(
+    /// `<Report Physical Address>`: the physical address and type of the initiator
+    ReportPhysicalAddress {
+        physical_address: PhysicalAddress,
+        device_type: DeviceType,
+    }
+);
+message!(
+    /// `<Set Menu Language>`: the menu language of the TV
+    SetMenuLanguage {
+        /// ISO 639-2 code, e.g. `*b"eng"`
+        language: [u8; 3],
+    }
+);
+message!(
+    /// `<Deck Control>`
+    DeckControl { mode: DeckControlMode }
+);
+message!(
+    /// `<Deck Status>`
+    DeckStatus { info: DeckInfo }
+);
+message!(
+    /// `<Give Deck Status>`
+    GiveDeckStatus { request: StatusRequest }
+);
+message!(
+    /// `<Play>`
+    Play { mode: PlayMode }
+);
+message!(
+    /// `<Give Tuner Device Status>`
+    GiveTunerDeviceStatus { request: StatusRequest }
+);
+message!(
+    /// `<Select Analogue Service>`
+    SelectAnalogueService { service: AnalogueService }
+);
+message!(
+    /// `<Select Digital Service>`
+    SelectDigitalService { service: DigitalServiceId }
+);
+message!(
+    /// `<Tuner Device Status>`
+    TunerDeviceStatus {
+        info: TunerInfo,
+        service: TunerService,
+    }
+);
+message!(
+    /// `<Tuner Step Decrement>`
+    TunerStepDecrement
+);
+message!(
+    /// `<Tuner Step Increment>`
+    TunerStepIncrement
+);
+message!(
+    /// `<Device Vendor ID>`: the vendor of the initiator
+    DeviceVendorId { vendor: VendorId }
+);
+message!(
+    /// `<Give Device Vendor ID>`
+    GiveDeviceVendorId
+);
+message!(
+    /// `<Vendor Command>`
+    VendorCommand { data: Vec<u8> }
+);
+message!(
+    /// `<Vendor Command With ID>`
+    VendorCommandWithId { vendor: VendorId, data: Vec<u8> }
+);
+message!(
+    /// `<Vendor Remote Button Down>`
+    VendorRemoteButtonDown { data: Vec<u8> }
+);
+message!(
+    /// `<Vendor Remote Button Up>`
+    VendorRemoteButtonUp
+);
+message!(
+    /// `<Set OSD String>`: text to show on the TV
+    SetOsdString {
+        control: DisplayControl,
+        text: String,
+    }
+);
+message!(
+    /// `<Give OSD Name>`
+    GiveOsdName
+);
+message!(
+    /// `<Set OSD Name>`: the name of the initiator
+    SetOsdName { name: String }
+);
+message!(
+    /// `<Menu Request>`
+    MenuRequest { request: MenuRequestType }
+);
+message!(
+    /// `<Menu Status>`
+    MenuStatus { state: MenuState }
+);
+message!(
+    /// `<User Control Pressed>`
+    UserControlPressed {
+        code: UserControlCode,
+        /// Additional operands of some codes, e.g. the play mode of `Play Function` or the
+        /// 4 byte channel identifier of `Tune Function`
+        parameters: Vec<u8>,
+    }
+);
+message!(
+    /// `<User Control Released>`
+    UserControlRelease
+);
+message!(
+    /// `<Give Device Power Status>`
+    GiveDevicePowerStatus
+);
+message!(
+    /// `<Report Power Status>`
+    ReportPowerStatus { status: PowerStatus }
+);
+message!(
+    /// `<Feature Abort>`: the destination did not process `opcode`
+    FeatureAbort {
+        opcode: Opcode,
+        reason: AbortReason,
+    }
+);
+message!(
+    /// `<Abort>`: test message, answered with `<Feature Abort>`
+    Abort
+);
+message!(
+    /// `<Give Audio Status>`
+    GiveAudioStatus
+);
+message!(
+    /// `<Give System Audio Mode Status>`
+    GiveSystemAudioModeStatus
+);
+message!(
+    /// `<Report Audio Status>`
+    ReportAudioStatus { status: AudioStatus }
+);
+message!(
+    /// `<Set System Audio Mode>`
+    SetSystemAudioMode { status: SystemAudioStatus }
+);
+message!(
+    /// `<System Audio Mode Request>`: turn system audio on for the source at the address, or
+    /// off without it
+    SystemAudioModeRequest {
+        physical_address: Option<PhysicalAddress>,
+    }
+);
+message!(
+    /// `<System Audio Mode Status>`
+    SystemAudioModeStatus { status: SystemAudioStatus }
+);
+message!(
+    /// `<Set Audio Rate>`
+    SetAudioRate { rate: AudioRate }
+);
+message!(
+    /// `<Report Short Audio Descriptor>`
+    ReportShortAudioDescriptors {
+        descriptors: Vec<ShortAudioDescriptor>,
+    }
+);
+message!(
+    /// `<Request Short Audio Descriptor>`
+    RequestShortAudioDescriptors {
+        /// Audio format codes
+        formats: Vec<u8>,
+    }
+);
+message!(
+    /// `<Initiate ARC>`
+    StartArc
+);
+message!(
+    /// `<Report ARC Initiated>`
+    ReportArcStarted
+);
+message!(
+    /// `<Report ARC Terminated>`
+    ReportArcEnded
+);
+message!(
+    /// `<Request ARC Initiation>`
+    RequestArcStart
+);
+message!(
+    /// `<Request ARC Termination>`
+    RequestArcEnd
+);
+message!(
+    /// `<Terminate ARC>`
+    EndArc
+);
+message!(
+    /// `<CDC Message>`: HDMI Ethernet Channel message, with the physical address of the
+    /// initiator first
+    Cdc { data: Vec<u8> }
+);
+
+/// Enum of all the messages
+macro_rules! cec_message {
+    ($($name:ident),* $(,)?) => {
+        /// Message of any opcode
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub enum CecMessage {
+            $($name($name),)*
+        }
+
+        impl CecMessage {
+            pub fn opcode(&self) -> Opcode {
+                match self {
+                    $(CecMessage::$name(_) => Opcode::$name,)*
+                }
+            }
+
+            /// Appends the operands of the message to `operands`
+            pub fn encode_operands(&self, operands: &mut Vec<u8>) -> Result<(), MessageError> {
+                match self {
+                    $(CecMessage::$name(message) => message.encode_operands(operands),)*
+                }
+            }
+
+            /// Decodes the message of `opcode` from all of `operands`
+            pub fn decode(opcode: Opcode, operands: &[u8]) -> Result<Self, MessageError> {
+                match opcode {
+                    $(Opcode::$name => $name::decode_operands(operands).map(CecMessage::$name),)*
+                    _ => Err(MessageError::UnknownOpcode(opcode)),
+                }
+            }
+        }
+
+        impl Operands for CecMessage {
+            fn describe_operands(&self, operands: &mut Vec<format::Operand>) {
+                match self {
+                    $(CecMessage::$name(message) => message.describe_operands(operands),)*
+                }
+            }
+        }
+
+        $(
+            impl From<$name> for CecMessage {
+                fn from(message: $name) -> Self {
+                    CecMessage::$name(message)
+                }
+            }
+        )*
+    };
+}
+
+cec_message!(
+    ActiveSource,
+    ImageViewOn,
+    TextViewOn,
+    InactiveSource,
+    RequestActiveSource,
+    RoutingChange,
+    RoutingInformation,
+    SetStreamPath,
+    Standby,
+    RecordOff,
+    RecordOn,
+    RecordStatus,
+    RecordTvScreen,
+    ClearAnalogueTimer,
+    ClearDigitalTimer,
+    ClearExternalTimer,
+    SetAnalogueTimer,
+    SetDigitalTimer,
+    SetExternalTimer,
+    SetTimerProgramTitle,
+    TimerClearedStatus,
+    TimerStatus,
+    CecVersion,
+    GetCecVersion,
+    GivePhysicalAddress,
+    GetMenuLanguage,
+    ReportPhysicalAddress,
+    SetMenuLanguage,
+    DeckControl,
+    DeckStatus,
+    GiveDeckStatus,
+    Play,
+    GiveTunerDeviceStatus,
+    SelectAnalogueService,
+    SelectDigitalService,
+    TunerDeviceStatus,
+    TunerStepDecrement,
+    TunerStepIncrement,
+    DeviceVendorId,
+    GiveDeviceVendorId,
+    VendorCommand,
+    VendorCommandWithId,
+    VendorRemoteButtonDown,
+    VendorRemoteButtonUp,
+    SetOsdString,
+    GiveOsdName,
+    SetOsdName,
+    MenuRequest,
+    MenuStatus,
+    UserControlPressed,
+    UserControlRelease,
+    GiveDevicePowerStatus,
+    ReportPowerStatus,
+    FeatureAbort,
+    Abort,
+    GiveAudioStatus,
+    GiveSystemAudioModeStatus,
+    ReportAudioStatus,
+    SetSystemAudioMode,
+    SystemAudioModeRequest,
+    SystemAudioModeStatus,
+    SetAudioRate,
+    ReportShortAudioDescriptors,
+    RequestShortAudioDescriptors,
+    StartArc,
+    ReportArcStarted,
+    ReportArcEnded,
+    RequestArcStart,
+    RequestArcEnd,
+    EndArc,
+    Cdc,
+);
+
+impl CecMessage {
+    /// Command with the message from `initiator` to `destination`, with the default transmit
+    /// timeout of libcec
+    pub fn to_command(
+        &self,
+        initiator: LogicalAddress,
+        destination: LogicalAddress,
+    ) -> Result<cec_command, MessageError> {
+        let mut operands = Vec::new();
+        self.encode_operands(&mut operands)?;
+        command(self.opcode(), &operands, initiator, destination)
+    }
+
+    /// Decodes the message of `command`
+    pub fn from_command(command: &cec_command) -> Result<Self, MessageError> {
+        CecMessage::decode(command_opcode(command)?, operands(command)?)
+    }
+}
+
+impl TryFrom<&cec_command> for CecMessage {
+    type Error = MessageError;
+
+    fn try_from(command: &cec_command) -> Result<Self, MessageError> {
+        CecMessage::from_command(command)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(message: impl Into<CecMessage>) -> Vec<u8> {
+        let message = message.into();
+        let mut operands = Vec::new();
+        message.encode_operands(&mut operands).unwrap();
+        assert_eq!(CecMessage::decode(message.opcode(), &operands), Ok(message));
+        operands
+    }
+
+    #[test]
+    fn test_all_opcodes() {
+        for opcode in Opcode::ALL {
+            match CecMessage::decode(*opcode, &[]) {
+                Err(MessageError::UnknownOpcode(_)) => assert_eq!(*opcode, Opcode::None),
+                Ok(message) => assert_eq!(message.opcode(), *opcode),
+                Err(_) => {}
+            }
+        }
+    }
+
+    #[test]
+    fn test_operands() {
+        assert_eq!(
+            encode(ReportPhysicalAddress {
+                physical_address: PhysicalAddress::from_raw(0x1000),
+                device_type: DeviceType::PlaybackDevice,
+            }),
+            [0x10, 0x00, 0x04]
+        );
+        assert_eq!(
+            encode(SetOsdName {
+                name: "Kodi".to_owned()
+            }),
+            b"Kodi"
+        );
+        assert_eq!(
+            encode(DeviceVendorId {
+                vendor: VendorId::PulseEight
+            }),
+            [0x00, 0x15, 0x82]
+        );
+        assert_eq!(
+            encode(UserControlPressed {
+                code: UserControlCode::Select,
+                parameters: vec![],
+            }),
+            [0x00]
+        );
+        assert_eq!(
+            encode(FeatureAbort {
+                opcode: Opcode::GiveDeckStatus,
+                reason: AbortReason::Refused,
+            }),
+            [0x1a, 0x04]
+        );
+        assert_eq!(
+            encode(ReportAudioStatus {
+                status: AudioStatus {
+                    muted: true,
+                    volume: 50,
+                },
+            }),
+            [0xb2]
+        );
+        assert_eq!(
+            encode(SetDigitalTimer {
+                schedule: TimerSchedule {
+                    day_of_month: 24,
+                    month_of_year: 12,
+                    start_hour: 20,
+                    start_minute: 15,
+                    duration_hours: 1,
+                    duration_minutes: 30,
+                    recording_sequence: 0,
+                },
+                service: [1, 2, 3, 4, 5, 6, 7],
+            }),
+            [24, 12, 0x20, 0x15, 0x01, 0x30, 0, 1, 2, 3, 4, 5, 6, 7]
+        );
+        assert_eq!(
+            encode(TunerDeviceStatus {
+                info: TunerInfo {
+                    recording: true,
+                    display: TunerDisplayInfo::DisplayingAnalogueTuner,
+                },
+                service: TunerService::Analogue(AnalogueService {
+                    broadcast_type: AnalogueBroadcastType::Cable,
+                    frequency: 0x1234,
+                    broadcast_system: BroadcastSystem::PalBG,
+                }),
+            }),
+            [0x82, 0x00, 0x12, 0x34, 0x00]
+        );
+        assert_eq!(
+            encode(RecordOn {
+                source: RecordSource::ExternalPhysicalAddress(PhysicalAddress::from_raw(0x2100)),
+            }),
+            [5, 0x21, 0x00]
+        );
+        assert!(encode(StartArc).is_empty());
+
+        // `Tune Function` with a 4 byte channel identifier
+        let operands = [0x67, 0x22, 0x01, 0x00, 0x05];
+        let message = CecMessage::decode(Opcode::UserControlPressed, &operands).unwrap();
+        assert_eq!(
+            message,
+            CecMessage::UserControlPressed(UserControlPressed {
+                code: UserControlCode::from_raw(c_enum!(cec_user_control_code, 0x67)),
+                parameters: vec![0x22, 0x01, 0x00, 0x05],
+            })
+        );
+        assert_eq!(encode(message), operands);
+    }
+
+    #[test]
+    fn test_unknown_values() {
+        let message = CecMessage::decode(Opcode::ReportPowerStatus, &[0x42]).unwrap();
+        assert_eq!(
+            message,
+            CecMessage::ReportPowerStatus(ReportPowerStatus {
+                status: PowerStatus::Unknown(c_enum!(cec_power_status, 0x42))
+            })
+        );
+        assert_eq!(encode(message), [0x42]);
+    }
+
+    #[test]
+    fn test_invalid_operands() {
+        assert_eq!(
+            CecMessage::decode(Opcode::ActiveSource, &[0x10]),
+            Err(MessageError::InvalidLength {
+                opcode: Opcode::ActiveSource,
+                length: 1
+            })
+        );
+        assert_eq!(
+            CecMessage::decode(Opcode::ActiveSource, &[0x10, 0x00, 0x00]),
+            Err(MessageError::InvalidLength {
+                opcode: Opcode::ActiveSource,
+                length: 3
+            })
+        );
+        assert_eq!(
+            CecMessage::decode(Opcode::Standby, &[0x00]),
+            Err(MessageError::InvalidLength {
+                opcode: Opcode::Standby,
+                length: 1
+            })
+        );
+        assert_eq!(
+            CecMessage::decode(Opcode::ReportShortAudioDescriptors, &[1, 2, 3, 4]),
+            Err(MessageError::InvalidLength {
+                opcode: Opcode::ReportShortAudioDescriptors,
+                length: 4
+            })
+        );
+        assert_eq!(
+            CecMessage::decode(Opcode::SetOsdName, b"caf\xc3\xa9"),
+            Err(MessageError::InvalidOperand {
+                opcode: Opcode::SetOsdName,
+                operand: "name"
+            })
+        );
+        let mut operands = Vec::new();
+        let err = AudioStatus {
+            muted: false,
+            volume: 0x80,
+        };
+        assert!(ReportAudioStatus { status: err }
+            .encode_operands(&mut operands)
+            .is_err());
+        assert_eq!(
+            CecMessage::decode(Opcode::Unknown(c_enum!(cec_opcode, 0x01)), &[]),
+            Err(MessageError::UnknownOpcode(Opcode::Unknown(c_enum!(
+                cec_opcode, 0x01
+            ))))
+        );
+    }
+
+    #[test]
+    fn test_command() {
+        let message = SetOsdName {
+            name: "Kodi".to_owned(),
+        };
+        let command = message
+            .to_command(LogicalAddress::PlaybackDevice1, LogicalAddress::Tv)
+            .unwrap();
+        assert_eq!(command.initiator, LogicalAddress::PlaybackDevice1.raw());
+        assert_eq!(command.destination, LogicalAddress::Tv.raw());
+        assert_eq!(Opcode::from_raw(command.opcode), Opcode::SetOsdName);
+        assert_eq!(command.opcode_set, 1);
+        assert_eq!(operands(&command), Ok(&b"Kodi"[..]));
+        assert_eq!(SetOsdName::from_command(&command), Ok(message));
+        assert_eq!(
+            Standby::from_command(&command),
+            Err(MessageError::UnexpectedOpcode {
+                expected: Opcode::Standby,
+                found: Opcode::SetOsdName
+            })
+        );
+
+        let poll = cec_command {
+            opcode_set: 0,
+            ..command
+        };
+        assert_eq!(CecMessage::from_command(&poll), Err(MessageError::NoOpcode));
+
+        let too_long = VendorCommand {
+            data: vec![0; MAX_OPERANDS_SIZE + 1],
+        };
+        assert_eq!(
+            too_long
+                .to_command(LogicalAddress::PlaybackDevice1, LogicalAddress::Tv)
+                .err(),
+            Some(MessageError::TooLong {
+                length: MAX_OPERANDS_SIZE + 1
+            })
+        );
+        let mut invalid = command;
+        invalid.parameters.size = 65;
+        assert_eq!(
+            CecMessage::from_command(&invalid),
+            Err(MessageError::TooLong { length: 65 })
+        );
+    }
+}
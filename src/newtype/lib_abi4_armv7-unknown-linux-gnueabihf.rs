@@ -0,0 +1,2181 @@
+/* automatically generated by rust-bindgen 0.71.1 */
+
+pub type cec_menu_language = [::std::os::raw::c_char; 4usize];
+pub type cec_osd_name = [::std::os::raw::c_char; 14usize];
+pub type libcec_connection_t = *mut ::std::os::raw::c_void;
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_abort_reason(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_analogue_broadcast_type(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_audio_rate(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_boolean(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_version(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_deck_control_mode(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_deck_info(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_device_type(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_display_control(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_external_source_specifier(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_menu_request_type(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_menu_state(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_play_mode(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_power_status(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_record_source_type(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_record_status_info(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_recording_sequence(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_status_request(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_system_audio_status(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_timer_cleared_status_data(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_timer_overlap_warning(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_media_info(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_programmed_indicator(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_programmed_info(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_not_programmed_error_info(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_recording_flag(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_tuner_display_info(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_broadcast_system(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_user_control_code(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_logical_address(pub ::std::os::raw::c_int);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_opcode(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_log_level(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_bus_device_status(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_vendor_id(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cec_adapter_type(pub ::std::os::raw::c_uint);
+#[doc = " force exporting through swig"]
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct libcec_version(pub ::std::os::raw::c_uint);
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct cec_log_message {
+    #[doc = "< the actual message, valid until returning from the log callback"]
+    pub message: *const ::std::os::raw::c_char,
+    #[doc = "< log level of the message"]
+    pub level: cec_log_level,
+    #[doc = "< the timestamp of this message"]
+    pub time: i64,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct cec_keypress {
+    #[doc = "< the keycode"]
+    pub keycode: cec_user_control_code,
+    #[doc = "< the duration of the keypress"]
+    pub duration: ::std::os::raw::c_uint,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct cec_adapter {
+    #[doc = "< the path to the com port"]
+    pub path: [::std::os::raw::c_char; 1024usize],
+    #[doc = "< the name of the com port"]
+    pub comm: [::std::os::raw::c_char; 1024usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct cec_adapter_descriptor {
+    #[doc = "< the path to the com port"]
+    pub strComPath: [::std::os::raw::c_char; 1024usize],
+    #[doc = "< the name of the com port"]
+    pub strComName: [::std::os::raw::c_char; 1024usize],
+    pub iVendorId: u16,
+    pub iProductId: u16,
+    pub iFirmwareVersion: u16,
+    pub iPhysicalAddress: u16,
+    pub iFirmwareBuildDate: u32,
+    pub adapterType: cec_adapter_type,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct cec_datapacket {
+    #[doc = "< the actual data"]
+    pub data: [u8; 64usize],
+    #[doc = "< the size of the data"]
+    pub size: u8,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct cec_command {
+    #[doc = "< the logical address of the initiator of this message"]
+    pub initiator: cec_logical_address,
+    #[doc = "< the logical address of the destination of this message"]
+    pub destination: cec_logical_address,
+    #[doc = "< 1 when the ACK bit is set, 0 otherwise"]
+    pub ack: i8,
+    #[doc = "< 1 when the EOM bit is set, 0 otherwise"]
+    pub eom: i8,
+    #[doc = "< the opcode of this message"]
+    pub opcode: cec_opcode,
+    #[doc = "< the parameters attached to this message"]
+    pub parameters: cec_datapacket,
+    #[doc = "< 1 when an opcode is set, 0 otherwise (POLL message)"]
+    pub opcode_set: i8,
+    #[doc = "< the timeout to use in ms"]
+    pub transmit_timeout: i32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct cec_device_type_list {
+    #[doc = "< the list of device types"]
+    pub types: [cec_device_type; 5usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct cec_logical_addresses {
+    #[doc = "< the primary logical address to use"]
+    pub primary: cec_logical_address,
+    #[doc = "< the list of addresses"]
+    pub addresses: [::std::os::raw::c_int; 16usize],
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct libcec_alert(pub ::std::os::raw::c_uint);
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct libcec_parameter_type(pub ::std::os::raw::c_uint);
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct libcec_parameter {
+    #[doc = "< the type of this parameter"]
+    pub paramType: libcec_parameter_type,
+    #[doc = "< the value of this parameter"]
+    pub paramData: *mut ::std::os::raw::c_void,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct cec_adapter_stats {
+    pub tx_ack: ::std::os::raw::c_uint,
+    pub tx_nack: ::std::os::raw::c_uint,
+    pub tx_error: ::std::os::raw::c_uint,
+    pub rx_total: ::std::os::raw::c_uint,
+    pub rx_error: ::std::os::raw::c_uint,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct ICECCallbacks {
+    #[doc = " @brief Transfer a log message from libCEC to the client.\n @param cbparam             Callback parameter provided when the callbacks were set up\n @param message             The message to transfer."]
+    pub logMessage: ::std::option::Option<
+        unsafe extern "C" fn(cbparam: *mut ::std::os::raw::c_void, message: *const cec_log_message),
+    >,
+    #[doc = " @brief Transfer a keypress from libCEC to the client.\n @param cbparam             Callback parameter provided when the callbacks were set up\n @param key                 The keypress to transfer."]
+    pub keyPress: ::std::option::Option<
+        unsafe extern "C" fn(cbparam: *mut ::std::os::raw::c_void, key: *const cec_keypress),
+    >,
+    #[doc = " @brief Transfer a CEC command from libCEC to the client.\n @param cbparam             Callback parameter provided when the callbacks were set up\n @param command             The command to transfer."]
+    pub commandReceived: ::std::option::Option<
+        unsafe extern "C" fn(cbparam: *mut ::std::os::raw::c_void, command: *const cec_command),
+    >,
+    #[doc = " @brief Transfer a changed configuration from libCEC to the client\n @param cbparam             Callback parameter provided when the callbacks were set up\n @param configuration       The configuration to transfer"]
+    pub configurationChanged: ::std::option::Option<
+        unsafe extern "C" fn(
+            cbparam: *mut ::std::os::raw::c_void,
+            configuration: *const libcec_configuration,
+        ),
+    >,
+    #[doc = " @brief Transfer a libcec alert message from libCEC to the client\n @param cbparam             Callback parameter provided when the callbacks were set up\n @param alert               The alert type transfer.\n @param data                Misc. additional information."]
+    pub alert: ::std::option::Option<
+        unsafe extern "C" fn(
+            cbparam: *mut ::std::os::raw::c_void,
+            alert: libcec_alert,
+            param: libcec_parameter,
+        ),
+    >,
+    #[doc = " @brief Transfer a menu state change to the client.\n Transfer a menu state change to the client. If the command returns 1, then the change will be processed by\n the busdevice. If 0, then the state of the busdevice won't be changed, and will always be kept 'activated',\n @warning CEC does not allow the player to suppress the menu state change on the TV, so the menu on the TV will always be displayed, whatever the return value of this method is.\n so keypresses are always routed.\n @param cbparam             Callback parameter provided when the callbacks were set up\n @param state               The new value.\n\n @return 1 if libCEC should use this new value, 0 otherwise."]
+    pub menuStateChanged: ::std::option::Option<
+        unsafe extern "C" fn(
+            cbparam: *mut ::std::os::raw::c_void,
+            state: cec_menu_state,
+        ) -> ::std::os::raw::c_int,
+    >,
+    #[doc = " @brief Called when a source that's handled by this client is activated.\n @param cbparam             Callback parameter provided when the callbacks were set up\n @param logicalAddress      The address that was just activated.\n @param bActivated          1 if activated, 0 when deactivated."]
+    pub sourceActivated: ::std::option::Option<
+        unsafe extern "C" fn(
+            cbParam: *mut ::std::os::raw::c_void,
+            logicalAddress: cec_logical_address,
+            bActivated: u8,
+        ),
+    >,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct libcec_configuration {
+    #[doc = "< the version of the client that is connecting"]
+    pub clientVersion: u32,
+    #[doc = "< the device name to use on the CEC bus, name + 0 terminator"]
+    pub strDeviceName: [::std::os::raw::c_char; 13usize],
+    #[doc = "< the device type(s) to use on the CEC bus for libCEC"]
+    pub deviceTypes: cec_device_type_list,
+    #[doc = "< (read only) set to 1 by libCEC when the physical address was autodetected"]
+    pub bAutodetectAddress: u8,
+    #[doc = "< the physical address of the CEC adapter"]
+    pub iPhysicalAddress: u16,
+    #[doc = "< the logical address of the device to which the adapter is connected. only used when iPhysicalAddress = 0 or when the adapter doesn't support autodetection"]
+    pub baseDevice: cec_logical_address,
+    #[doc = "< the HDMI port to which the adapter is connected. only used when iPhysicalAddress = 0 or when the adapter doesn't support autodetection"]
+    pub iHDMIPort: u8,
+    #[doc = "< override the vendor ID of the TV. leave this untouched to autodetect"]
+    pub tvVendor: u32,
+    #[doc = "< list of devices to wake when initialising libCEC or when calling PowerOnDevices() without any parameter."]
+    pub wakeDevices: cec_logical_addresses,
+    #[doc = "< list of devices to power off when calling StandbyDevices() without any parameter."]
+    pub powerOffDevices: cec_logical_addresses,
+    #[doc = "< the version number of the server. read-only"]
+    pub serverVersion: u32,
+    #[doc = "< true to get the settings from the ROM (if set, and a v2 ROM is present), false to use these settings."]
+    pub bGetSettingsFromROM: u8,
+    #[doc = "< make libCEC the active source on the bus when starting the player application"]
+    pub bActivateSource: u8,
+    #[doc = "< put this PC in standby mode when the TV is switched off. only used when bShutdownOnStandby = 0"]
+    pub bPowerOffOnStandby: u8,
+    #[doc = "< the object to pass along with a call of the callback methods. NULL to ignore"]
+    pub callbackParam: *mut ::std::os::raw::c_void,
+    #[doc = "< the callback methods to use. set this to NULL when not using callbacks"]
+    pub callbacks: *mut ICECCallbacks,
+    #[doc = "< (read-only) the current logical addresses. added in 1.5.3"]
+    pub logicalAddresses: cec_logical_addresses,
+    #[doc = "< (read-only) the firmware version of the adapter. added in 1.6.0"]
+    pub iFirmwareVersion: u16,
+    #[doc = "< the menu language used by the client. 3 character ISO 639-2 country code. see http://http://www.loc.gov/standards/iso639-2/ added in 1.6.2"]
+    pub strDeviceLanguage: [::std::os::raw::c_char; 3usize],
+    #[doc = "< (read-only) the build date of the firmware, in seconds since epoch. if not available, this value will be set to 0. added in 1.6.2"]
+    pub iFirmwareBuildDate: u32,
+    #[doc = "< won't allocate a CCECClient when starting the connection when set (same as monitor mode). added in 1.6.3"]
+    pub bMonitorOnly: u8,
+    #[doc = "< CEC spec version to use by libCEC. defaults to v1.4. added in 1.8.0"]
+    pub cecVersion: cec_version,
+    #[doc = "< type of the CEC adapter that we're connected to. added in 1.8.2"]
+    pub adapterType: cec_adapter_type,
+    #[doc = "< key code that initiates combo keys. defaults to CEC_USER_CONTROL_CODE_F1_BLUE. CEC_USER_CONTROL_CODE_UNKNOWN to disable. added in 2.0.5"]
+    pub comboKey: cec_user_control_code,
+    #[doc = "< timeout until the combo key is sent as normal keypress"]
+    pub iComboKeyTimeoutMs: u32,
+    #[doc = "< rate at which buttons autorepeat. 0 means rely on CEC device"]
+    pub iButtonRepeatRateMs: u32,
+    #[doc = "< duration after last update until a button is considered released"]
+    pub iButtonReleaseDelayMs: u32,
+    #[doc = "< prevent double taps within this timeout. defaults to 200ms. added in 4.0.0"]
+    pub iDoubleTapTimeoutMs: u32,
+    #[doc = "< set to 1 to automatically waking an AVR when the source is activated. added in 4.0.0"]
+    pub bAutoWakeAVR: u8,
+}
+pub const CEC_LIB_VERSION_MAJOR: u32 = 4;
+pub const CEC_LIB_VERSION_MAJOR_STR: &[u8; 2] = b"4\0";
+pub const CEC_LIB_VERSION_MINOR: u32 = 0;
+pub const CEC_DEFAULT_PHYSICAL_ADDRESS: u32 = 4096;
+pub const CEC_DEFAULT_HDMI_PORT: u32 = 1;
+pub const CEC_DEFAULT_BASE_DEVICE: u32 = 0;
+pub const CEC_BUTTON_TIMEOUT: u32 = 500;
+pub const CEC_DOUBLE_TAP_TIMEOUT_MS: u32 = 200;
+pub const CEC_POWER_STATE_REFRESH_TIME: u32 = 30000;
+pub const CEC_FW_VERSION_UNKNOWN: u32 = 65535;
+pub const CEC_FW_BUILD_UNKNOWN: u32 = 0;
+pub const CEC_CONNECT_TRIES: u32 = 3;
+pub const CEC_PHYSICAL_ADDRESS_TV: u32 = 0;
+pub const CEC_MIN_PHYSICAL_ADDRESS: u32 = 4096;
+pub const CEC_MAX_PHYSICAL_ADDRESS: u32 = 65534;
+pub const CEC_INVALID_PHYSICAL_ADDRESS: u32 = 65535;
+pub const CEC_MIN_VENDORID: u32 = 1;
+pub const CEC_MAX_VENDORID: u32 = 16777214;
+pub const CEC_INVALID_VENDORID: u32 = 16777215;
+pub const CEC_MIN_HDMI_PORTNUMBER: u32 = 1;
+pub const CEC_MAX_HDMI_PORTNUMBER: u32 = 15;
+pub const CEC_HDMI_PORTNUMBER_NONE: u32 = 0;
+pub const CEC_DEFAULT_SETTING_ACTIVATE_SOURCE: u32 = 1;
+pub const CEC_DEFAULT_SETTING_POWER_OFF_SHUTDOWN: u32 = 1;
+pub const CEC_DEFAULT_SETTING_POWER_OFF_ON_STANDBY: u32 = 1;
+pub const CEC_DEFAULT_DEVICE_LANGUAGE: &[u8; 4] = b"eng\0";
+pub const CEC_DEFAULT_SETTING_AUTODETECT_ADDRESS: u32 = 0;
+pub const CEC_DEFAULT_SETTING_GET_SETTINGS_FROM_ROM: u32 = 0;
+pub const CEC_DEFAULT_SETTING_CEC_VERSION: u32 = 5;
+pub const CEC_DEFAULT_TRANSMIT_RETRY_WAIT: u32 = 500;
+pub const CEC_DEFAULT_TRANSMIT_TIMEOUT: u32 = 1000;
+pub const CEC_DEFAULT_TRANSMIT_WAIT: u32 = 1000;
+pub const CEC_DEFAULT_TRANSMIT_RETRIES: u32 = 1;
+pub const CEC_DEFAULT_CONNECT_TIMEOUT: u32 = 10000;
+pub const CEC_DEFAULT_CONNECT_RETRY_WAIT: u32 = 1000;
+pub const CEC_SERIAL_DEFAULT_BAUDRATE: u32 = 38400;
+pub const CEC_CLEAR_INPUT_DEFAULT_WAIT: u32 = 1000;
+pub const CEC_ACTIVE_SOURCE_SWITCH_RETRY_TIME_MS: u32 = 1000;
+pub const CEC_FORWARD_STANDBY_MIN_INTERVAL: u32 = 10000;
+pub const CEC_DEFAULT_COMBO_TIMEOUT_MS: u32 = 1000;
+pub const CEC_RPI_VIRTUAL_PATH: &[u8; 13] = b"Raspberry Pi\0";
+pub const CEC_RPI_VIRTUAL_COM: &[u8; 4] = b"RPI\0";
+pub const CEC_TDA995x_PATH: &[u8; 13] = b"/dev/hdmicec\0";
+pub const CEC_TDA995x_VIRTUAL_COM: &[u8; 6] = b"CuBox\0";
+pub const CEC_EXYNOS_PATH: &[u8; 9] = b"/dev/CEC\0";
+pub const CEC_EXYNOS_VIRTUAL_COM: &[u8; 7] = b"Exynos\0";
+pub const CEC_MAX_DATA_PACKET_SIZE: u32 = 64;
+pub const CEC_LINUX_PATH: &[u8; 10] = b"/dev/cec0\0";
+pub const CEC_LINUX_VIRTUAL_COM: &[u8; 6] = b"Linux\0";
+pub const CEC_AOCEC_PATH: &[u8; 11] = b"/dev/aocec\0";
+pub const CEC_AOCEC_VIRTUAL_COM: &[u8; 6] = b"AOCEC\0";
+pub const CEC_IMX_PATH: &[u8; 18] = b"/dev/mxc_hdmi_cec\0";
+pub const CEC_IMX_VIRTUAL_COM: &[u8; 5] = b"i.MX\0";
+pub const CEC_MIN_LIB_VERSION: u32 = 4;
+pub const CEC_FEATURE_CONFIGURABLE_COMBO_KEY: u32 = 1;
+pub const LIBCEC_OSD_NAME_SIZE: u32 = 13;
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of cec_log_message"][::std::mem::size_of::<cec_log_message>() - 16usize];
+    ["Alignment of cec_log_message"][::std::mem::align_of::<cec_log_message>() - 8usize];
+    ["Offset of field: cec_log_message::message"]
+        [::std::mem::offset_of!(cec_log_message, message) - 0usize];
+    ["Offset of field: cec_log_message::level"]
+        [::std::mem::offset_of!(cec_log_message, level) - 4usize];
+    ["Offset of field: cec_log_message::time"]
+        [::std::mem::offset_of!(cec_log_message, time) - 8usize];
+};
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of cec_keypress"][::std::mem::size_of::<cec_keypress>() - 8usize];
+    ["Alignment of cec_keypress"][::std::mem::align_of::<cec_keypress>() - 4usize];
+    ["Offset of field: cec_keypress::keycode"]
+        [::std::mem::offset_of!(cec_keypress, keycode) - 0usize];
+    ["Offset of field: cec_keypress::duration"]
+        [::std::mem::offset_of!(cec_keypress, duration) - 4usize];
+};
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of cec_adapter"][::std::mem::size_of::<cec_adapter>() - 2048usize];
+    ["Alignment of cec_adapter"][::std::mem::align_of::<cec_adapter>() - 1usize];
+    ["Offset of field: cec_adapter::path"][::std::mem::offset_of!(cec_adapter, path) - 0usize];
+    ["Offset of field: cec_adapter::comm"][::std::mem::offset_of!(cec_adapter, comm) - 1024usize];
+};
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of cec_adapter_descriptor"][::std::mem::size_of::<cec_adapter_descriptor>() - 2064usize];
+    ["Alignment of cec_adapter_descriptor"]
+        [::std::mem::align_of::<cec_adapter_descriptor>() - 4usize];
+    ["Offset of field: cec_adapter_descriptor::strComPath"]
+        [::std::mem::offset_of!(cec_adapter_descriptor, strComPath) - 0usize];
+    ["Offset of field: cec_adapter_descriptor::strComName"]
+        [::std::mem::offset_of!(cec_adapter_descriptor, strComName) - 1024usize];
+    ["Offset of field: cec_adapter_descriptor::iVendorId"]
+        [::std::mem::offset_of!(cec_adapter_descriptor, iVendorId) - 2048usize];
+    ["Offset of field: cec_adapter_descriptor::iProductId"]
+        [::std::mem::offset_of!(cec_adapter_descriptor, iProductId) - 2050usize];
+    ["Offset of field: cec_adapter_descriptor::iFirmwareVersion"]
+        [::std::mem::offset_of!(cec_adapter_descriptor, iFirmwareVersion) - 2052usize];
+    ["Offset of field: cec_adapter_descriptor::iPhysicalAddress"]
+        [::std::mem::offset_of!(cec_adapter_descriptor, iPhysicalAddress) - 2054usize];
+    ["Offset of field: cec_adapter_descriptor::iFirmwareBuildDate"]
+        [::std::mem::offset_of!(cec_adapter_descriptor, iFirmwareBuildDate) - 2056usize];
+    ["Offset of field: cec_adapter_descriptor::adapterType"]
+        [::std::mem::offset_of!(cec_adapter_descriptor, adapterType) - 2060usize];
+};
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of cec_datapacket"][::std::mem::size_of::<cec_datapacket>() - 65usize];
+    ["Alignment of cec_datapacket"][::std::mem::align_of::<cec_datapacket>() - 1usize];
+    ["Offset of field: cec_datapacket::data"]
+        [::std::mem::offset_of!(cec_datapacket, data) - 0usize];
+    ["Offset of field: cec_datapacket::size"]
+        [::std::mem::offset_of!(cec_datapacket, size) - 64usize];
+};
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of cec_command"][::std::mem::size_of::<cec_command>() - 88usize];
+    ["Alignment of cec_command"][::std::mem::align_of::<cec_command>() - 4usize];
+    ["Offset of field: cec_command::initiator"]
+        [::std::mem::offset_of!(cec_command, initiator) - 0usize];
+    ["Offset of field: cec_command::destination"]
+        [::std::mem::offset_of!(cec_command, destination) - 4usize];
+    ["Offset of field: cec_command::ack"][::std::mem::offset_of!(cec_command, ack) - 8usize];
+    ["Offset of field: cec_command::eom"][::std::mem::offset_of!(cec_command, eom) - 9usize];
+    ["Offset of field: cec_command::opcode"][::std::mem::offset_of!(cec_command, opcode) - 12usize];
+    ["Offset of field: cec_command::parameters"]
+        [::std::mem::offset_of!(cec_command, parameters) - 16usize];
+    ["Offset of field: cec_command::opcode_set"]
+        [::std::mem::offset_of!(cec_command, opcode_set) - 81usize];
+    ["Offset of field: cec_command::transmit_timeout"]
+        [::std::mem::offset_of!(cec_command, transmit_timeout) - 84usize];
+};
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of cec_device_type_list"][::std::mem::size_of::<cec_device_type_list>() - 20usize];
+    ["Alignment of cec_device_type_list"][::std::mem::align_of::<cec_device_type_list>() - 4usize];
+    ["Offset of field: cec_device_type_list::types"]
+        [::std::mem::offset_of!(cec_device_type_list, types) - 0usize];
+};
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of cec_logical_addresses"][::std::mem::size_of::<cec_logical_addresses>() - 68usize];
+    ["Alignment of cec_logical_addresses"]
+        [::std::mem::align_of::<cec_logical_addresses>() - 4usize];
+    ["Offset of field: cec_logical_addresses::primary"]
+        [::std::mem::offset_of!(cec_logical_addresses, primary) - 0usize];
+    ["Offset of field: cec_logical_addresses::addresses"]
+        [::std::mem::offset_of!(cec_logical_addresses, addresses) - 4usize];
+};
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of libcec_parameter"][::std::mem::size_of::<libcec_parameter>() - 8usize];
+    ["Alignment of libcec_parameter"][::std::mem::align_of::<libcec_parameter>() - 4usize];
+    ["Offset of field: libcec_parameter::paramType"]
+        [::std::mem::offset_of!(libcec_parameter, paramType) - 0usize];
+    ["Offset of field: libcec_parameter::paramData"]
+        [::std::mem::offset_of!(libcec_parameter, paramData) - 4usize];
+};
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of cec_adapter_stats"][::std::mem::size_of::<cec_adapter_stats>() - 20usize];
+    ["Alignment of cec_adapter_stats"][::std::mem::align_of::<cec_adapter_stats>() - 4usize];
+    ["Offset of field: cec_adapter_stats::tx_ack"]
+        [::std::mem::offset_of!(cec_adapter_stats, tx_ack) - 0usize];
+    ["Offset of field: cec_adapter_stats::tx_nack"]
+        [::std::mem::offset_of!(cec_adapter_stats, tx_nack) - 4usize];
+    ["Offset of field: cec_adapter_stats::tx_error"]
+        [::std::mem::offset_of!(cec_adapter_stats, tx_error) - 8usize];
+    ["Offset of field: cec_adapter_stats::rx_total"]
+        [::std::mem::offset_of!(cec_adapter_stats, rx_total) - 12usize];
+    ["Offset of field: cec_adapter_stats::rx_error"]
+        [::std::mem::offset_of!(cec_adapter_stats, rx_error) - 16usize];
+};
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of ICECCallbacks"][::std::mem::size_of::<ICECCallbacks>() - 28usize];
+    ["Alignment of ICECCallbacks"][::std::mem::align_of::<ICECCallbacks>() - 4usize];
+    ["Offset of field: ICECCallbacks::logMessage"]
+        [::std::mem::offset_of!(ICECCallbacks, logMessage) - 0usize];
+    ["Offset of field: ICECCallbacks::keyPress"]
+        [::std::mem::offset_of!(ICECCallbacks, keyPress) - 4usize];
+    ["Offset of field: ICECCallbacks::commandReceived"]
+        [::std::mem::offset_of!(ICECCallbacks, commandReceived) - 8usize];
+    ["Offset of field: ICECCallbacks::configurationChanged"]
+        [::std::mem::offset_of!(ICECCallbacks, configurationChanged) - 12usize];
+    ["Offset of field: ICECCallbacks::alert"]
+        [::std::mem::offset_of!(ICECCallbacks, alert) - 16usize];
+    ["Offset of field: ICECCallbacks::menuStateChanged"]
+        [::std::mem::offset_of!(ICECCallbacks, menuStateChanged) - 20usize];
+    ["Offset of field: ICECCallbacks::sourceActivated"]
+        [::std::mem::offset_of!(ICECCallbacks, sourceActivated) - 24usize];
+};
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of libcec_configuration"][::std::mem::size_of::<libcec_configuration>() - 324usize];
+    ["Alignment of libcec_configuration"][::std::mem::align_of::<libcec_configuration>() - 4usize];
+    ["Offset of field: libcec_configuration::clientVersion"]
+        [::std::mem::offset_of!(libcec_configuration, clientVersion) - 0usize];
+    ["Offset of field: libcec_configuration::strDeviceName"]
+        [::std::mem::offset_of!(libcec_configuration, strDeviceName) - 4usize];
+    ["Offset of field: libcec_configuration::deviceTypes"]
+        [::std::mem::offset_of!(libcec_configuration, deviceTypes) - 20usize];
+    ["Offset of field: libcec_configuration::bAutodetectAddress"]
+        [::std::mem::offset_of!(libcec_configuration, bAutodetectAddress) - 40usize];
+    ["Offset of field: libcec_configuration::iPhysicalAddress"]
+        [::std::mem::offset_of!(libcec_configuration, iPhysicalAddress) - 42usize];
+    ["Offset of field: libcec_configuration::baseDevice"]
+        [::std::mem::offset_of!(libcec_configuration, baseDevice) - 44usize];
+    ["Offset of field: libcec_configuration::iHDMIPort"]
+        [::std::mem::offset_of!(libcec_configuration, iHDMIPort) - 48usize];
+    ["Offset of field: libcec_configuration::tvVendor"]
+        [::std::mem::offset_of!(libcec_configuration, tvVendor) - 52usize];
+    ["Offset of field: libcec_configuration::wakeDevices"]
+        [::std::mem::offset_of!(libcec_configuration, wakeDevices) - 56usize];
+    ["Offset of field: libcec_configuration::powerOffDevices"]
+        [::std::mem::offset_of!(libcec_configuration, powerOffDevices) - 124usize];
+    ["Offset of field: libcec_configuration::serverVersion"]
+        [::std::mem::offset_of!(libcec_configuration, serverVersion) - 192usize];
+    ["Offset of field: libcec_configuration::bGetSettingsFromROM"]
+        [::std::mem::offset_of!(libcec_configuration, bGetSettingsFromROM) - 196usize];
+    ["Offset of field: libcec_configuration::bActivateSource"]
+        [::std::mem::offset_of!(libcec_configuration, bActivateSource) - 197usize];
+    ["Offset of field: libcec_configuration::bPowerOffOnStandby"]
+        [::std::mem::offset_of!(libcec_configuration, bPowerOffOnStandby) - 198usize];
+    ["Offset of field: libcec_configuration::callbackParam"]
+        [::std::mem::offset_of!(libcec_configuration, callbackParam) - 200usize];
+    ["Offset of field: libcec_configuration::callbacks"]
+        [::std::mem::offset_of!(libcec_configuration, callbacks) - 204usize];
+    ["Offset of field: libcec_configuration::logicalAddresses"]
+        [::std::mem::offset_of!(libcec_configuration, logicalAddresses) - 208usize];
+    ["Offset of field: libcec_configuration::iFirmwareVersion"]
+        [::std::mem::offset_of!(libcec_configuration, iFirmwareVersion) - 276usize];
+    ["Offset of field: libcec_configuration::strDeviceLanguage"]
+        [::std::mem::offset_of!(libcec_configuration, strDeviceLanguage) - 278usize];
+    ["Offset of field: libcec_configuration::iFirmwareBuildDate"]
+        [::std::mem::offset_of!(libcec_configuration, iFirmwareBuildDate) - 284usize];
+    ["Offset of field: libcec_configuration::bMonitorOnly"]
+        [::std::mem::offset_of!(libcec_configuration, bMonitorOnly) - 288usize];
+    ["Offset of field: libcec_configuration::cecVersion"]
+        [::std::mem::offset_of!(libcec_configuration, cecVersion) - 292usize];
+    ["Offset of field: libcec_configuration::adapterType"]
+        [::std::mem::offset_of!(libcec_configuration, adapterType) - 296usize];
+    ["Offset of field: libcec_configuration::comboKey"]
+        [::std::mem::offset_of!(libcec_configuration, comboKey) - 300usize];
+    ["Offset of field: libcec_configuration::iComboKeyTimeoutMs"]
+        [::std::mem::offset_of!(libcec_configuration, iComboKeyTimeoutMs) - 304usize];
+    ["Offset of field: libcec_configuration::iButtonRepeatRateMs"]
+        [::std::mem::offset_of!(libcec_configuration, iButtonRepeatRateMs) - 308usize];
+    ["Offset of field: libcec_configuration::iButtonReleaseDelayMs"]
+        [::std::mem::offset_of!(libcec_configuration, iButtonReleaseDelayMs) - 312usize];
+    ["Offset of field: libcec_configuration::iDoubleTapTimeoutMs"]
+        [::std::mem::offset_of!(libcec_configuration, iDoubleTapTimeoutMs) - 316usize];
+    ["Offset of field: libcec_configuration::bAutoWakeAVR"]
+        [::std::mem::offset_of!(libcec_configuration, bAutoWakeAVR) - 320usize];
+};
+impl cec_abort_reason {
+    pub const UNRECOGNIZED_OPCODE: cec_abort_reason = cec_abort_reason(0);
+}
+impl cec_abort_reason {
+    pub const NOT_IN_CORRECT_MODE_TO_RESPOND: cec_abort_reason = cec_abort_reason(1);
+}
+impl cec_abort_reason {
+    pub const CANNOT_PROVIDE_SOURCE: cec_abort_reason = cec_abort_reason(2);
+}
+impl cec_abort_reason {
+    pub const INVALID_OPERAND: cec_abort_reason = cec_abort_reason(3);
+}
+impl cec_abort_reason {
+    pub const REFUSED: cec_abort_reason = cec_abort_reason(4);
+}
+impl cec_analogue_broadcast_type {
+    pub const CABLE: cec_analogue_broadcast_type = cec_analogue_broadcast_type(0);
+}
+impl cec_analogue_broadcast_type {
+    pub const SATELLITE: cec_analogue_broadcast_type = cec_analogue_broadcast_type(1);
+}
+impl cec_analogue_broadcast_type {
+    pub const TERRESTIAL: cec_analogue_broadcast_type = cec_analogue_broadcast_type(2);
+}
+impl cec_audio_rate {
+    pub const RATE_CONTROL_OFF: cec_audio_rate = cec_audio_rate(0);
+}
+impl cec_audio_rate {
+    pub const STANDARD_RATE_100: cec_audio_rate = cec_audio_rate(1);
+}
+impl cec_audio_rate {
+    pub const FAST_RATE_MAX_101: cec_audio_rate = cec_audio_rate(2);
+}
+impl cec_audio_rate {
+    pub const SLOW_RATE_MIN_99: cec_audio_rate = cec_audio_rate(3);
+}
+impl cec_audio_rate {
+    pub const STANDARD_RATE_100_0: cec_audio_rate = cec_audio_rate(4);
+}
+impl cec_audio_rate {
+    pub const FAST_RATE_MAX_100_1: cec_audio_rate = cec_audio_rate(5);
+}
+impl cec_audio_rate {
+    pub const SLOW_RATE_MIN_99_9: cec_audio_rate = cec_audio_rate(6);
+}
+impl cec_boolean {
+    pub const CEC_FALSE: cec_boolean = cec_boolean(0);
+}
+impl cec_boolean {
+    pub const CEC_TRUE: cec_boolean = cec_boolean(1);
+}
+impl cec_version {
+    pub const UNKNOWN: cec_version = cec_version(0);
+}
+impl cec_version {
+    pub const _1_2: cec_version = cec_version(1);
+}
+impl cec_version {
+    pub const _1_2A: cec_version = cec_version(2);
+}
+impl cec_version {
+    pub const _1_3: cec_version = cec_version(3);
+}
+impl cec_version {
+    pub const _1_3A: cec_version = cec_version(4);
+}
+impl cec_version {
+    pub const _1_4: cec_version = cec_version(5);
+}
+impl cec_deck_control_mode {
+    pub const SKIP_FORWARD_WIND: cec_deck_control_mode = cec_deck_control_mode(1);
+}
+impl cec_deck_control_mode {
+    pub const SKIP_REVERSE_REWIND: cec_deck_control_mode = cec_deck_control_mode(2);
+}
+impl cec_deck_control_mode {
+    pub const STOP: cec_deck_control_mode = cec_deck_control_mode(3);
+}
+impl cec_deck_control_mode {
+    pub const EJECT: cec_deck_control_mode = cec_deck_control_mode(4);
+}
+impl cec_deck_info {
+    pub const PLAY: cec_deck_info = cec_deck_info(17);
+}
+impl cec_deck_info {
+    pub const RECORD: cec_deck_info = cec_deck_info(18);
+}
+impl cec_deck_info {
+    pub const PLAY_REVERSE: cec_deck_info = cec_deck_info(19);
+}
+impl cec_deck_info {
+    pub const STILL: cec_deck_info = cec_deck_info(20);
+}
+impl cec_deck_info {
+    pub const SLOW: cec_deck_info = cec_deck_info(21);
+}
+impl cec_deck_info {
+    pub const SLOW_REVERSE: cec_deck_info = cec_deck_info(22);
+}
+impl cec_deck_info {
+    pub const FAST_FORWARD: cec_deck_info = cec_deck_info(23);
+}
+impl cec_deck_info {
+    pub const FAST_REVERSE: cec_deck_info = cec_deck_info(24);
+}
+impl cec_deck_info {
+    pub const NO_MEDIA: cec_deck_info = cec_deck_info(25);
+}
+impl cec_deck_info {
+    pub const STOP: cec_deck_info = cec_deck_info(26);
+}
+impl cec_deck_info {
+    pub const SKIP_FORWARD_WIND: cec_deck_info = cec_deck_info(27);
+}
+impl cec_deck_info {
+    pub const SKIP_REVERSE_REWIND: cec_deck_info = cec_deck_info(28);
+}
+impl cec_deck_info {
+    pub const INDEX_SEARCH_FORWARD: cec_deck_info = cec_deck_info(29);
+}
+impl cec_deck_info {
+    pub const INDEX_SEARCH_REVERSE: cec_deck_info = cec_deck_info(30);
+}
+impl cec_deck_info {
+    pub const OTHER_STATUS: cec_deck_info = cec_deck_info(31);
+}
+impl cec_deck_info {
+    pub const OTHER_STATUS_LG: cec_deck_info = cec_deck_info(32);
+}
+impl cec_device_type {
+    pub const TV: cec_device_type = cec_device_type(0);
+}
+impl cec_device_type {
+    pub const RECORDING_DEVICE: cec_device_type = cec_device_type(1);
+}
+impl cec_device_type {
+    pub const RESERVED: cec_device_type = cec_device_type(2);
+}
+impl cec_device_type {
+    pub const TUNER: cec_device_type = cec_device_type(3);
+}
+impl cec_device_type {
+    pub const PLAYBACK_DEVICE: cec_device_type = cec_device_type(4);
+}
+impl cec_device_type {
+    pub const AUDIO_SYSTEM: cec_device_type = cec_device_type(5);
+}
+impl cec_display_control {
+    pub const DISPLAY_FOR_DEFAULT_TIME: cec_display_control = cec_display_control(0);
+}
+impl cec_display_control {
+    pub const DISPLAY_UNTIL_CLEARED: cec_display_control = cec_display_control(64);
+}
+impl cec_display_control {
+    pub const CLEAR_PREVIOUS_MESSAGE: cec_display_control = cec_display_control(128);
+}
+impl cec_display_control {
+    pub const RESERVED_FOR_FUTURE_USE: cec_display_control = cec_display_control(192);
+}
+impl cec_external_source_specifier {
+    pub const EXTERNAL_PLUG: cec_external_source_specifier = cec_external_source_specifier(4);
+}
+impl cec_external_source_specifier {
+    pub const EXTERNAL_PHYSICAL_ADDRESS: cec_external_source_specifier =
+        cec_external_source_specifier(5);
+}
+impl cec_menu_request_type {
+    pub const ACTIVATE: cec_menu_request_type = cec_menu_request_type(0);
+}
+impl cec_menu_request_type {
+    pub const DEACTIVATE: cec_menu_request_type = cec_menu_request_type(1);
+}
+impl cec_menu_request_type {
+    pub const QUERY: cec_menu_request_type = cec_menu_request_type(2);
+}
+impl cec_menu_state {
+    pub const ACTIVATED: cec_menu_state = cec_menu_state(0);
+}
+impl cec_menu_state {
+    pub const DEACTIVATED: cec_menu_state = cec_menu_state(1);
+}
+impl cec_play_mode {
+    pub const PLAY_FORWARD: cec_play_mode = cec_play_mode(36);
+}
+impl cec_play_mode {
+    pub const PLAY_REVERSE: cec_play_mode = cec_play_mode(32);
+}
+impl cec_play_mode {
+    pub const PLAY_STILL: cec_play_mode = cec_play_mode(37);
+}
+impl cec_play_mode {
+    pub const FAST_FORWARD_MIN_SPEED: cec_play_mode = cec_play_mode(5);
+}
+impl cec_play_mode {
+    pub const FAST_FORWARD_MEDIUM_SPEED: cec_play_mode = cec_play_mode(6);
+}
+impl cec_play_mode {
+    pub const FAST_FORWARD_MAX_SPEED: cec_play_mode = cec_play_mode(7);
+}
+impl cec_play_mode {
+    pub const FAST_REVERSE_MIN_SPEED: cec_play_mode = cec_play_mode(9);
+}
+impl cec_play_mode {
+    pub const FAST_REVERSE_MEDIUM_SPEED: cec_play_mode = cec_play_mode(10);
+}
+impl cec_play_mode {
+    pub const FAST_REVERSE_MAX_SPEED: cec_play_mode = cec_play_mode(11);
+}
+impl cec_play_mode {
+    pub const SLOW_FORWARD_MIN_SPEED: cec_play_mode = cec_play_mode(21);
+}
+impl cec_play_mode {
+    pub const SLOW_FORWARD_MEDIUM_SPEED: cec_play_mode = cec_play_mode(22);
+}
+impl cec_play_mode {
+    pub const SLOW_FORWARD_MAX_SPEED: cec_play_mode = cec_play_mode(23);
+}
+impl cec_play_mode {
+    pub const SLOW_REVERSE_MIN_SPEED: cec_play_mode = cec_play_mode(25);
+}
+impl cec_play_mode {
+    pub const SLOW_REVERSE_MEDIUM_SPEED: cec_play_mode = cec_play_mode(26);
+}
+impl cec_play_mode {
+    pub const SLOW_REVERSE_MAX_SPEED: cec_play_mode = cec_play_mode(27);
+}
+impl cec_power_status {
+    pub const ON: cec_power_status = cec_power_status(0);
+}
+impl cec_power_status {
+    pub const STANDBY: cec_power_status = cec_power_status(1);
+}
+impl cec_power_status {
+    pub const IN_TRANSITION_STANDBY_TO_ON: cec_power_status = cec_power_status(2);
+}
+impl cec_power_status {
+    pub const IN_TRANSITION_ON_TO_STANDBY: cec_power_status = cec_power_status(3);
+}
+impl cec_power_status {
+    pub const UNKNOWN: cec_power_status = cec_power_status(153);
+}
+impl cec_record_source_type {
+    pub const OWN_SOURCE: cec_record_source_type = cec_record_source_type(1);
+}
+impl cec_record_source_type {
+    pub const DIGITAL_SERVICE: cec_record_source_type = cec_record_source_type(2);
+}
+impl cec_record_source_type {
+    pub const ANALOGUE_SERVICE: cec_record_source_type = cec_record_source_type(3);
+}
+impl cec_record_source_type {
+    pub const EXTERNAL_PLUS: cec_record_source_type = cec_record_source_type(4);
+}
+impl cec_record_source_type {
+    pub const EXTERNAL_PHYSICAL_ADDRESS: cec_record_source_type = cec_record_source_type(5);
+}
+impl cec_record_status_info {
+    pub const RECORDING_CURRENTLY_SELECTED_SOURCE: cec_record_status_info =
+        cec_record_status_info(1);
+}
+impl cec_record_status_info {
+    pub const RECORDING_DIGITAL_SERVICE: cec_record_status_info = cec_record_status_info(2);
+}
+impl cec_record_status_info {
+    pub const RECORDING_ANALOGUE_SERVICE: cec_record_status_info = cec_record_status_info(3);
+}
+impl cec_record_status_info {
+    pub const RECORDING_EXTERNAL_INPUT: cec_record_status_info = cec_record_status_info(4);
+}
+impl cec_record_status_info {
+    pub const NO_RECORDING_UNABLE_TO_RECORD_DIGITAL_SERVICE: cec_record_status_info =
+        cec_record_status_info(5);
+}
+impl cec_record_status_info {
+    pub const NO_RECORDING_UNABLE_TO_RECORD_ANALOGUE_SERVICE: cec_record_status_info =
+        cec_record_status_info(6);
+}
+impl cec_record_status_info {
+    pub const NO_RECORDING_UNABLE_TO_SELECT_REQUIRED_SERVICE: cec_record_status_info =
+        cec_record_status_info(7);
+}
+impl cec_record_status_info {
+    pub const NO_RECORDING_INVALID_EXTERNAL_PLUG_NUMBER: cec_record_status_info =
+        cec_record_status_info(9);
+}
+impl cec_record_status_info {
+    pub const NO_RECORDING_INVALID_EXTERNAL_ADDRESS: cec_record_status_info =
+        cec_record_status_info(10);
+}
+impl cec_record_status_info {
+    pub const NO_RECORDING_CA_SYSTEM_NOT_SUPPORTED: cec_record_status_info =
+        cec_record_status_info(11);
+}
+impl cec_record_status_info {
+    pub const NO_RECORDING_NO_OR_INSUFFICIENT_ENTITLEMENTS: cec_record_status_info =
+        cec_record_status_info(12);
+}
+impl cec_record_status_info {
+    pub const NO_RECORDING_NOT_ALLOWED_TO_COPY_SOURCE: cec_record_status_info =
+        cec_record_status_info(13);
+}
+impl cec_record_status_info {
+    pub const NO_RECORDING_NO_FURTHER_COPIES_ALLOWED: cec_record_status_info =
+        cec_record_status_info(14);
+}
+impl cec_record_status_info {
+    pub const NO_RECORDING_NO_MEDIA: cec_record_status_info = cec_record_status_info(16);
+}
+impl cec_record_status_info {
+    pub const NO_RECORDING_PLAYING: cec_record_status_info = cec_record_status_info(17);
+}
+impl cec_record_status_info {
+    pub const NO_RECORDING_ALREADY_RECORDING: cec_record_status_info = cec_record_status_info(18);
+}
+impl cec_record_status_info {
+    pub const NO_RECORDING_MEDIA_PROTECTED: cec_record_status_info = cec_record_status_info(19);
+}
+impl cec_record_status_info {
+    pub const NO_RECORDING_NO_SOURCE_SIGNAL: cec_record_status_info = cec_record_status_info(20);
+}
+impl cec_record_status_info {
+    pub const NO_RECORDING_MEDIA_PROBLEM: cec_record_status_info = cec_record_status_info(21);
+}
+impl cec_record_status_info {
+    pub const NO_RECORDING_NOT_ENOUGH_SPACE_AVAILABLE: cec_record_status_info =
+        cec_record_status_info(22);
+}
+impl cec_record_status_info {
+    pub const NO_RECORDING_PARENTAL_LOCK_ON: cec_record_status_info = cec_record_status_info(23);
+}
+impl cec_record_status_info {
+    pub const RECORDING_TERMINATED_NORMALLY: cec_record_status_info = cec_record_status_info(26);
+}
+impl cec_record_status_info {
+    pub const RECORDING_HAS_ALREADY_TERMINATED: cec_record_status_info = cec_record_status_info(27);
+}
+impl cec_record_status_info {
+    pub const NO_RECORDING_OTHER_REASON: cec_record_status_info = cec_record_status_info(31);
+}
+impl cec_recording_sequence {
+    pub const SUNDAY: cec_recording_sequence = cec_recording_sequence(1);
+}
+impl cec_recording_sequence {
+    pub const MONDAY: cec_recording_sequence = cec_recording_sequence(2);
+}
+impl cec_recording_sequence {
+    pub const TUESDAY: cec_recording_sequence = cec_recording_sequence(4);
+}
+impl cec_recording_sequence {
+    pub const WEDNESDAY: cec_recording_sequence = cec_recording_sequence(8);
+}
+impl cec_recording_sequence {
+    pub const THURSDAY: cec_recording_sequence = cec_recording_sequence(16);
+}
+impl cec_recording_sequence {
+    pub const FRIDAY: cec_recording_sequence = cec_recording_sequence(32);
+}
+impl cec_recording_sequence {
+    pub const SATURDAY: cec_recording_sequence = cec_recording_sequence(64);
+}
+impl cec_recording_sequence {
+    pub const ONCE_ONLY: cec_recording_sequence = cec_recording_sequence(0);
+}
+impl cec_status_request {
+    pub const ON: cec_status_request = cec_status_request(1);
+}
+impl cec_status_request {
+    pub const OFF: cec_status_request = cec_status_request(2);
+}
+impl cec_status_request {
+    pub const ONCE: cec_status_request = cec_status_request(3);
+}
+impl cec_system_audio_status {
+    pub const OFF: cec_system_audio_status = cec_system_audio_status(0);
+}
+impl cec_system_audio_status {
+    pub const ON: cec_system_audio_status = cec_system_audio_status(1);
+}
+impl cec_timer_cleared_status_data {
+    pub const TIMER_NOT_CLEARED_RECORDING: cec_timer_cleared_status_data =
+        cec_timer_cleared_status_data(0);
+}
+impl cec_timer_cleared_status_data {
+    pub const TIMER_NOT_CLEARED_NO_MATCHING: cec_timer_cleared_status_data =
+        cec_timer_cleared_status_data(1);
+}
+impl cec_timer_cleared_status_data {
+    pub const TIMER_NOT_CLEARED_NO_INF0_AVAILABLE: cec_timer_cleared_status_data =
+        cec_timer_cleared_status_data(2);
+}
+impl cec_timer_cleared_status_data {
+    pub const TIMER_CLEARED: cec_timer_cleared_status_data = cec_timer_cleared_status_data(128);
+}
+impl cec_timer_overlap_warning {
+    pub const NO_OVERLAP: cec_timer_overlap_warning = cec_timer_overlap_warning(0);
+}
+impl cec_timer_overlap_warning {
+    pub const TIMER_BLOCKS_OVERLAP: cec_timer_overlap_warning = cec_timer_overlap_warning(1);
+}
+impl cec_media_info {
+    pub const MEDIA_PRESENT_AND_NOT_PROTECTED: cec_media_info = cec_media_info(0);
+}
+impl cec_media_info {
+    pub const MEDIA_PRESENT_BUT_PROTECTED: cec_media_info = cec_media_info(1);
+}
+impl cec_media_info {
+    pub const MEDIA_NOT_PRESENT: cec_media_info = cec_media_info(2);
+}
+impl cec_media_info {
+    pub const FUTURE_USE: cec_media_info = cec_media_info(3);
+}
+impl cec_programmed_indicator {
+    pub const NOT_PROGRAMMED: cec_programmed_indicator = cec_programmed_indicator(0);
+}
+impl cec_programmed_indicator {
+    pub const PROGRAMMED: cec_programmed_indicator = cec_programmed_indicator(1);
+}
+impl cec_programmed_info {
+    pub const FUTURE_USE: cec_programmed_info = cec_programmed_info(0);
+}
+impl cec_programmed_info {
+    pub const ENOUGH_SPACE_AVAILABLE_FOR_RECORDING: cec_programmed_info = cec_programmed_info(8);
+}
+impl cec_programmed_info {
+    pub const NOT_ENOUGH_SPACE_AVAILABLE_FOR_RECORDING: cec_programmed_info =
+        cec_programmed_info(9);
+}
+impl cec_programmed_info {
+    pub const MAY_NOT_BE_ENOUGH_SPACE_AVAILABLE: cec_programmed_info = cec_programmed_info(11);
+}
+impl cec_programmed_info {
+    pub const NO_MEDIA_INFO_AVAILABLE: cec_programmed_info = cec_programmed_info(10);
+}
+impl cec_not_programmed_error_info {
+    pub const FUTURE_USE: cec_not_programmed_error_info = cec_not_programmed_error_info(0);
+}
+impl cec_not_programmed_error_info {
+    pub const NO_FREE_TIMER_AVAILABLE: cec_not_programmed_error_info =
+        cec_not_programmed_error_info(1);
+}
+impl cec_not_programmed_error_info {
+    pub const DATE_OUT_OF_RANGE: cec_not_programmed_error_info = cec_not_programmed_error_info(2);
+}
+impl cec_not_programmed_error_info {
+    pub const RECORDING_SEQUENCE_ERROR: cec_not_programmed_error_info =
+        cec_not_programmed_error_info(3);
+}
+impl cec_not_programmed_error_info {
+    pub const INVALID_EXTERNAL_PLUG_NUMBER: cec_not_programmed_error_info =
+        cec_not_programmed_error_info(4);
+}
+impl cec_not_programmed_error_info {
+    pub const INVALID_EXTERNAL_PHYSICAL_ADDRESS: cec_not_programmed_error_info =
+        cec_not_programmed_error_info(5);
+}
+impl cec_not_programmed_error_info {
+    pub const CA_SYSTEM_NOT_SUPPORTED: cec_not_programmed_error_info =
+        cec_not_programmed_error_info(6);
+}
+impl cec_not_programmed_error_info {
+    pub const NO_OR_INSUFFICIENT_CA_ENTITLEMENTS: cec_not_programmed_error_info =
+        cec_not_programmed_error_info(7);
+}
+impl cec_not_programmed_error_info {
+    pub const DOES_NOT_SUPPORT_RESOLUTION: cec_not_programmed_error_info =
+        cec_not_programmed_error_info(8);
+}
+impl cec_not_programmed_error_info {
+    pub const PARENTAL_LOCK_ON: cec_not_programmed_error_info = cec_not_programmed_error_info(9);
+}
+impl cec_not_programmed_error_info {
+    pub const CLOCK_FAILURE: cec_not_programmed_error_info = cec_not_programmed_error_info(10);
+}
+impl cec_not_programmed_error_info {
+    pub const RESERVED_FOR_FUTURE_USE_START: cec_not_programmed_error_info =
+        cec_not_programmed_error_info(11);
+}
+impl cec_not_programmed_error_info {
+    pub const RESERVED_FOR_FUTURE_USE_END: cec_not_programmed_error_info =
+        cec_not_programmed_error_info(13);
+}
+impl cec_not_programmed_error_info {
+    pub const DUPLICATE_ALREADY_PROGRAMMED: cec_not_programmed_error_info =
+        cec_not_programmed_error_info(14);
+}
+impl cec_recording_flag {
+    pub const NOT_BEING_USED_FOR_RECORDING: cec_recording_flag = cec_recording_flag(0);
+}
+impl cec_recording_flag {
+    pub const BEING_USED_FOR_RECORDING: cec_recording_flag = cec_recording_flag(1);
+}
+impl cec_tuner_display_info {
+    pub const DISPLAYING_DIGITAL_TUNER: cec_tuner_display_info = cec_tuner_display_info(0);
+}
+impl cec_tuner_display_info {
+    pub const NOT_DISPLAYING_TUNER: cec_tuner_display_info = cec_tuner_display_info(1);
+}
+impl cec_tuner_display_info {
+    pub const DISPLAYING_ANALOGUE_TUNER: cec_tuner_display_info = cec_tuner_display_info(2);
+}
+impl cec_broadcast_system {
+    pub const PAL_B_G: cec_broadcast_system = cec_broadcast_system(0);
+}
+impl cec_broadcast_system {
+    pub const SECAM_L1: cec_broadcast_system = cec_broadcast_system(1);
+}
+impl cec_broadcast_system {
+    pub const PAL_M: cec_broadcast_system = cec_broadcast_system(2);
+}
+impl cec_broadcast_system {
+    pub const NTSC_M: cec_broadcast_system = cec_broadcast_system(3);
+}
+impl cec_broadcast_system {
+    pub const PAL_I: cec_broadcast_system = cec_broadcast_system(4);
+}
+impl cec_broadcast_system {
+    pub const SECAM_DK: cec_broadcast_system = cec_broadcast_system(5);
+}
+impl cec_broadcast_system {
+    pub const SECAM_B_G: cec_broadcast_system = cec_broadcast_system(6);
+}
+impl cec_broadcast_system {
+    pub const SECAM_L2: cec_broadcast_system = cec_broadcast_system(7);
+}
+impl cec_broadcast_system {
+    pub const PAL_DK: cec_broadcast_system = cec_broadcast_system(8);
+}
+impl cec_broadcast_system {
+    pub const OTHER_SYSTEM: cec_broadcast_system = cec_broadcast_system(30);
+}
+impl cec_user_control_code {
+    pub const SELECT: cec_user_control_code = cec_user_control_code(0);
+}
+impl cec_user_control_code {
+    pub const UP: cec_user_control_code = cec_user_control_code(1);
+}
+impl cec_user_control_code {
+    pub const DOWN: cec_user_control_code = cec_user_control_code(2);
+}
+impl cec_user_control_code {
+    pub const LEFT: cec_user_control_code = cec_user_control_code(3);
+}
+impl cec_user_control_code {
+    pub const RIGHT: cec_user_control_code = cec_user_control_code(4);
+}
+impl cec_user_control_code {
+    pub const RIGHT_UP: cec_user_control_code = cec_user_control_code(5);
+}
+impl cec_user_control_code {
+    pub const RIGHT_DOWN: cec_user_control_code = cec_user_control_code(6);
+}
+impl cec_user_control_code {
+    pub const LEFT_UP: cec_user_control_code = cec_user_control_code(7);
+}
+impl cec_user_control_code {
+    pub const LEFT_DOWN: cec_user_control_code = cec_user_control_code(8);
+}
+impl cec_user_control_code {
+    pub const ROOT_MENU: cec_user_control_code = cec_user_control_code(9);
+}
+impl cec_user_control_code {
+    pub const SETUP_MENU: cec_user_control_code = cec_user_control_code(10);
+}
+impl cec_user_control_code {
+    pub const CONTENTS_MENU: cec_user_control_code = cec_user_control_code(11);
+}
+impl cec_user_control_code {
+    pub const FAVORITE_MENU: cec_user_control_code = cec_user_control_code(12);
+}
+impl cec_user_control_code {
+    pub const EXIT: cec_user_control_code = cec_user_control_code(13);
+}
+impl cec_user_control_code {
+    pub const TOP_MENU: cec_user_control_code = cec_user_control_code(16);
+}
+impl cec_user_control_code {
+    pub const DVD_MENU: cec_user_control_code = cec_user_control_code(17);
+}
+impl cec_user_control_code {
+    pub const NUMBER_ENTRY_MODE: cec_user_control_code = cec_user_control_code(29);
+}
+impl cec_user_control_code {
+    pub const NUMBER11: cec_user_control_code = cec_user_control_code(30);
+}
+impl cec_user_control_code {
+    pub const NUMBER12: cec_user_control_code = cec_user_control_code(31);
+}
+impl cec_user_control_code {
+    pub const NUMBER0: cec_user_control_code = cec_user_control_code(32);
+}
+impl cec_user_control_code {
+    pub const NUMBER1: cec_user_control_code = cec_user_control_code(33);
+}
+impl cec_user_control_code {
+    pub const NUMBER2: cec_user_control_code = cec_user_control_code(34);
+}
+impl cec_user_control_code {
+    pub const NUMBER3: cec_user_control_code = cec_user_control_code(35);
+}
+impl cec_user_control_code {
+    pub const NUMBER4: cec_user_control_code = cec_user_control_code(36);
+}
+impl cec_user_control_code {
+    pub const NUMBER5: cec_user_control_code = cec_user_control_code(37);
+}
+impl cec_user_control_code {
+    pub const NUMBER6: cec_user_control_code = cec_user_control_code(38);
+}
+impl cec_user_control_code {
+    pub const NUMBER7: cec_user_control_code = cec_user_control_code(39);
+}
+impl cec_user_control_code {
+    pub const NUMBER8: cec_user_control_code = cec_user_control_code(40);
+}
+impl cec_user_control_code {
+    pub const NUMBER9: cec_user_control_code = cec_user_control_code(41);
+}
+impl cec_user_control_code {
+    pub const DOT: cec_user_control_code = cec_user_control_code(42);
+}
+impl cec_user_control_code {
+    pub const ENTER: cec_user_control_code = cec_user_control_code(43);
+}
+impl cec_user_control_code {
+    pub const CLEAR: cec_user_control_code = cec_user_control_code(44);
+}
+impl cec_user_control_code {
+    pub const NEXT_FAVORITE: cec_user_control_code = cec_user_control_code(47);
+}
+impl cec_user_control_code {
+    pub const CHANNEL_UP: cec_user_control_code = cec_user_control_code(48);
+}
+impl cec_user_control_code {
+    pub const CHANNEL_DOWN: cec_user_control_code = cec_user_control_code(49);
+}
+impl cec_user_control_code {
+    pub const PREVIOUS_CHANNEL: cec_user_control_code = cec_user_control_code(50);
+}
+impl cec_user_control_code {
+    pub const SOUND_SELECT: cec_user_control_code = cec_user_control_code(51);
+}
+impl cec_user_control_code {
+    pub const INPUT_SELECT: cec_user_control_code = cec_user_control_code(52);
+}
+impl cec_user_control_code {
+    pub const DISPLAY_INFORMATION: cec_user_control_code = cec_user_control_code(53);
+}
+impl cec_user_control_code {
+    pub const HELP: cec_user_control_code = cec_user_control_code(54);
+}
+impl cec_user_control_code {
+    pub const PAGE_UP: cec_user_control_code = cec_user_control_code(55);
+}
+impl cec_user_control_code {
+    pub const PAGE_DOWN: cec_user_control_code = cec_user_control_code(56);
+}
+impl cec_user_control_code {
+    pub const POWER: cec_user_control_code = cec_user_control_code(64);
+}
+impl cec_user_control_code {
+    pub const VOLUME_UP: cec_user_control_code = cec_user_control_code(65);
+}
+impl cec_user_control_code {
+    pub const VOLUME_DOWN: cec_user_control_code = cec_user_control_code(66);
+}
+impl cec_user_control_code {
+    pub const MUTE: cec_user_control_code = cec_user_control_code(67);
+}
+impl cec_user_control_code {
+    pub const PLAY: cec_user_control_code = cec_user_control_code(68);
+}
+impl cec_user_control_code {
+    pub const STOP: cec_user_control_code = cec_user_control_code(69);
+}
+impl cec_user_control_code {
+    pub const PAUSE: cec_user_control_code = cec_user_control_code(70);
+}
+impl cec_user_control_code {
+    pub const RECORD: cec_user_control_code = cec_user_control_code(71);
+}
+impl cec_user_control_code {
+    pub const REWIND: cec_user_control_code = cec_user_control_code(72);
+}
+impl cec_user_control_code {
+    pub const FAST_FORWARD: cec_user_control_code = cec_user_control_code(73);
+}
+impl cec_user_control_code {
+    pub const EJECT: cec_user_control_code = cec_user_control_code(74);
+}
+impl cec_user_control_code {
+    pub const FORWARD: cec_user_control_code = cec_user_control_code(75);
+}
+impl cec_user_control_code {
+    pub const BACKWARD: cec_user_control_code = cec_user_control_code(76);
+}
+impl cec_user_control_code {
+    pub const STOP_RECORD: cec_user_control_code = cec_user_control_code(77);
+}
+impl cec_user_control_code {
+    pub const PAUSE_RECORD: cec_user_control_code = cec_user_control_code(78);
+}
+impl cec_user_control_code {
+    pub const ANGLE: cec_user_control_code = cec_user_control_code(80);
+}
+impl cec_user_control_code {
+    pub const SUB_PICTURE: cec_user_control_code = cec_user_control_code(81);
+}
+impl cec_user_control_code {
+    pub const VIDEO_ON_DEMAND: cec_user_control_code = cec_user_control_code(82);
+}
+impl cec_user_control_code {
+    pub const ELECTRONIC_PROGRAM_GUIDE: cec_user_control_code = cec_user_control_code(83);
+}
+impl cec_user_control_code {
+    pub const TIMER_PROGRAMMING: cec_user_control_code = cec_user_control_code(84);
+}
+impl cec_user_control_code {
+    pub const INITIAL_CONFIGURATION: cec_user_control_code = cec_user_control_code(85);
+}
+impl cec_user_control_code {
+    pub const SELECT_BROADCAST_TYPE: cec_user_control_code = cec_user_control_code(86);
+}
+impl cec_user_control_code {
+    pub const SELECT_SOUND_PRESENTATION: cec_user_control_code = cec_user_control_code(87);
+}
+impl cec_user_control_code {
+    pub const PLAY_FUNCTION: cec_user_control_code = cec_user_control_code(96);
+}
+impl cec_user_control_code {
+    pub const PAUSE_PLAY_FUNCTION: cec_user_control_code = cec_user_control_code(97);
+}
+impl cec_user_control_code {
+    pub const RECORD_FUNCTION: cec_user_control_code = cec_user_control_code(98);
+}
+impl cec_user_control_code {
+    pub const PAUSE_RECORD_FUNCTION: cec_user_control_code = cec_user_control_code(99);
+}
+impl cec_user_control_code {
+    pub const STOP_FUNCTION: cec_user_control_code = cec_user_control_code(100);
+}
+impl cec_user_control_code {
+    pub const MUTE_FUNCTION: cec_user_control_code = cec_user_control_code(101);
+}
+impl cec_user_control_code {
+    pub const RESTORE_VOLUME_FUNCTION: cec_user_control_code = cec_user_control_code(102);
+}
+impl cec_user_control_code {
+    pub const TUNE_FUNCTION: cec_user_control_code = cec_user_control_code(103);
+}
+impl cec_user_control_code {
+    pub const SELECT_MEDIA_FUNCTION: cec_user_control_code = cec_user_control_code(104);
+}
+impl cec_user_control_code {
+    pub const SELECT_AV_INPUT_FUNCTION: cec_user_control_code = cec_user_control_code(105);
+}
+impl cec_user_control_code {
+    pub const SELECT_AUDIO_INPUT_FUNCTION: cec_user_control_code = cec_user_control_code(106);
+}
+impl cec_user_control_code {
+    pub const POWER_TOGGLE_FUNCTION: cec_user_control_code = cec_user_control_code(107);
+}
+impl cec_user_control_code {
+    pub const POWER_OFF_FUNCTION: cec_user_control_code = cec_user_control_code(108);
+}
+impl cec_user_control_code {
+    pub const POWER_ON_FUNCTION: cec_user_control_code = cec_user_control_code(109);
+}
+impl cec_user_control_code {
+    pub const F1_BLUE: cec_user_control_code = cec_user_control_code(113);
+}
+impl cec_user_control_code {
+    pub const F2_RED: cec_user_control_code = cec_user_control_code(114);
+}
+impl cec_user_control_code {
+    pub const F3_GREEN: cec_user_control_code = cec_user_control_code(115);
+}
+impl cec_user_control_code {
+    pub const F4_YELLOW: cec_user_control_code = cec_user_control_code(116);
+}
+impl cec_user_control_code {
+    pub const F5: cec_user_control_code = cec_user_control_code(117);
+}
+impl cec_user_control_code {
+    pub const DATA: cec_user_control_code = cec_user_control_code(118);
+}
+impl cec_user_control_code {
+    pub const AN_RETURN: cec_user_control_code = cec_user_control_code(145);
+}
+impl cec_user_control_code {
+    pub const AN_CHANNELS_LIST: cec_user_control_code = cec_user_control_code(150);
+}
+impl cec_user_control_code {
+    pub const MAX: cec_user_control_code = cec_user_control_code(150);
+}
+impl cec_user_control_code {
+    pub const UNKNOWN: cec_user_control_code = cec_user_control_code(255);
+}
+impl cec_logical_address {
+    pub const UNKNOWN: cec_logical_address = cec_logical_address(-1);
+}
+impl cec_logical_address {
+    pub const TV: cec_logical_address = cec_logical_address(0);
+}
+impl cec_logical_address {
+    pub const RECORDINGDEVICE1: cec_logical_address = cec_logical_address(1);
+}
+impl cec_logical_address {
+    pub const RECORDINGDEVICE2: cec_logical_address = cec_logical_address(2);
+}
+impl cec_logical_address {
+    pub const TUNER1: cec_logical_address = cec_logical_address(3);
+}
+impl cec_logical_address {
+    pub const PLAYBACKDEVICE1: cec_logical_address = cec_logical_address(4);
+}
+impl cec_logical_address {
+    pub const AUDIOSYSTEM: cec_logical_address = cec_logical_address(5);
+}
+impl cec_logical_address {
+    pub const TUNER2: cec_logical_address = cec_logical_address(6);
+}
+impl cec_logical_address {
+    pub const TUNER3: cec_logical_address = cec_logical_address(7);
+}
+impl cec_logical_address {
+    pub const PLAYBACKDEVICE2: cec_logical_address = cec_logical_address(8);
+}
+impl cec_logical_address {
+    pub const RECORDINGDEVICE3: cec_logical_address = cec_logical_address(9);
+}
+impl cec_logical_address {
+    pub const TUNER4: cec_logical_address = cec_logical_address(10);
+}
+impl cec_logical_address {
+    pub const PLAYBACKDEVICE3: cec_logical_address = cec_logical_address(11);
+}
+impl cec_logical_address {
+    pub const RESERVED1: cec_logical_address = cec_logical_address(12);
+}
+impl cec_logical_address {
+    pub const RESERVED2: cec_logical_address = cec_logical_address(13);
+}
+impl cec_logical_address {
+    pub const FREEUSE: cec_logical_address = cec_logical_address(14);
+}
+impl cec_logical_address {
+    pub const UNREGISTERED: cec_logical_address = cec_logical_address(15);
+}
+impl cec_logical_address {
+    pub const BROADCAST: cec_logical_address = cec_logical_address(15);
+}
+impl cec_opcode {
+    pub const ACTIVE_SOURCE: cec_opcode = cec_opcode(130);
+}
+impl cec_opcode {
+    pub const IMAGE_VIEW_ON: cec_opcode = cec_opcode(4);
+}
+impl cec_opcode {
+    pub const TEXT_VIEW_ON: cec_opcode = cec_opcode(13);
+}
+impl cec_opcode {
+    pub const INACTIVE_SOURCE: cec_opcode = cec_opcode(157);
+}
+impl cec_opcode {
+    pub const REQUEST_ACTIVE_SOURCE: cec_opcode = cec_opcode(133);
+}
+impl cec_opcode {
+    pub const ROUTING_CHANGE: cec_opcode = cec_opcode(128);
+}
+impl cec_opcode {
+    pub const ROUTING_INFORMATION: cec_opcode = cec_opcode(129);
+}
+impl cec_opcode {
+    pub const SET_STREAM_PATH: cec_opcode = cec_opcode(134);
+}
+impl cec_opcode {
+    pub const STANDBY: cec_opcode = cec_opcode(54);
+}
+impl cec_opcode {
+    pub const RECORD_OFF: cec_opcode = cec_opcode(11);
+}
+impl cec_opcode {
+    pub const RECORD_ON: cec_opcode = cec_opcode(9);
+}
+impl cec_opcode {
+    pub const RECORD_STATUS: cec_opcode = cec_opcode(10);
+}
+impl cec_opcode {
+    pub const RECORD_TV_SCREEN: cec_opcode = cec_opcode(15);
+}
+impl cec_opcode {
+    pub const CLEAR_ANALOGUE_TIMER: cec_opcode = cec_opcode(51);
+}
+impl cec_opcode {
+    pub const CLEAR_DIGITAL_TIMER: cec_opcode = cec_opcode(153);
+}
+impl cec_opcode {
+    pub const CLEAR_EXTERNAL_TIMER: cec_opcode = cec_opcode(161);
+}
+impl cec_opcode {
+    pub const SET_ANALOGUE_TIMER: cec_opcode = cec_opcode(52);
+}
+impl cec_opcode {
+    pub const SET_DIGITAL_TIMER: cec_opcode = cec_opcode(151);
+}
+impl cec_opcode {
+    pub const SET_EXTERNAL_TIMER: cec_opcode = cec_opcode(162);
+}
+impl cec_opcode {
+    pub const SET_TIMER_PROGRAM_TITLE: cec_opcode = cec_opcode(103);
+}
+impl cec_opcode {
+    pub const TIMER_CLEARED_STATUS: cec_opcode = cec_opcode(67);
+}
+impl cec_opcode {
+    pub const TIMER_STATUS: cec_opcode = cec_opcode(53);
+}
+impl cec_opcode {
+    pub const CEC_VERSION: cec_opcode = cec_opcode(158);
+}
+impl cec_opcode {
+    pub const GET_CEC_VERSION: cec_opcode = cec_opcode(159);
+}
+impl cec_opcode {
+    pub const GIVE_PHYSICAL_ADDRESS: cec_opcode = cec_opcode(131);
+}
+impl cec_opcode {
+    pub const GET_MENU_LANGUAGE: cec_opcode = cec_opcode(145);
+}
+impl cec_opcode {
+    pub const REPORT_PHYSICAL_ADDRESS: cec_opcode = cec_opcode(132);
+}
+impl cec_opcode {
+    pub const SET_MENU_LANGUAGE: cec_opcode = cec_opcode(50);
+}
+impl cec_opcode {
+    pub const DECK_CONTROL: cec_opcode = cec_opcode(66);
+}
+impl cec_opcode {
+    pub const DECK_STATUS: cec_opcode = cec_opcode(27);
+}
+impl cec_opcode {
+    pub const GIVE_DECK_STATUS: cec_opcode = cec_opcode(26);
+}
+impl cec_opcode {
+    pub const PLAY: cec_opcode = cec_opcode(65);
+}
+impl cec_opcode {
+    pub const GIVE_TUNER_DEVICE_STATUS: cec_opcode = cec_opcode(8);
+}
+impl cec_opcode {
+    pub const SELECT_ANALOGUE_SERVICE: cec_opcode = cec_opcode(146);
+}
+impl cec_opcode {
+    pub const SELECT_DIGITAL_SERVICE: cec_opcode = cec_opcode(147);
+}
+impl cec_opcode {
+    pub const TUNER_DEVICE_STATUS: cec_opcode = cec_opcode(7);
+}
+impl cec_opcode {
+    pub const TUNER_STEP_DECREMENT: cec_opcode = cec_opcode(6);
+}
+impl cec_opcode {
+    pub const TUNER_STEP_INCREMENT: cec_opcode = cec_opcode(5);
+}
+impl cec_opcode {
+    pub const DEVICE_VENDOR_ID: cec_opcode = cec_opcode(135);
+}
+impl cec_opcode {
+    pub const GIVE_DEVICE_VENDOR_ID: cec_opcode = cec_opcode(140);
+}
+impl cec_opcode {
+    pub const VENDOR_COMMAND: cec_opcode = cec_opcode(137);
+}
+impl cec_opcode {
+    pub const VENDOR_COMMAND_WITH_ID: cec_opcode = cec_opcode(160);
+}
+impl cec_opcode {
+    pub const VENDOR_REMOTE_BUTTON_DOWN: cec_opcode = cec_opcode(138);
+}
+impl cec_opcode {
+    pub const VENDOR_REMOTE_BUTTON_UP: cec_opcode = cec_opcode(139);
+}
+impl cec_opcode {
+    pub const SET_OSD_STRING: cec_opcode = cec_opcode(100);
+}
+impl cec_opcode {
+    pub const GIVE_OSD_NAME: cec_opcode = cec_opcode(70);
+}
+impl cec_opcode {
+    pub const SET_OSD_NAME: cec_opcode = cec_opcode(71);
+}
+impl cec_opcode {
+    pub const MENU_REQUEST: cec_opcode = cec_opcode(141);
+}
+impl cec_opcode {
+    pub const MENU_STATUS: cec_opcode = cec_opcode(142);
+}
+impl cec_opcode {
+    pub const USER_CONTROL_PRESSED: cec_opcode = cec_opcode(68);
+}
+impl cec_opcode {
+    pub const USER_CONTROL_RELEASE: cec_opcode = cec_opcode(69);
+}
+impl cec_opcode {
+    pub const GIVE_DEVICE_POWER_STATUS: cec_opcode = cec_opcode(143);
+}
+impl cec_opcode {
+    pub const REPORT_POWER_STATUS: cec_opcode = cec_opcode(144);
+}
+impl cec_opcode {
+    pub const FEATURE_ABORT: cec_opcode = cec_opcode(0);
+}
+impl cec_opcode {
+    pub const ABORT: cec_opcode = cec_opcode(255);
+}
+impl cec_opcode {
+    pub const GIVE_AUDIO_STATUS: cec_opcode = cec_opcode(113);
+}
+impl cec_opcode {
+    pub const GIVE_SYSTEM_AUDIO_MODE_STATUS: cec_opcode = cec_opcode(125);
+}
+impl cec_opcode {
+    pub const REPORT_AUDIO_STATUS: cec_opcode = cec_opcode(122);
+}
+impl cec_opcode {
+    pub const SET_SYSTEM_AUDIO_MODE: cec_opcode = cec_opcode(114);
+}
+impl cec_opcode {
+    pub const SYSTEM_AUDIO_MODE_REQUEST: cec_opcode = cec_opcode(112);
+}
+impl cec_opcode {
+    pub const SYSTEM_AUDIO_MODE_STATUS: cec_opcode = cec_opcode(126);
+}
+impl cec_opcode {
+    pub const SET_AUDIO_RATE: cec_opcode = cec_opcode(154);
+}
+impl cec_opcode {
+    pub const REPORT_SHORT_AUDIO_DESCRIPTORS: cec_opcode = cec_opcode(163);
+}
+impl cec_opcode {
+    pub const REQUEST_SHORT_AUDIO_DESCRIPTORS: cec_opcode = cec_opcode(164);
+}
+impl cec_opcode {
+    pub const START_ARC: cec_opcode = cec_opcode(192);
+}
+impl cec_opcode {
+    pub const REPORT_ARC_STARTED: cec_opcode = cec_opcode(193);
+}
+impl cec_opcode {
+    pub const REPORT_ARC_ENDED: cec_opcode = cec_opcode(194);
+}
+impl cec_opcode {
+    pub const REQUEST_ARC_START: cec_opcode = cec_opcode(195);
+}
+impl cec_opcode {
+    pub const REQUEST_ARC_END: cec_opcode = cec_opcode(196);
+}
+impl cec_opcode {
+    pub const END_ARC: cec_opcode = cec_opcode(197);
+}
+impl cec_opcode {
+    pub const CDC: cec_opcode = cec_opcode(248);
+}
+impl cec_opcode {
+    pub const NONE: cec_opcode = cec_opcode(253);
+}
+impl cec_log_level {
+    pub const CEC_LOG_ERROR: cec_log_level = cec_log_level(1);
+}
+impl cec_log_level {
+    pub const CEC_LOG_WARNING: cec_log_level = cec_log_level(2);
+}
+impl cec_log_level {
+    pub const CEC_LOG_NOTICE: cec_log_level = cec_log_level(4);
+}
+impl cec_log_level {
+    pub const CEC_LOG_TRAFFIC: cec_log_level = cec_log_level(8);
+}
+impl cec_log_level {
+    pub const CEC_LOG_DEBUG: cec_log_level = cec_log_level(16);
+}
+impl cec_log_level {
+    pub const CEC_LOG_ALL: cec_log_level = cec_log_level(31);
+}
+impl cec_bus_device_status {
+    pub const UNKNOWN: cec_bus_device_status = cec_bus_device_status(0);
+}
+impl cec_bus_device_status {
+    pub const PRESENT: cec_bus_device_status = cec_bus_device_status(1);
+}
+impl cec_bus_device_status {
+    pub const NOT_PRESENT: cec_bus_device_status = cec_bus_device_status(2);
+}
+impl cec_bus_device_status {
+    pub const HANDLED_BY_LIBCEC: cec_bus_device_status = cec_bus_device_status(3);
+}
+impl cec_vendor_id {
+    pub const TOSHIBA: cec_vendor_id = cec_vendor_id(57);
+}
+impl cec_vendor_id {
+    pub const SAMSUNG: cec_vendor_id = cec_vendor_id(240);
+}
+impl cec_vendor_id {
+    pub const DENON: cec_vendor_id = cec_vendor_id(1485);
+}
+impl cec_vendor_id {
+    pub const MARANTZ: cec_vendor_id = cec_vendor_id(1656);
+}
+impl cec_vendor_id {
+    pub const LOEWE: cec_vendor_id = cec_vendor_id(2434);
+}
+impl cec_vendor_id {
+    pub const ONKYO: cec_vendor_id = cec_vendor_id(2480);
+}
+impl cec_vendor_id {
+    pub const MEDION: cec_vendor_id = cec_vendor_id(3256);
+}
+impl cec_vendor_id {
+    pub const TOSHIBA2: cec_vendor_id = cec_vendor_id(3303);
+}
+impl cec_vendor_id {
+    pub const APPLE: cec_vendor_id = cec_vendor_id(4346);
+}
+impl cec_vendor_id {
+    pub const PULSE_EIGHT: cec_vendor_id = cec_vendor_id(5506);
+}
+impl cec_vendor_id {
+    pub const HARMAN_KARDON2: cec_vendor_id = cec_vendor_id(6480);
+}
+impl cec_vendor_id {
+    pub const GOOGLE: cec_vendor_id = cec_vendor_id(6673);
+}
+impl cec_vendor_id {
+    pub const AKAI: cec_vendor_id = cec_vendor_id(8391);
+}
+impl cec_vendor_id {
+    pub const AOC: cec_vendor_id = cec_vendor_id(9319);
+}
+impl cec_vendor_id {
+    pub const PANASONIC: cec_vendor_id = cec_vendor_id(32837);
+}
+impl cec_vendor_id {
+    pub const PHILIPS: cec_vendor_id = cec_vendor_id(36926);
+}
+impl cec_vendor_id {
+    pub const DAEWOO: cec_vendor_id = cec_vendor_id(36947);
+}
+impl cec_vendor_id {
+    pub const YAMAHA: cec_vendor_id = cec_vendor_id(41182);
+}
+impl cec_vendor_id {
+    pub const GRUNDIG: cec_vendor_id = cec_vendor_id(53461);
+}
+impl cec_vendor_id {
+    pub const PIONEER: cec_vendor_id = cec_vendor_id(57398);
+}
+impl cec_vendor_id {
+    pub const LG: cec_vendor_id = cec_vendor_id(57489);
+}
+impl cec_vendor_id {
+    pub const SHARP: cec_vendor_id = cec_vendor_id(524319);
+}
+impl cec_vendor_id {
+    pub const SONY: cec_vendor_id = cec_vendor_id(524358);
+}
+impl cec_vendor_id {
+    pub const BROADCOM: cec_vendor_id = cec_vendor_id(1622150);
+}
+impl cec_vendor_id {
+    pub const SHARP2: cec_vendor_id = cec_vendor_id(5458000);
+}
+impl cec_vendor_id {
+    pub const VIZIO: cec_vendor_id = cec_vendor_id(7042157);
+}
+impl cec_vendor_id {
+    pub const BENQ: cec_vendor_id = cec_vendor_id(8414697);
+}
+impl cec_vendor_id {
+    pub const HARMAN_KARDON: cec_vendor_id = cec_vendor_id(10249310);
+}
+impl cec_vendor_id {
+    pub const UNKNOWN: cec_vendor_id = cec_vendor_id(0);
+}
+impl cec_adapter_type {
+    pub const UNKNOWN: cec_adapter_type = cec_adapter_type(0);
+}
+impl cec_adapter_type {
+    pub const P8_EXTERNAL: cec_adapter_type = cec_adapter_type(1);
+}
+impl cec_adapter_type {
+    pub const P8_DAUGHTERBOARD: cec_adapter_type = cec_adapter_type(2);
+}
+impl cec_adapter_type {
+    pub const RPI: cec_adapter_type = cec_adapter_type(256);
+}
+impl cec_adapter_type {
+    pub const TDA995x: cec_adapter_type = cec_adapter_type(512);
+}
+impl cec_adapter_type {
+    pub const EXYNOS: cec_adapter_type = cec_adapter_type(768);
+}
+impl cec_adapter_type {
+    pub const LINUX: cec_adapter_type = cec_adapter_type(1024);
+}
+impl cec_adapter_type {
+    pub const AOCEC: cec_adapter_type = cec_adapter_type(1280);
+}
+impl cec_adapter_type {
+    pub const IMX: cec_adapter_type = cec_adapter_type(1536);
+}
+impl libcec_version {
+    pub const CURRENT: libcec_version = libcec_version(262149);
+}
+impl Default for cec_log_message {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+impl Default for cec_keypress {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+impl Default for cec_adapter {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+impl Default for cec_adapter_descriptor {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+impl Default for cec_datapacket {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+impl Default for cec_command {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+impl Default for cec_device_type_list {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+impl Default for cec_logical_addresses {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+impl libcec_alert {
+    pub const SERVICE_DEVICE: libcec_alert = libcec_alert(0);
+}
+impl libcec_alert {
+    pub const CONNECTION_LOST: libcec_alert = libcec_alert(1);
+}
+impl libcec_alert {
+    pub const PERMISSION_ERROR: libcec_alert = libcec_alert(2);
+}
+impl libcec_alert {
+    pub const PORT_BUSY: libcec_alert = libcec_alert(3);
+}
+impl libcec_alert {
+    pub const PHYSICAL_ADDRESS_ERROR: libcec_alert = libcec_alert(4);
+}
+impl libcec_alert {
+    pub const TV_POLL_FAILED: libcec_alert = libcec_alert(5);
+}
+impl libcec_parameter_type {
+    pub const STRING: libcec_parameter_type = libcec_parameter_type(0);
+}
+impl libcec_parameter_type {
+    pub const UNKOWN: libcec_parameter_type = libcec_parameter_type(1);
+}
+impl Default for libcec_parameter {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+impl Default for libcec_configuration {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+unsafe extern "C" {
+    pub fn libcec_initialise(configuration: *mut libcec_configuration) -> libcec_connection_t;
+    pub fn libcec_destroy(connection: libcec_connection_t);
+    pub fn libcec_open(
+        connection: libcec_connection_t,
+        strPort: *const ::std::os::raw::c_char,
+        iTimeout: u32,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_close(connection: libcec_connection_t);
+    pub fn libcec_clear_configuration(configuration: *mut libcec_configuration);
+    pub fn libcec_enable_callbacks(
+        connection: libcec_connection_t,
+        cbParam: *mut ::std::os::raw::c_void,
+        callbacks: *mut ICECCallbacks,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_find_adapters(
+        connection: libcec_connection_t,
+        deviceList: *mut cec_adapter,
+        iBufSize: u8,
+        strDevicePath: *const ::std::os::raw::c_char,
+    ) -> i8;
+    pub fn libcec_ping_adapters(connection: libcec_connection_t) -> ::std::os::raw::c_int;
+    pub fn libcec_start_bootloader(connection: libcec_connection_t) -> ::std::os::raw::c_int;
+    pub fn libcec_power_on_devices(
+        connection: libcec_connection_t,
+        address: cec_logical_address,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_standby_devices(
+        connection: libcec_connection_t,
+        address: cec_logical_address,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_set_active_source(
+        connection: libcec_connection_t,
+        type_: cec_device_type,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_set_deck_control_mode(
+        connection: libcec_connection_t,
+        mode: cec_deck_control_mode,
+        bSendUpdate: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_set_deck_info(
+        connection: libcec_connection_t,
+        info: cec_deck_info,
+        bSendUpdate: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_set_inactive_view(connection: libcec_connection_t) -> ::std::os::raw::c_int;
+    pub fn libcec_set_menu_state(
+        connection: libcec_connection_t,
+        state: cec_menu_state,
+        bSendUpdate: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_transmit(
+        connection: libcec_connection_t,
+        data: *const cec_command,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_set_logical_address(
+        connection: libcec_connection_t,
+        iLogicalAddress: cec_logical_address,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_set_physical_address(
+        connection: libcec_connection_t,
+        iPhysicalAddress: u16,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_set_osd_string(
+        connection: libcec_connection_t,
+        iLogicalAddress: cec_logical_address,
+        duration: cec_display_control,
+        strMessage: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_switch_monitoring(
+        connection: libcec_connection_t,
+        bEnable: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_get_device_cec_version(
+        connection: libcec_connection_t,
+        iLogicalAddress: cec_logical_address,
+    ) -> cec_version;
+    pub fn libcec_get_device_menu_language(
+        connection: libcec_connection_t,
+        iLogicalAddress: cec_logical_address,
+        language: *mut ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_get_device_vendor_id(
+        connection: libcec_connection_t,
+        iLogicalAddress: cec_logical_address,
+    ) -> u32;
+    pub fn libcec_get_device_physical_address(
+        connection: libcec_connection_t,
+        iLogicalAddress: cec_logical_address,
+    ) -> u16;
+    pub fn libcec_get_active_source(connection: libcec_connection_t) -> cec_logical_address;
+    pub fn libcec_is_active_source(
+        connection: libcec_connection_t,
+        iAddress: cec_logical_address,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_get_device_power_status(
+        connection: libcec_connection_t,
+        iLogicalAddress: cec_logical_address,
+    ) -> cec_power_status;
+    pub fn libcec_poll_device(
+        connection: libcec_connection_t,
+        iLogicalAddress: cec_logical_address,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_get_active_devices(connection: libcec_connection_t) -> cec_logical_addresses;
+    pub fn libcec_is_active_device(
+        connection: libcec_connection_t,
+        address: cec_logical_address,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_is_active_device_type(
+        connection: libcec_connection_t,
+        type_: cec_device_type,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_set_hdmi_port(
+        connection: libcec_connection_t,
+        baseDevice: cec_logical_address,
+        iPort: u8,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_volume_up(
+        connection: libcec_connection_t,
+        bSendRelease: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_volume_down(
+        connection: libcec_connection_t,
+        bSendRelease: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_mute_audio(
+        connection: libcec_connection_t,
+        bSendRelease: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_send_keypress(
+        connection: libcec_connection_t,
+        iDestination: cec_logical_address,
+        key: cec_user_control_code,
+        bWait: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_send_key_release(
+        connection: libcec_connection_t,
+        iDestination: cec_logical_address,
+        bWait: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_get_device_osd_name(
+        connection: libcec_connection_t,
+        iAddress: cec_logical_address,
+        name: *mut ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_set_stream_path_logical(
+        connection: libcec_connection_t,
+        iAddress: cec_logical_address,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_set_stream_path_physical(
+        connection: libcec_connection_t,
+        iPhysicalAddress: u16,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_get_logical_addresses(connection: libcec_connection_t) -> cec_logical_addresses;
+    pub fn libcec_get_current_configuration(
+        connection: libcec_connection_t,
+        configuration: *mut libcec_configuration,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_can_persist_configuration(
+        connection: libcec_connection_t,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_persist_configuration(
+        connection: libcec_connection_t,
+        configuration: *mut libcec_configuration,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_set_configuration(
+        connection: libcec_connection_t,
+        configuration: *const libcec_configuration,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_rescan_devices(connection: libcec_connection_t);
+    pub fn libcec_is_libcec_active_source(connection: libcec_connection_t)
+        -> ::std::os::raw::c_int;
+    pub fn libcec_get_device_information(
+        connection: libcec_connection_t,
+        strPort: *const ::std::os::raw::c_char,
+        config: *mut libcec_configuration,
+        iTimeoutMs: u32,
+    ) -> ::std::os::raw::c_int;
+    pub fn libcec_get_lib_info(connection: libcec_connection_t) -> *const ::std::os::raw::c_char;
+    pub fn libcec_init_video_standalone(connection: libcec_connection_t);
+    pub fn libcec_get_adapter_vendor_id(connection: libcec_connection_t) -> u16;
+    pub fn libcec_get_adapter_product_id(connection: libcec_connection_t) -> u16;
+    pub fn libcec_audio_toggle_mute(connection: libcec_connection_t) -> u8;
+    pub fn libcec_audio_mute(connection: libcec_connection_t) -> u8;
+    pub fn libcec_audio_unmute(connection: libcec_connection_t) -> u8;
+    pub fn libcec_audio_get_status(connection: libcec_connection_t) -> u8;
+    pub fn libcec_detect_adapters(
+        connection: libcec_connection_t,
+        deviceList: *mut cec_adapter_descriptor,
+        iBufSize: u8,
+        strDevicePath: *const ::std::os::raw::c_char,
+        bQuickScan: ::std::os::raw::c_int,
+    ) -> i8;
+    pub fn libcec_menu_state_to_string(
+        state: cec_menu_state,
+        buf: *mut ::std::os::raw::c_char,
+        bufsize: usize,
+    );
+    pub fn libcec_cec_version_to_string(
+        version: cec_version,
+        buf: *mut ::std::os::raw::c_char,
+        bufsize: usize,
+    );
+    pub fn libcec_power_status_to_string(
+        status: cec_power_status,
+        buf: *mut ::std::os::raw::c_char,
+        bufsize: usize,
+    );
+    pub fn libcec_logical_address_to_string(
+        address: cec_logical_address,
+        buf: *mut ::std::os::raw::c_char,
+        bufsize: usize,
+    );
+    pub fn libcec_deck_control_mode_to_string(
+        mode: cec_deck_control_mode,
+        buf: *mut ::std::os::raw::c_char,
+        bufsize: usize,
+    );
+    pub fn libcec_deck_status_to_string(
+        status: cec_deck_info,
+        buf: *mut ::std::os::raw::c_char,
+        bufsize: usize,
+    );
+    pub fn libcec_opcode_to_string(
+        opcode: cec_opcode,
+        buf: *mut ::std::os::raw::c_char,
+        bufsize: usize,
+    );
+    pub fn libcec_system_audio_status_to_string(
+        mode: cec_system_audio_status,
+        buf: *mut ::std::os::raw::c_char,
+        bufsize: usize,
+    );
+    pub fn libcec_audio_status_to_string(
+        status: cec_audio_status::Type,
+        buf: *mut ::std::os::raw::c_char,
+        bufsize: usize,
+    );
+    pub fn libcec_vendor_id_to_string(
+        vendor: cec_vendor_id,
+        buf: *mut ::std::os::raw::c_char,
+        bufsize: usize,
+    );
+    pub fn libcec_user_control_key_to_string(
+        key: cec_user_control_code,
+        buf: *mut ::std::os::raw::c_char,
+        bufsize: usize,
+    );
+    pub fn libcec_adapter_type_to_string(
+        type_: cec_adapter_type,
+        buf: *mut ::std::os::raw::c_char,
+        bufsize: usize,
+    );
+    pub fn libcec_version_to_string(version: u32, buf: *mut ::std::os::raw::c_char, bufsize: usize);
+}
+pub mod cec_audio_status {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const MUTE_STATUS_MASK: Type = 128;
+    pub const VOLUME_STATUS_MASK: Type = 127;
+    pub const VOLUME_MIN: Type = 0;
+    pub const VOLUME_MAX: Type = 100;
+    pub const VOLUME_STATUS_UNKNOWN: Type = 127;
+}
+pub mod cec_channel_identifier {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const CEC_CHANNEL_NUMBER_FORMAT_MASK: Type = 4227858432;
+    pub const CEC_1_PART_CHANNEL_NUMBER: Type = 67108864;
+    pub const CEC_2_PART_CHANNEL_NUMBER: Type = 134217728;
+    pub const CEC_MAJOR_CHANNEL_NUMBER_MASK: Type = 67043328;
+    pub const CEC_MINOR_CHANNEL_NUMBER_MASK: Type = 65535;
+}
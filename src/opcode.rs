@@ -0,0 +1,421 @@
+//! Opcode metadata from the CEC specification
+//!
+//! [`OPCODES`] has an [`OpcodeInfo`] for each opcode of libcec: whether it is sent to a single
+//! device, broadcast or either, the number of operand bytes, the CEC version which introduced
+//! it and the opcodes of the replies which it asks for.
+//!
+//! ```
+//! use libcec_sys::enums::Opcode;
+//! use libcec_sys::opcode::Addressing;
+//!
+//! let info = Opcode::GiveDevicePowerStatus.info().unwrap();
+//! assert_eq!(info.addressing, Addressing::Directed);
+//! assert_eq!(info.responses, &[Opcode::ReportPowerStatus]);
+//! assert_eq!(Opcode::ReportPhysicalAddress.info().unwrap().min_operands, 3);
+//! ```
+use std::fmt;
+
+use crate::enums::{CecVersion, Opcode};
+use Addressing::{Both, Broadcast, Directed};
+use CecVersion::{V1_3a, V1_4};
+
+/// How a message is addressed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Addressing {
+    /// Only sent to a single device
+    Directed,
+    /// Only sent to the broadcast address
+    Broadcast,
+    /// Sent either way
+    Both,
+}
+
+impl Addressing {
+    /// Whether a message sent to the broadcast address, or to a single device, is allowed
+    pub const fn allows(self, broadcast: bool) -> bool {
+        match self {
+            Addressing::Directed => !broadcast,
+            Addressing::Broadcast => broadcast,
+            Addressing::Both => true,
+        }
+    }
+}
+
+impl fmt::Display for Addressing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Addressing::Directed => "directed",
+            Addressing::Broadcast => "broadcast",
+            Addressing::Both => "directed or broadcast",
+        })
+    }
+}
+
+/// Metadata of an opcode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpcodeInfo {
+    pub opcode: Opcode,
+    pub addressing: Addressing,
+    /// Minimum number of operand bytes
+    pub min_operands: u8,
+    /// Maximum number of operand bytes
+    pub max_operands: u8,
+    /// Version of the specification which introduced the opcode, `V1_3a` for 1.3a or earlier
+    pub version: CecVersion,
+    /// Opcodes of the replies, besides `<Feature Abort>` which any directed message may get
+    pub responses: &'static [Opcode],
+}
+
+impl OpcodeInfo {
+    /// Whether `length` operand bytes are allowed
+    pub const fn accepts_length(&self, length: usize) -> bool {
+        self.min_operands as usize <= length && length <= self.max_operands as usize
+    }
+
+    /// Whether `opcode` is one of the replies
+    pub fn is_response(&self, opcode: Opcode) -> bool {
+        self.responses.contains(&opcode)
+    }
+}
+
+const fn info(
+    opcode: Opcode,
+    addressing: Addressing,
+    (min_operands, max_operands): (u8, u8),
+    version: CecVersion,
+    responses: &'static [Opcode],
+) -> OpcodeInfo {
+    OpcodeInfo {
+        opcode,
+        addressing,
+        min_operands,
+        max_operands,
+        version,
+        responses,
+    }
+}
+
+/// Metadata of every opcode of libcec, except `Opcode::None`
+pub static OPCODES: &[OpcodeInfo] = &[
+    info(Opcode::FeatureAbort, Directed, (2, 2), V1_3a, &[]),
+    info(Opcode::ImageViewOn, Directed, (0, 0), V1_3a, &[]),
+    info(Opcode::TunerStepIncrement, Directed, (0, 0), V1_3a, &[]),
+    info(Opcode::TunerStepDecrement, Directed, (0, 0), V1_3a, &[]),
+    info(Opcode::TunerDeviceStatus, Directed, (5, 8), V1_3a, &[]),
+    info(
+        Opcode::GiveTunerDeviceStatus,
+        Directed,
+        (1, 1),
+        V1_3a,
+        &[Opcode::TunerDeviceStatus],
+    ),
+    info(
+        Opcode::RecordOn,
+        Directed,
+        (1, 8),
+        V1_3a,
+        &[Opcode::RecordStatus],
+    ),
+    info(Opcode::RecordStatus, Directed, (1, 1), V1_3a, &[]),
+    info(Opcode::RecordOff, Directed, (0, 0), V1_3a, &[]),
+    info(Opcode::TextViewOn, Directed, (0, 0), V1_3a, &[]),
+    info(
+        Opcode::RecordTvScreen,
+        Directed,
+        (0, 0),
+        V1_3a,
+        &[Opcode::RecordOn],
+    ),
+    info(
+        Opcode::GiveDeckStatus,
+        Directed,
+        (1, 1),
+        V1_3a,
+        &[Opcode::DeckStatus],
+    ),
+    info(Opcode::DeckStatus, Directed, (1, 1), V1_3a, &[]),
+    info(Opcode::SetMenuLanguage, Broadcast, (3, 3), V1_3a, &[]),
+    info(
+        Opcode::ClearAnalogueTimer,
+        Directed,
+        (11, 11),
+        V1_3a,
+        &[Opcode::TimerClearedStatus],
+    ),
+    info(
+        Opcode::SetAnalogueTimer,
+        Directed,
+        (11, 11),
+        V1_3a,
+        &[Opcode::TimerStatus],
+    ),
+    info(Opcode::TimerStatus, Directed, (1, 3), V1_3a, &[]),
+    info(Opcode::Standby, Both, (0, 0), V1_3a, &[]),
+    info(Opcode::Play, Directed, (1, 1), V1_3a, &[]),
+    info(Opcode::DeckControl, Directed, (1, 1), V1_3a, &[]),
+    info(Opcode::TimerClearedStatus, Directed, (1, 1), V1_3a, &[]),
+    info(Opcode::UserControlPressed, Directed, (1, 5), V1_3a, &[]),
+    info(Opcode::UserControlRelease, Directed, (0, 0), V1_3a, &[]),
+    info(
+        Opcode::GiveOsdName,
+        Directed,
+        (0, 0),
+        V1_3a,
+        &[Opcode::SetOsdName],
+    ),
+    info(Opcode::SetOsdName, Directed, (1, 14), V1_3a, &[]),
+    info(Opcode::SetOsdString, Directed, (2, 14), V1_3a, &[]),
+    info(Opcode::SetTimerProgramTitle, Directed, (1, 14), V1_3a, &[]),
+    info(
+        Opcode::SystemAudioModeRequest,
+        Directed,
+        (0, 2),
+        V1_3a,
+        &[Opcode::SetSystemAudioMode],
+    ),
+    info(
+        Opcode::GiveAudioStatus,
+        Directed,
+        (0, 0),
+        V1_3a,
+        &[Opcode::ReportAudioStatus],
+    ),
+    info(Opcode::SetSystemAudioMode, Both, (1, 1), V1_3a, &[]),
+    info(Opcode::ReportAudioStatus, Directed, (1, 1), V1_3a, &[]),
+    info(
+        Opcode::GiveSystemAudioModeStatus,
+        Directed,
+        (0, 0),
+        V1_3a,
+        &[Opcode::SystemAudioModeStatus],
+    ),
+    info(Opcode::SystemAudioModeStatus, Directed, (1, 1), V1_3a, &[]),
+    info(Opcode::RoutingChange, Broadcast, (4, 4), V1_3a, &[]),
+    info(Opcode::RoutingInformation, Broadcast, (2, 2), V1_3a, &[]),
+    info(Opcode::ActiveSource, Broadcast, (2, 2), V1_3a, &[]),
+    info(
+        Opcode::GivePhysicalAddress,
+        Directed,
+        (0, 0),
+        V1_3a,
+        &[Opcode::ReportPhysicalAddress],
+    ),
+    info(Opcode::ReportPhysicalAddress, Broadcast, (3, 3), V1_3a, &[]),
+    info(
+        Opcode::RequestActiveSource,
+        Broadcast,
+        (0, 0),
+        V1_3a,
+        &[Opcode::ActiveSource],
+    ),
+    info(
+        Opcode::SetStreamPath,
+        Broadcast,
+        (2, 2),
+        V1_3a,
+        &[Opcode::ActiveSource],
+    ),
+    info(Opcode::DeviceVendorId, Broadcast, (3, 3), V1_3a, &[]),
+    info(Opcode::VendorCommand, Directed, (1, 14), V1_3a, &[]),
+    info(Opcode::VendorRemoteButtonDown, Both, (1, 14), V1_3a, &[]),
+    info(Opcode::VendorRemoteButtonUp, Both, (0, 0), V1_3a, &[]),
+    info(
+        Opcode::GiveDeviceVendorId,
+        Directed,
+        (0, 0),
+        V1_3a,
+        &[Opcode::DeviceVendorId],
+    ),
+    info(
+        Opcode::MenuRequest,
+        Directed,
+        (1, 1),
+        V1_3a,
+        &[Opcode::MenuStatus],
+    ),
+    info(Opcode::MenuStatus, Directed, (1, 1), V1_3a, &[]),
+    info(
+        Opcode::GiveDevicePowerStatus,
+        Directed,
+        (0, 0),
+        V1_3a,
+        &[Opcode::ReportPowerStatus],
+    ),
+    info(Opcode::ReportPowerStatus, Directed, (1, 1), V1_3a, &[]),
+    info(
+        Opcode::GetMenuLanguage,
+        Directed,
+        (0, 0),
+        V1_3a,
+        &[Opcode::SetMenuLanguage],
+    ),
+    info(Opcode::SelectAnalogueService, Directed, (4, 4), V1_3a, &[]),
+    info(Opcode::SelectDigitalService, Directed, (7, 7), V1_3a, &[]),
+    info(
+        Opcode::SetDigitalTimer,
+        Directed,
+        (14, 14),
+        V1_3a,
+        &[Opcode::TimerStatus],
+    ),
+    info(
+        Opcode::ClearDigitalTimer,
+        Directed,
+        (14, 14),
+        V1_3a,
+        &[Opcode::TimerClearedStatus],
+    ),
+    info(Opcode::SetAudioRate, Directed, (1, 1), V1_3a, &[]),
+    info(Opcode::InactiveSource, Directed, (2, 2), V1_3a, &[]),
+    info(Opcode::CecVersion, Directed, (1, 1), V1_3a, &[]),
+    info(
+        Opcode::GetCecVersion,
+        Directed,
+        (0, 0),
+        V1_3a,
+        &[Opcode::CecVersion],
+    ),
+    info(Opcode::VendorCommandWithId, Both, (4, 14), V1_3a, &[]),
+    info(
+        Opcode::ClearExternalTimer,
+        Directed,
+        (9, 10),
+        V1_3a,
+        &[Opcode::TimerClearedStatus],
+    ),
+    info(
+        Opcode::SetExternalTimer,
+        Directed,
+        (9, 10),
+        V1_3a,
+        &[Opcode::TimerStatus],
+    ),
+    info(
+        Opcode::ReportShortAudioDescriptors,
+        Directed,
+        (3, 12),
+        V1_4,
+        &[],
+    ),
+    info(
+        Opcode::RequestShortAudioDescriptors,
+        Directed,
+        (1, 4),
+        V1_4,
+        &[Opcode::ReportShortAudioDescriptors],
+    ),
+    info(
+        Opcode::StartArc,
+        Directed,
+        (0, 0),
+        V1_4,
+        &[Opcode::ReportArcStarted],
+    ),
+    info(Opcode::ReportArcStarted, Directed, (0, 0), V1_4, &[]),
+    info(Opcode::ReportArcEnded, Directed, (0, 0), V1_4, &[]),
+    info(
+        Opcode::RequestArcStart,
+        Directed,
+        (0, 0),
+        V1_4,
+        &[Opcode::StartArc],
+    ),
+    info(
+        Opcode::RequestArcEnd,
+        Directed,
+        (0, 0),
+        V1_4,
+        &[Opcode::EndArc],
+    ),
+    info(
+        Opcode::EndArc,
+        Directed,
+        (0, 0),
+        V1_4,
+        &[Opcode::ReportArcEnded],
+    ),
+    info(Opcode::Cdc, Broadcast, (3, 14), V1_4, &[]),
+    info(
+        Opcode::Abort,
+        Directed,
+        (0, 0),
+        V1_3a,
+        &[Opcode::FeatureAbort],
+    ),
+];
+
+impl Opcode {
+    /// Metadata of the opcode, `None` for `Opcode::None` and values not known to libcec
+    pub fn info(self) -> Option<&'static OpcodeInfo> {
+        OPCODES.iter().find(|info| info.opcode == self)
+    }
+
+    /// Opcodes of the messages which `self` replies to
+    pub fn requests(self) -> impl Iterator<Item = Opcode> {
+        OPCODES
+            .iter()
+            .filter(move |info| info.is_response(self))
+            .map(|info| info.opcode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::CecMessage;
+
+    #[test]
+    fn test_all_opcodes() {
+        for opcode in Opcode::ALL {
+            assert_eq!(
+                opcode.info().map(|info| info.opcode),
+                Some(*opcode).filter(|opcode| *opcode != Opcode::None)
+            );
+        }
+        assert_eq!(OPCODES.len(), Opcode::ALL.len() - 1);
+    }
+
+    #[test]
+    fn test_responses() {
+        assert!(Opcode::GiveOsdName
+            .info()
+            .unwrap()
+            .is_response(Opcode::SetOsdName));
+        assert_eq!(
+            Opcode::TimerStatus.requests().collect::<Vec<_>>(),
+            [
+                Opcode::SetAnalogueTimer,
+                Opcode::SetDigitalTimer,
+                Opcode::SetExternalTimer
+            ]
+        );
+        for info in OPCODES {
+            for response in info.responses {
+                assert!(response.info().is_some());
+            }
+        }
+    }
+
+    #[test]
+    fn test_codec_lengths() {
+        for info in OPCODES {
+            assert!(info.min_operands <= info.max_operands);
+            assert!(!info.accepts_length(usize::from(info.max_operands) + 1));
+            let operands = vec![0; usize::from(info.max_operands) + 1];
+            assert!(CecMessage::decode(info.opcode, &operands).is_err());
+            if info.min_operands > 0 {
+                let operands = vec![0; usize::from(info.min_operands) - 1];
+                assert!(CecMessage::decode(info.opcode, &operands).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn test_addressing() {
+        assert!(Addressing::Broadcast.allows(true));
+        assert!(!Addressing::Broadcast.allows(false));
+        assert!(!Addressing::Directed.allows(true));
+        assert!(Addressing::Both.allows(false));
+    }
+}
@@ -0,0 +1,484 @@
+//! Queries: requests matched with their replies
+//!
+//! libcec has getters for a few queries only. For other requests, such as
+//! `<Give Deck Status>` or `<Give Tuner Device Status>`, the reply arrives later in the
+//! `commandReceived` callback. A [`Correlator`] is a [`CecHandler`] which takes those
+//! commands: [`Correlator::query`] transmits the request, waits for a reply from its destination
+//! to the initiator or broadcast, with one of the reply opcodes of [`crate::opcode::OPCODES`],
+//! or for a `<Feature Abort>` of the request, and gives up after a timeout. With the `safe`
+//! feature, `Connection::query` queries from the primary logical address of the connection.
+//!
+//! ```
+//! use std::sync::Arc;
+//! use std::thread;
+//!
+//! use libcec_sys::enums::{DeckInfo, LogicalAddress, StatusRequest};
+//! use libcec_sys::handler::CecHandler;
+//! use libcec_sys::message::{CecMessage, DeckStatus, GiveDeckStatus, Message};
+//! use libcec_sys::query::Correlator;
+//!
+//! let correlator = Arc::new(Correlator::new());
+//! // with the safe feature: Connection::init_with_handler(configuration, correlator.clone())
+//! let handler = Arc::clone(&correlator);
+//! let request = GiveDeckStatus { request: StatusRequest::Once }.into();
+//! let reply = correlator.query(
+//!     LogicalAddress::Tv,
+//!     LogicalAddress::PlaybackDevice1,
+//!     &request,
+//!     |_command| {
+//!         // libcec_transmit, then the device replies
+//!         let reply = DeckStatus { info: DeckInfo::Play }
+//!             .to_command(LogicalAddress::PlaybackDevice1, LogicalAddress::Tv)
+//!             .unwrap();
+//!         thread::spawn(move || handler.command_received(&reply));
+//!         Ok(())
+//!     },
+//! )?;
+//! assert_eq!(reply.message, CecMessage::DeckStatus(DeckStatus { info: DeckInfo::Play }));
+//! # Ok::<(), libcec_sys::query::QueryError>(())
+//! ```
+use std::error::Error;
+use std::fmt;
+use std::sync::{Condvar, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use crate::enums::{AbortReason, LogicalAddress, Opcode};
+use crate::error::CecError;
+use crate::handler::CecHandler;
+use crate::message::{CecMessage, FeatureAbort, Message, MessageError};
+use crate::*;
+
+/// Time a device has to reply, the maximum response time of the CEC specification
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Reply to a query
+#[derive(Debug, Clone)]
+pub struct Reply {
+    /// The received command
+    pub command: cec_command,
+    /// The decoded message of the command
+    pub message: CecMessage,
+}
+
+/// Error of a query
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueryError {
+    /// The request has no replies in the opcode table, so there is nothing to wait for
+    NoReply(Opcode),
+    /// Queries are directed, and cannot be sent to the broadcast address
+    BroadcastDestination,
+    /// The initiator is no logical address from 0 to 14, e.g. unregistered because the
+    /// connection has not claimed an address yet, so no reply could reach it
+    NoLogicalAddress,
+    /// The request could not be encoded
+    InvalidRequest(MessageError),
+    /// The request could not be transmitted
+    Transmit(CecError),
+    /// The destination refused the request with a `<Feature Abort>`
+    Aborted(AbortReason),
+    /// The reply has the opcode of a reply, but its operands do not decode
+    InvalidReply(MessageError),
+    /// No reply within the timeout
+    Timeout,
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QueryError::NoReply(opcode) => write!(f, "`{opcode}` has no reply to wait for"),
+            QueryError::BroadcastDestination => f.write_str("queries cannot be broadcast"),
+            QueryError::NoLogicalAddress => f.write_str("no logical address to query from"),
+            QueryError::InvalidRequest(err) => write!(f, "invalid request: {err}"),
+            QueryError::Transmit(err) => write!(f, "failed to transmit request: {err}"),
+            QueryError::Aborted(reason) => write!(f, "request aborted: {reason}"),
+            QueryError::InvalidReply(err) => write!(f, "invalid reply: {err}"),
+            QueryError::Timeout => f.write_str("no reply in time"),
+        }
+    }
+}
+
+impl Error for QueryError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            QueryError::InvalidRequest(err) | QueryError::InvalidReply(err) => Some(err),
+            QueryError::Transmit(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Query waiting for its reply
+struct Pending {
+    id: u64,
+    initiator: LogicalAddress,
+    destination: LogicalAddress,
+    request: Opcode,
+    replies: &'static [Opcode],
+    result: Option<Result<Reply, QueryError>>,
+}
+
+impl Pending {
+    /// Result of the query for `command` from its destination, `None` when it is no reply
+    fn matches(&self, command: &cec_command) -> Option<Result<Reply, QueryError>> {
+        let destination = LogicalAddress::from_raw(command.destination);
+        if command.opcode_set == 0
+            || LogicalAddress::from_raw(command.initiator) != self.destination
+            || !(destination == self.initiator || destination.is_broadcast())
+        {
+            return None;
+        }
+        let opcode = Opcode::from_raw(command.opcode);
+        if self.replies.contains(&opcode) {
+            return Some(
+                CecMessage::from_command(command)
+                    .map(|message| Reply {
+                        command: *command,
+                        message,
+                    })
+                    .map_err(QueryError::InvalidReply),
+            );
+        }
+        match FeatureAbort::from_command(command) {
+            Ok(abort) if abort.opcode == self.request => {
+                Some(Err(QueryError::Aborted(abort.reason)))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Default)]
+struct State {
+    next_id: u64,
+    pending: Vec<Pending>,
+}
+
+/// Matches received commands with the queries waiting for them
+///
+/// Register it as the handler of the connection, behind an `Arc`, or call
+/// [`Correlator::command_received`] from the handler.
+pub struct Correlator {
+    state: Mutex<State>,
+    replied: Condvar,
+    timeout: Duration,
+}
+
+impl Correlator {
+    /// Correlator waiting [`DEFAULT_TIMEOUT`] for replies
+    pub fn new() -> Self {
+        Correlator {
+            state: Mutex::new(State::default()),
+            replied: Condvar::new(),
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    /// Fail queries with [`QueryError::Timeout`] when the reply takes longer than `timeout`
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        // the state is plain data, so a panic while holding the lock cannot break it
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// Send `request` from `initiator` to `destination` with `transmit`, and wait for its
+    /// reply
+    ///
+    /// The query waits from before the request is transmitted, so that fast replies are not
+    /// missed. Concurrent queries for the same reply get the replies in order. `initiator` must
+    /// be a logical address from 0 to 14, to get the replies.
+    pub fn query<F>(
+        &self,
+        initiator: LogicalAddress,
+        destination: LogicalAddress,
+        request: &CecMessage,
+        transmit: F,
+    ) -> Result<Reply, QueryError>
+    where
+        F: FnOnce(&cec_command) -> Result<(), CecError>,
+    {
+        let opcode = request.opcode();
+        let replies = opcode.info().map_or(&[][..], |info| info.responses);
+        if replies.is_empty() {
+            return Err(QueryError::NoReply(opcode));
+        }
+        if destination.is_broadcast() {
+            return Err(QueryError::BroadcastDestination);
+        }
+        if initiator.index().is_none() || initiator == LogicalAddress::UNREGISTERED {
+            return Err(QueryError::NoLogicalAddress);
+        }
+        let command = request
+            .to_command(initiator, destination)
+            .map_err(QueryError::InvalidRequest)?;
+
+        let id = {
+            let mut state = self.lock();
+            let id = state.next_id;
+            state.next_id += 1;
+            state.pending.push(Pending {
+                id,
+                initiator,
+                destination,
+                request: opcode,
+                replies,
+                result: None,
+            });
+            id
+        };
+        let result = transmit(&command)
+            .map_err(QueryError::Transmit)
+            .and_then(|()| self.wait(id));
+        self.lock().pending.retain(|pending| pending.id != id);
+        result
+    }
+
+    fn wait(&self, id: u64) -> Result<Reply, QueryError> {
+        let deadline = Instant::now() + self.timeout;
+        let mut state = self.lock();
+        loop {
+            let pending = state.pending.iter_mut().find(|pending| pending.id == id);
+            if let Some(result) = pending.and_then(|pending| pending.result.take()) {
+                return result;
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(QueryError::Timeout);
+            }
+            state = self
+                .replied
+                .wait_timeout(state, deadline - now)
+                .unwrap_or_else(|err| err.into_inner())
+                .0;
+        }
+    }
+
+    /// Resolve the oldest query which `command` replies to, returning whether there was one
+    pub fn command_received(&self, command: &cec_command) -> bool {
+        let mut state = self.lock();
+        let resolved = state
+            .pending
+            .iter_mut()
+            .filter(|pending| pending.result.is_none())
+            .find_map(|pending| {
+                let result = pending.matches(command)?;
+                Some((pending, result))
+            });
+        let Some((pending, result)) = resolved else {
+            return false;
+        };
+        pending.result = Some(result);
+        self.replied.notify_all();
+        true
+    }
+
+    /// Number of queries waiting for a reply
+    pub fn pending(&self) -> usize {
+        self.lock()
+            .pending
+            .iter()
+            .filter(|pending| pending.result.is_none())
+            .count()
+    }
+}
+
+impl Default for Correlator {
+    fn default() -> Self {
+        Correlator::new()
+    }
+}
+
+impl fmt::Debug for Correlator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Correlator")
+            .field("pending", &self.pending())
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+impl CecHandler for Correlator {
+    fn command_received(&self, command: &cec_command) {
+        Correlator::command_received(self, command);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::thread;
+
+    use super::*;
+    use crate::address::PhysicalAddress;
+    use crate::enums::{DeviceType, PowerStatus, StatusRequest};
+    use crate::message::{
+        GiveDeckStatus, GiveDevicePowerStatus, GivePhysicalAddress, ReportPhysicalAddress,
+        ReportPowerStatus, Standby,
+    };
+
+    const TV: LogicalAddress = LogicalAddress::Tv;
+    const PLAYBACK: LogicalAddress = LogicalAddress::PlaybackDevice1;
+
+    fn power_status() -> CecMessage {
+        GiveDevicePowerStatus.into()
+    }
+
+    /// Query from the TV to the playback device, which answers with `reply` from `initiator`
+    /// to `destination`
+    fn query_with_reply(
+        correlator: &Arc<Correlator>,
+        request: CecMessage,
+        reply: impl Into<CecMessage>,
+        initiator: LogicalAddress,
+        destination: LogicalAddress,
+    ) -> Result<Reply, QueryError> {
+        let reply = reply.into().to_command(initiator, destination).unwrap();
+        let handler = Arc::clone(correlator);
+        correlator.query(TV, PLAYBACK, &request, |_| {
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(10));
+                handler.command_received(&reply);
+            });
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_reply() {
+        let correlator = Arc::new(Correlator::new());
+        let reply = query_with_reply(
+            &correlator,
+            power_status(),
+            ReportPowerStatus {
+                status: PowerStatus::Standby,
+            },
+            PLAYBACK,
+            TV,
+        )
+        .unwrap();
+        assert_eq!(
+            reply.message,
+            CecMessage::ReportPowerStatus(ReportPowerStatus {
+                status: PowerStatus::Standby
+            })
+        );
+        assert_eq!(correlator.pending(), 0);
+    }
+
+    #[test]
+    fn test_broadcast_reply() {
+        let correlator = Arc::new(Correlator::new());
+        let report = ReportPhysicalAddress {
+            physical_address: PhysicalAddress::from_raw(0x1000),
+            device_type: DeviceType::PlaybackDevice,
+        };
+        let reply = query_with_reply(
+            &correlator,
+            GivePhysicalAddress.into(),
+            report.clone(),
+            PLAYBACK,
+            LogicalAddress::Broadcast,
+        )
+        .unwrap();
+        assert_eq!(reply.message, CecMessage::ReportPhysicalAddress(report));
+    }
+
+    #[test]
+    fn test_feature_abort() {
+        let correlator = Arc::new(Correlator::new());
+        let abort = FeatureAbort {
+            opcode: Opcode::GiveDeckStatus,
+            reason: AbortReason::UnrecognizedOpcode,
+        };
+        let request = GiveDeckStatus {
+            request: StatusRequest::Once,
+        };
+        assert_eq!(
+            query_with_reply(&correlator, request.into(), abort, PLAYBACK, TV).err(),
+            Some(QueryError::Aborted(AbortReason::UnrecognizedOpcode))
+        );
+    }
+
+    #[test]
+    fn test_timeout() {
+        let correlator = Arc::new(Correlator::new().with_timeout(Duration::from_millis(50)));
+        // reply from another device
+        let reply = ReportPowerStatus {
+            status: PowerStatus::On,
+        };
+        assert_eq!(
+            query_with_reply(
+                &correlator,
+                power_status(),
+                reply.clone(),
+                LogicalAddress::Tuner1,
+                TV
+            )
+            .err(),
+            Some(QueryError::Timeout)
+        );
+        // reply to another device
+        assert_eq!(
+            query_with_reply(
+                &correlator,
+                power_status(),
+                reply,
+                PLAYBACK,
+                LogicalAddress::AudioSystem
+            )
+            .err(),
+            Some(QueryError::Timeout)
+        );
+        // feature abort of another request
+        let abort = FeatureAbort {
+            opcode: Opcode::GiveDeckStatus,
+            reason: AbortReason::Refused,
+        };
+        assert_eq!(
+            query_with_reply(&correlator, power_status(), abort, PLAYBACK, TV).err(),
+            Some(QueryError::Timeout)
+        );
+        assert_eq!(correlator.pending(), 0);
+    }
+
+    #[test]
+    fn test_invalid_queries() {
+        let correlator = Correlator::new();
+        let never = |_: &cec_command| -> Result<(), CecError> { panic!("transmitted") };
+        assert_eq!(
+            correlator.query(TV, PLAYBACK, &Standby.into(), never).err(),
+            Some(QueryError::NoReply(Opcode::Standby))
+        );
+        assert_eq!(
+            correlator
+                .query(TV, LogicalAddress::Broadcast, &power_status(), never)
+                .err(),
+            Some(QueryError::BroadcastDestination)
+        );
+        for initiator in [LogicalAddress::UNREGISTERED, LogicalAddress::UNKNOWN] {
+            assert_eq!(
+                correlator
+                    .query(initiator, PLAYBACK, &power_status(), never)
+                    .err(),
+                Some(QueryError::NoLogicalAddress)
+            );
+        }
+        assert_eq!(
+            correlator
+                .query(TV, PLAYBACK, &power_status(), |_| Err(
+                    CecError::TransmitFailed
+                ))
+                .err(),
+            Some(QueryError::Transmit(CecError::TransmitFailed))
+        );
+        assert_eq!(correlator.pending(), 0);
+        assert!(!correlator.command_received(&cec_command::default()));
+    }
+}
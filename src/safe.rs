@@ -0,0 +1,646 @@
+//! Safe wrapper around a libcec connection
+//!
+//! [`Connection`] owns the `libcec_connection_t` handle, closing and destroying it when dropped,
+//! and keeps the callbacks passed to libcec alive for as long as libcec may call them.
+//!
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use libcec_sys::enums::LogicalAddress;
+//! use libcec_sys::safe::Connection;
+//! use libcec_sys::strings::ConfigurationExt;
+//! use libcec_sys::libcec_configuration;
+//!
+//! let mut configuration = libcec_configuration::default();
+//! unsafe { libcec_sys::libcec_clear_configuration(&mut configuration) };
+//! configuration.set_device_name("example").unwrap();
+//!
+//! let mut connection = Connection::init(configuration)?;
+//! connection.open("RPI", Duration::from_secs(5))?;
+//! connection.power_on_devices(LogicalAddress::Tv)?;
+//! # Ok::<(), libcec_sys::safe::Error>(())
+//! ```
+use std::any::Any;
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::os::raw::c_void;
+use std::path::Path;
+use std::ptr;
+use std::time::Duration;
+
+use crate::adapter::{self, Adapter, AdapterDescriptor};
+use crate::address::{LogicalAddresses, PhysicalAddress};
+use crate::enums::{
+    CecVersion, DeckControlMode, DeckInfo, DeviceType, DisplayControl, LogicalAddress, MenuState,
+    PowerStatus, UserControlCode,
+};
+use crate::feature_abort::AbortTracker;
+use crate::handler::{self, CecHandler};
+use crate::message::CecMessage;
+use crate::query::{Correlator, QueryError, Reply};
+use crate::strings;
+use crate::validation;
+use crate::*;
+
+pub use crate::error::CecError as Error;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+fn c_string(s: &str) -> Result<CString> {
+    CString::new(s).map_err(|_| Error::NulInString)
+}
+
+/// `path` as a C string, or NULL for `None`
+fn c_path(path: Option<&Path>) -> Result<Option<CString>> {
+    path.map(|path| {
+        path.to_str()
+            .ok_or_else(|| Error::InvalidPath(path.to_owned()))
+            .and_then(c_string)
+    })
+    .transpose()
+}
+
+fn timeout_ms(timeout: Duration) -> u32 {
+    timeout.as_millis().try_into().unwrap_or(u32::MAX)
+}
+
+/// Callbacks and their parameter, which libcec holds pointers to
+struct CallbackData {
+    callbacks: *mut ICECCallbacks,
+    param: *mut (dyn Any + Send + Sync),
+}
+
+impl CallbackData {
+    fn new<T: Send + Sync + 'static>(callbacks: ICECCallbacks, param: T) -> Self {
+        let param: Box<dyn Any + Send + Sync> = Box::new(param);
+        CallbackData {
+            callbacks: Box::into_raw(Box::new(callbacks)),
+            param: Box::into_raw(param),
+        }
+    }
+}
+
+impl Drop for CallbackData {
+    fn drop(&mut self) {
+        // SAFETY: both come from Box::into_raw, and libcec no longer uses them
+        unsafe {
+            drop(Box::from_raw(self.callbacks));
+            drop(Box::from_raw(self.param));
+        }
+    }
+}
+
+/// libcec connection, closed and destroyed on drop
+pub struct Connection {
+    handle: libcec_connection_t,
+    opened: bool,
+    // dropped after the handle is destroyed
+    callback_data: Option<CallbackData>,
+}
+
+// SAFETY: libcec connections are internally synchronized, and the callback parameter is Send
+// and Sync
+unsafe impl Send for Connection {}
+unsafe impl Sync for Connection {}
+
+impl Connection {
+    /// Initialise libcec with `configuration`, without callbacks
+    ///
+    /// Any `callbacks` and `callbackParam` of the configuration are ignored.
+    pub fn init(mut configuration: libcec_configuration) -> Result<Self> {
+        configuration.callbacks = ptr::null_mut();
+        configuration.callbackParam = ptr::null_mut();
+        Self::init_raw(configuration, None)
+    }
+
+    /// Initialise libcec with `configuration`, calling `callbacks` with a pointer to
+    /// `callback_param` as `cbparam`
+    ///
+    /// Both are owned by the connection, and live until it is dropped.
+    pub fn init_with_callbacks<T: Send + Sync + 'static>(
+        mut configuration: libcec_configuration,
+        callbacks: ICECCallbacks,
+        callback_param: T,
+    ) -> Result<Self> {
+        let callback_data = CallbackData::new(callbacks, callback_param);
+        configuration.callbacks = callback_data.callbacks;
+        configuration.callbackParam = callback_data.param.cast::<c_void>();
+        Self::init_raw(configuration, Some(callback_data))
+    }
+
+    /// Initialise libcec with `configuration`, calling `handler` on callbacks
+    ///
+    /// The handler is owned by the connection, and lives until it is dropped.
+    pub fn init_with_handler<H: CecHandler + 'static>(
+        configuration: libcec_configuration,
+        handler: H,
+    ) -> Result<Self> {
+        Self::init_with_callbacks(configuration, handler::callbacks::<H>(), handler)
+    }
+
+    fn init_raw(
+        mut configuration: libcec_configuration,
+        callback_data: Option<CallbackData>,
+    ) -> Result<Self> {
+        let handle = Error::check_initialise(unsafe { libcec_initialise(&mut configuration) })?;
+        Ok(Connection {
+            handle,
+            opened: false,
+            callback_data,
+        })
+    }
+
+    /// Raw handle, for libcec functions without a safe counterpart
+    ///
+    /// The handle must not be closed or destroyed.
+    pub fn as_raw(&self) -> libcec_connection_t {
+        self.handle
+    }
+
+    /// Open the adapter at `port`, e.g. a path from `libcec_detect_adapters`
+    pub fn open(&mut self, port: &str, timeout: Duration) -> Result<()> {
+        let c_port = c_string(port)?;
+        Error::check_open(
+            unsafe { libcec_open(self.handle, c_port.as_ptr(), timeout_ms(timeout)) },
+            port,
+        )?;
+        self.opened = true;
+        Ok(())
+    }
+
+    /// Close the adapter, if open. The connection can be opened again.
+    pub fn close(&mut self) {
+        if self.opened {
+            unsafe { libcec_close(self.handle) };
+            self.opened = false;
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.opened
+    }
+
+    /// Adapters connected to the system, or only the one at `path`
+    ///
+    /// A quick scan skips reading the firmware version and build date of Pulse-Eight adapters.
+    pub fn detect_adapters(
+        &self,
+        quick_scan: bool,
+        path: Option<&Path>,
+    ) -> Result<Vec<AdapterDescriptor>> {
+        let path = c_path(path)?;
+        let path = path.as_ref().map_or(ptr::null(), |path| path.as_ptr());
+        let descriptors = adapter::collect(|buffer: &mut [cec_adapter_descriptor]| unsafe {
+            libcec_detect_adapters(
+                self.handle,
+                buffer.as_mut_ptr(),
+                buffer.len() as u8,
+                path,
+                quick_scan.into(),
+            )
+        })?;
+        Ok(descriptors.iter().map(AdapterDescriptor::from).collect())
+    }
+
+    /// Paths of the adapters connected to the system, or only the one at `path`
+    pub fn find_adapters(&self, path: Option<&Path>) -> Result<Vec<Adapter>> {
+        let path = c_path(path)?;
+        let path = path.as_ref().map_or(ptr::null(), |path| path.as_ptr());
+        let adapters = adapter::collect(|buffer: &mut [cec_adapter]| unsafe {
+            libcec_find_adapters(self.handle, buffer.as_mut_ptr(), buffer.len() as u8, path)
+        })?;
+        Ok(adapters.iter().map(Adapter::from).collect())
+    }
+
+    pub fn ping_adapters(&self) -> Result<()> {
+        Error::check("libcec_ping_adapters", unsafe {
+            libcec_ping_adapters(self.handle)
+        })
+    }
+
+    pub fn start_bootloader(&self) -> Result<()> {
+        Error::check("libcec_start_bootloader", unsafe {
+            libcec_start_bootloader(self.handle)
+        })
+    }
+
+    pub fn transmit(&self, command: &cec_command) -> Result<()> {
+        Error::check_transmit(unsafe { libcec_transmit(self.handle, command) })
+    }
+
+    /// Transmit `command` only when it follows the CEC specification of `version`, failing
+    /// with [`Error::InvalidCommand`] otherwise
+    pub fn transmit_checked(
+        &self,
+        command: &cec_command,
+        version: CecVersion,
+        mode: validation::Mode,
+    ) -> Result<()> {
+        let violations = validation::validate_with_mode(command, version, mode);
+        if !violations.is_empty() {
+            return Err(Error::InvalidCommand(violations));
+        }
+        self.transmit(command)
+    }
+
+    /// Transmit `command` unless its destination refused its opcode, failing with
+    /// [`Error::Refused`] then
+    ///
+    /// `tracker` must receive the commands of the connection, see [`crate::feature_abort`].
+    pub fn transmit_tracked(&self, tracker: &AbortTracker, command: &cec_command) -> Result<()> {
+        tracker.transmit(command, |command| self.transmit(command))
+    }
+
+    /// Send `request` to `destination` from the primary logical address, and wait for the
+    /// reply
+    ///
+    /// `correlator` must receive the commands of the connection, see [`crate::query`]. Fails
+    /// with [`QueryError::NoLogicalAddress`] when no logical address is claimed, e.g. before
+    /// [`Connection::open`].
+    pub fn query(
+        &self,
+        correlator: &Correlator,
+        destination: LogicalAddress,
+        request: &CecMessage,
+    ) -> std::result::Result<Reply, QueryError> {
+        let initiator = self.logical_addresses().primary();
+        correlator.query(initiator, destination, request, |command| {
+            self.transmit(command)
+        })
+    }
+
+    pub fn power_on_devices(&self, address: LogicalAddress) -> Result<()> {
+        Error::check("libcec_power_on_devices", unsafe {
+            libcec_power_on_devices(self.handle, address.raw())
+        })
+    }
+
+    pub fn standby_devices(&self, address: LogicalAddress) -> Result<()> {
+        Error::check("libcec_standby_devices", unsafe {
+            libcec_standby_devices(self.handle, address.raw())
+        })
+    }
+
+    pub fn set_active_source(&self, device_type: DeviceType) -> Result<()> {
+        Error::check("libcec_set_active_source", unsafe {
+            libcec_set_active_source(self.handle, device_type.raw())
+        })
+    }
+
+    pub fn set_inactive_view(&self) -> Result<()> {
+        Error::check("libcec_set_inactive_view", unsafe {
+            libcec_set_inactive_view(self.handle)
+        })
+    }
+
+    pub fn set_deck_control_mode(&self, mode: DeckControlMode, send_update: bool) -> Result<()> {
+        Error::check("libcec_set_deck_control_mode", unsafe {
+            libcec_set_deck_control_mode(self.handle, mode.raw(), send_update.into())
+        })
+    }
+
+    pub fn set_deck_info(&self, info: DeckInfo, send_update: bool) -> Result<()> {
+        Error::check("libcec_set_deck_info", unsafe {
+            libcec_set_deck_info(self.handle, info.raw(), send_update.into())
+        })
+    }
+
+    pub fn set_menu_state(&self, state: MenuState, send_update: bool) -> Result<()> {
+        Error::check("libcec_set_menu_state", unsafe {
+            libcec_set_menu_state(self.handle, state.raw(), send_update.into())
+        })
+    }
+
+    pub fn set_logical_address(&self, address: LogicalAddress) -> Result<()> {
+        Error::check("libcec_set_logical_address", unsafe {
+            libcec_set_logical_address(self.handle, address.raw())
+        })
+    }
+
+    pub fn set_physical_address(&self, physical_address: PhysicalAddress) -> Result<()> {
+        Error::check("libcec_set_physical_address", unsafe {
+            libcec_set_physical_address(self.handle, physical_address.raw())
+        })
+    }
+
+    pub fn set_hdmi_port(&self, base_device: LogicalAddress, port: u8) -> Result<()> {
+        Error::check("libcec_set_hdmi_port", unsafe {
+            libcec_set_hdmi_port(self.handle, base_device.raw(), port)
+        })
+    }
+
+    pub fn set_osd_string(
+        &self,
+        address: LogicalAddress,
+        duration: DisplayControl,
+        message: &str,
+    ) -> Result<()> {
+        let message = c_string(message)?;
+        Error::check("libcec_set_osd_string", unsafe {
+            libcec_set_osd_string(self.handle, address.raw(), duration.raw(), message.as_ptr())
+        })
+    }
+
+    pub fn switch_monitoring(&self, enable: bool) -> Result<()> {
+        Error::check("libcec_switch_monitoring", unsafe {
+            libcec_switch_monitoring(self.handle, enable.into())
+        })
+    }
+
+    pub fn device_cec_version(&self, address: LogicalAddress) -> CecVersion {
+        CecVersion::from_raw(unsafe { libcec_get_device_cec_version(self.handle, address.raw()) })
+    }
+
+    pub fn device_menu_language(&self, address: LogicalAddress) -> Result<String> {
+        let mut language: cec_menu_language = [0; 4];
+        Error::check("libcec_get_device_menu_language", unsafe {
+            libcec_get_device_menu_language(self.handle, address.raw(), language.as_mut_ptr())
+        })?;
+        Ok(String::from_utf8_lossy(strings::c_bytes(&language)).into_owned())
+    }
+
+    pub fn device_vendor_id(&self, address: LogicalAddress) -> u32 {
+        unsafe { libcec_get_device_vendor_id(self.handle, address.raw()) }
+    }
+
+    pub fn device_physical_address(&self, address: LogicalAddress) -> PhysicalAddress {
+        PhysicalAddress::from_raw(unsafe {
+            libcec_get_device_physical_address(self.handle, address.raw())
+        })
+    }
+
+    pub fn device_power_status(&self, address: LogicalAddress) -> PowerStatus {
+        PowerStatus::from_raw(unsafe { libcec_get_device_power_status(self.handle, address.raw()) })
+    }
+
+    pub fn device_osd_name(&self, address: LogicalAddress) -> Result<String> {
+        let mut name: cec_osd_name = [0; 14];
+        Error::check("libcec_get_device_osd_name", unsafe {
+            libcec_get_device_osd_name(self.handle, address.raw(), name.as_mut_ptr())
+        })?;
+        Ok(String::from_utf8_lossy(strings::c_bytes(&name)).into_owned())
+    }
+
+    pub fn poll_device(&self, address: LogicalAddress) -> Result<()> {
+        Error::check("libcec_poll_device", unsafe {
+            libcec_poll_device(self.handle, address.raw())
+        })
+    }
+
+    pub fn active_source(&self) -> LogicalAddress {
+        LogicalAddress::from_raw(unsafe { libcec_get_active_source(self.handle) })
+    }
+
+    pub fn is_active_source(&self, address: LogicalAddress) -> bool {
+        unsafe { libcec_is_active_source(self.handle, address.raw()) != 0 }
+    }
+
+    pub fn is_libcec_active_source(&self) -> bool {
+        unsafe { libcec_is_libcec_active_source(self.handle) != 0 }
+    }
+
+    pub fn active_devices(&self) -> LogicalAddresses {
+        unsafe { libcec_get_active_devices(self.handle) }.into()
+    }
+
+    pub fn is_active_device(&self, address: LogicalAddress) -> bool {
+        unsafe { libcec_is_active_device(self.handle, address.raw()) != 0 }
+    }
+
+    pub fn is_active_device_type(&self, device_type: DeviceType) -> bool {
+        unsafe { libcec_is_active_device_type(self.handle, device_type.raw()) != 0 }
+    }
+
+    pub fn logical_addresses(&self) -> LogicalAddresses {
+        unsafe { libcec_get_logical_addresses(self.handle) }.into()
+    }
+
+    pub fn set_stream_path_logical(&self, address: LogicalAddress) -> Result<()> {
+        Error::check("libcec_set_stream_path_logical", unsafe {
+            libcec_set_stream_path_logical(self.handle, address.raw())
+        })
+    }
+
+    pub fn set_stream_path_physical(&self, physical_address: PhysicalAddress) -> Result<()> {
+        Error::check("libcec_set_stream_path_physical", unsafe {
+            libcec_set_stream_path_physical(self.handle, physical_address.raw())
+        })
+    }
+
+    pub fn volume_up(&self, send_release: bool) -> Result<()> {
+        Error::check("libcec_volume_up", unsafe {
+            libcec_volume_up(self.handle, send_release.into())
+        })
+    }
+
+    pub fn volume_down(&self, send_release: bool) -> Result<()> {
+        Error::check("libcec_volume_down", unsafe {
+            libcec_volume_down(self.handle, send_release.into())
+        })
+    }
+
+    pub fn mute_audio(&self, send_release: bool) -> Result<()> {
+        Error::check("libcec_mute_audio", unsafe {
+            libcec_mute_audio(self.handle, send_release.into())
+        })
+    }
+
+    /// Toggle mute, returning the audio status
+    pub fn audio_toggle_mute(&self) -> u8 {
+        unsafe { libcec_audio_toggle_mute(self.handle) }
+    }
+
+    /// Mute, returning the audio status
+    pub fn audio_mute(&self) -> u8 {
+        unsafe { libcec_audio_mute(self.handle) }
+    }
+
+    /// Unmute, returning the audio status
+    pub fn audio_unmute(&self) -> u8 {
+        unsafe { libcec_audio_unmute(self.handle) }
+    }
+
+    pub fn audio_status(&self) -> u8 {
+        unsafe { libcec_audio_get_status(self.handle) }
+    }
+
+    #[cfg(abi7)]
+    pub fn system_audio_mode(&self, enable: bool) -> Result<()> {
+        Error::check("libcec_system_audio_mode", unsafe {
+            libcec_system_audio_mode(self.handle, enable.into())
+        })
+    }
+
+    #[cfg(abi7)]
+    pub fn system_audio_mode_status(&self) -> u8 {
+        unsafe { libcec_system_audio_mode_get_status(self.handle) }
+    }
+
+    pub fn send_keypress(
+        &self,
+        destination: LogicalAddress,
+        key: UserControlCode,
+        wait: bool,
+    ) -> Result<()> {
+        Error::check("libcec_send_keypress", unsafe {
+            libcec_send_keypress(self.handle, destination.raw(), key.raw(), wait.into())
+        })
+    }
+
+    pub fn send_key_release(&self, destination: LogicalAddress, wait: bool) -> Result<()> {
+        Error::check("libcec_send_key_release", unsafe {
+            libcec_send_key_release(self.handle, destination.raw(), wait.into())
+        })
+    }
+
+    pub fn current_configuration(&self) -> Result<libcec_configuration> {
+        let mut configuration = libcec_configuration::default();
+        Error::check("libcec_get_current_configuration", unsafe {
+            libcec_get_current_configuration(self.handle, &mut configuration)
+        })?;
+        Ok(configuration)
+    }
+
+    /// Change the configuration. The callbacks of the connection are kept.
+    pub fn set_configuration(&self, configuration: &libcec_configuration) -> Result<()> {
+        let mut configuration = *configuration;
+        configuration.callbacks = self.callbacks_ptr();
+        configuration.callbackParam = self.callback_param_ptr();
+        Error::check("libcec_set_configuration", unsafe {
+            libcec_set_configuration(self.handle, &configuration)
+        })
+    }
+
+    pub fn can_save_configuration(&self) -> bool {
+        cfg_if::cfg_if! {
+            if #[cfg(any(abi4, abi5))] {
+                unsafe { libcec_can_persist_configuration(self.handle) != 0 }
+            } else {
+                unsafe { libcec_can_save_configuration(self.handle) != 0 }
+            }
+        }
+    }
+
+    pub fn rescan_devices(&self) {
+        unsafe { libcec_rescan_devices(self.handle) }
+    }
+
+    /// Read the configuration of the adapter at `port`, which must not be open
+    pub fn device_information(
+        &self,
+        port: &str,
+        timeout: Duration,
+    ) -> Result<libcec_configuration> {
+        let c_port = c_string(port)?;
+        let mut configuration = libcec_configuration::default();
+        Error::check("libcec_get_device_information", unsafe {
+            libcec_get_device_information(
+                self.handle,
+                c_port.as_ptr(),
+                &mut configuration,
+                timeout_ms(timeout),
+            )
+        })?;
+        Ok(configuration)
+    }
+
+    /// Version and build information of libcec
+    pub fn lib_info(&self) -> String {
+        let info = unsafe { libcec_get_lib_info(self.handle) };
+        if info.is_null() {
+            return String::new();
+        }
+        unsafe { CStr::from_ptr(info) }
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    pub fn init_video_standalone(&self) {
+        unsafe { libcec_init_video_standalone(self.handle) }
+    }
+
+    pub fn adapter_vendor_id(&self) -> u16 {
+        unsafe { libcec_get_adapter_vendor_id(self.handle) }
+    }
+
+    pub fn adapter_product_id(&self) -> u16 {
+        unsafe { libcec_get_adapter_product_id(self.handle) }
+    }
+
+    /// Replace the callbacks of the connection with ones calling `handler`
+    pub fn set_handler<H: CecHandler + 'static>(&mut self, handler: H) -> Result<()> {
+        let callback_data = CallbackData::new(handler::callbacks::<H>(), handler);
+        let param = callback_data.param.cast::<c_void>();
+        cfg_if::cfg_if! {
+            if #[cfg(any(abi4, abi5))] {
+                Error::check("libcec_enable_callbacks", unsafe {
+                    libcec_enable_callbacks(self.handle, param, callback_data.callbacks)
+                })?;
+            } else {
+                Error::check("libcec_set_callbacks", unsafe {
+                    libcec_set_callbacks(self.handle, callback_data.callbacks, param)
+                })?;
+            }
+        }
+        // the previous callbacks are no longer used by libcec
+        self.callback_data = Some(callback_data);
+        Ok(())
+    }
+
+    fn callbacks_ptr(&self) -> *mut ICECCallbacks {
+        self.callback_data
+            .as_ref()
+            .map_or(ptr::null_mut(), |data| data.callbacks)
+    }
+
+    fn callback_param_ptr(&self) -> *mut c_void {
+        self.callback_data
+            .as_ref()
+            .map_or(ptr::null_mut(), |data| data.param.cast::<c_void>())
+    }
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        self.close();
+        unsafe { libcec_destroy(self.handle) };
+    }
+}
+
+impl fmt::Debug for Connection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Connection")
+            .field("handle", &self.handle)
+            .field("opened", &self.opened)
+            .field("callbacks", &self.callback_data.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_c_string() {
+        assert_eq!(c_string("a\0b"), Err(Error::NulInString));
+    }
+
+    #[test]
+    fn test_c_path() {
+        assert_eq!(c_path(None), Ok(None));
+        assert_eq!(
+            c_path(Some(Path::new("/dev/cec0")))
+                .unwrap()
+                .unwrap()
+                .as_bytes(),
+            b"/dev/cec0"
+        );
+    }
+
+    #[test]
+    fn test_timeout_ms() {
+        assert_eq!(timeout_ms(Duration::from_secs(5)), 5000);
+        assert_eq!(timeout_ms(Duration::MAX), u32::MAX);
+    }
+}
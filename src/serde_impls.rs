@@ -0,0 +1,388 @@
+//! serde support for the plain data types of the bindings
+//!
+//! Fields keep their C names. C strings are (lossily) converted to UTF-8 strings, fixed size
+//! arrays to sequences and enums to their [`crate::enums`] counterparts, which serialize by
+//! variant name, the C `UNKNOWN` values as `UNKNOWN` and other values not known to libcec as
+//! `{"Unknown": 200}`. Pointers (`callbacks`, `callbackParam`) are skipped and deserialize as
+//! null.
+#![allow(non_snake_case)]
+
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::enums::{AdapterType, CecVersion, DeviceType, LogicalAddress, Opcode, UserControlCode};
+use crate::strings;
+use crate::*;
+
+/// `#[serde(with)]` module for a C enum, going through its Rust enum
+macro_rules! enum_with {
+    ($module:ident, $c_ty:ty, $ty:ident) => {
+        mod $module {
+            use super::*;
+
+            pub fn serialize<S: Serializer>(raw: &$c_ty, serializer: S) -> Result<S::Ok, S::Error> {
+                $ty::from_raw(*raw).serialize(serializer)
+            }
+
+            pub fn deserialize<'de, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<$c_ty, D::Error> {
+                $ty::deserialize(deserializer).map($ty::raw)
+            }
+        }
+    };
+}
+
+enum_with!(adapter_type, cec_adapter_type, AdapterType);
+enum_with!(version, cec_version, CecVersion);
+enum_with!(logical_address, cec_logical_address, LogicalAddress);
+enum_with!(opcode, cec_opcode, Opcode);
+enum_with!(user_control_code, cec_user_control_code, UserControlCode);
+
+/// NUL-terminated C string in a fixed size buffer
+mod c_string {
+    use super::*;
+
+    pub fn serialize<S: Serializer, const N: usize>(
+        chars: &[c_char; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&String::from_utf8_lossy(strings::c_bytes(chars)))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<[c_char; N], D::Error> {
+        // room for the terminating NUL
+        super::fixed_string::from_str(&String::deserialize(deserializer)?, N - 1)
+    }
+}
+
+/// String in a fixed size buffer, NUL-terminated only when shorter than the buffer
+mod fixed_string {
+    use super::*;
+
+    pub fn serialize<S: Serializer, const N: usize>(
+        chars: &[c_char; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        super::c_string::serialize(chars, serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<[c_char; N], D::Error> {
+        from_str(&String::deserialize(deserializer)?, N)
+    }
+
+    pub fn from_str<E: serde::de::Error, const N: usize>(
+        s: &str,
+        max_len: usize,
+    ) -> Result<[c_char; N], E> {
+        let mut chars = [0; N];
+        strings::set_str(&mut chars, s, max_len).map_err(E::custom)?;
+        Ok(chars)
+    }
+}
+
+/// Device types, as a sequence of [`DeviceType`]
+mod device_types {
+    use super::*;
+
+    pub fn serialize<S: Serializer, const N: usize>(
+        types: &[cec_device_type; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(types.iter().map(|&raw| DeviceType::from_raw(raw)))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<[cec_device_type; N], D::Error> {
+        let types = Vec::<DeviceType>::deserialize(deserializer)?;
+        let types: Vec<cec_device_type> = types.into_iter().map(DeviceType::raw).collect();
+        types.try_into().map_err(|types: Vec<_>| {
+            D::Error::invalid_length(types.len(), &&*format!("{N} device types"))
+        })
+    }
+}
+
+/// Flags per logical address, as a sequence
+mod address_flags {
+    use super::*;
+
+    pub fn serialize<S: Serializer, const N: usize>(
+        flags: &[c_int; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(flags)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<[c_int; N], D::Error> {
+        Vec::<c_int>::deserialize(deserializer)?
+            .try_into()
+            .map_err(|flags: Vec<_>| D::Error::invalid_length(flags.len(), &&*format!("{N} flags")))
+    }
+}
+
+/// Implement `Serialize` and `Deserialize` with a `#[serde(remote)]` definition
+macro_rules! impl_remote {
+    ($c_ty:ident, $remote:ident) => {
+        impl Serialize for $c_ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                $remote::serialize(self, serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $c_ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                $remote::deserialize(deserializer)
+            }
+        }
+    };
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "cec_keypress")]
+struct Keypress {
+    #[serde(with = "user_control_code")]
+    keycode: cec_user_control_code,
+    duration: std::os::raw::c_uint,
+}
+impl_remote!(cec_keypress, Keypress);
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "cec_logical_addresses")]
+struct LogicalAddresses {
+    #[serde(with = "logical_address")]
+    primary: cec_logical_address,
+    #[serde(with = "address_flags")]
+    addresses: [c_int; 16],
+}
+impl_remote!(cec_logical_addresses, LogicalAddresses);
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "cec_device_type_list")]
+struct DeviceTypeList {
+    #[serde(with = "device_types")]
+    types: [cec_device_type; 5],
+}
+impl_remote!(cec_device_type_list, DeviceTypeList);
+
+/// Only the `size` used bytes of `data`, as a sequence
+impl Serialize for cec_datapacket {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let size = usize::from(self.size).min(self.data.len());
+        serializer.collect_seq(&self.data[..size])
+    }
+}
+
+impl<'de> Deserialize<'de> for cec_datapacket {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = Vec::<u8>::deserialize(deserializer)?;
+        let mut packet = cec_datapacket {
+            data: [0; 64],
+            size: 0,
+        };
+        if bytes.len() > packet.data.len() {
+            return Err(D::Error::invalid_length(bytes.len(), &"at most 64 bytes"));
+        }
+        packet.data[..bytes.len()].copy_from_slice(&bytes);
+        packet.size = bytes.len() as u8;
+        Ok(packet)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "cec_command")]
+struct Command {
+    #[serde(with = "logical_address")]
+    initiator: cec_logical_address,
+    #[serde(with = "logical_address")]
+    destination: cec_logical_address,
+    ack: i8,
+    eom: i8,
+    #[serde(with = "opcode")]
+    opcode: cec_opcode,
+    parameters: cec_datapacket,
+    opcode_set: i8,
+    transmit_timeout: i32,
+}
+impl_remote!(cec_command, Command);
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "cec_adapter_descriptor")]
+struct AdapterDescriptor {
+    #[serde(with = "c_string")]
+    strComPath: [c_char; 1024],
+    #[serde(with = "c_string")]
+    strComName: [c_char; 1024],
+    iVendorId: u16,
+    iProductId: u16,
+    iFirmwareVersion: u16,
+    iPhysicalAddress: u16,
+    iFirmwareBuildDate: u32,
+    #[serde(with = "adapter_type")]
+    adapterType: cec_adapter_type,
+}
+impl_remote!(cec_adapter_descriptor, AdapterDescriptor);
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "libcec_configuration")]
+struct Configuration {
+    clientVersion: u32,
+    #[serde(with = "c_string")]
+    strDeviceName: [c_char; LIBCEC_OSD_NAME_SIZE as usize],
+    deviceTypes: cec_device_type_list,
+    bAutodetectAddress: u8,
+    iPhysicalAddress: u16,
+    #[serde(with = "logical_address")]
+    baseDevice: cec_logical_address,
+    iHDMIPort: u8,
+    tvVendor: u32,
+    wakeDevices: cec_logical_addresses,
+    powerOffDevices: cec_logical_addresses,
+    serverVersion: u32,
+    bGetSettingsFromROM: u8,
+    bActivateSource: u8,
+    bPowerOffOnStandby: u8,
+    #[serde(skip, default = "ptr::null_mut")]
+    callbackParam: *mut std::os::raw::c_void,
+    #[serde(skip, default = "ptr::null_mut")]
+    callbacks: *mut ICECCallbacks,
+    logicalAddresses: cec_logical_addresses,
+    iFirmwareVersion: u16,
+    #[serde(with = "fixed_string")]
+    strDeviceLanguage: [c_char; 3],
+    iFirmwareBuildDate: u32,
+    bMonitorOnly: u8,
+    #[serde(with = "version")]
+    cecVersion: cec_version,
+    #[serde(with = "adapter_type")]
+    adapterType: cec_adapter_type,
+    #[serde(with = "user_control_code")]
+    comboKey: cec_user_control_code,
+    iComboKeyTimeoutMs: u32,
+    iButtonRepeatRateMs: u32,
+    iButtonReleaseDelayMs: u32,
+    iDoubleTapTimeoutMs: u32,
+    bAutoWakeAVR: u8,
+    #[cfg(not(abi4))]
+    bAutoPowerOn: u8,
+}
+impl_remote!(libcec_configuration, Configuration);
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::enums::{AdapterType, DeviceType, LogicalAddress, Opcode, UserControlCode};
+    use crate::strings::set_str;
+    use crate::*;
+
+    #[test]
+    fn test_keypress() {
+        let keypress = cec_keypress {
+            keycode: UserControlCode::Select.raw(),
+            duration: 100,
+        };
+        let value = serde_json::to_value(keypress).unwrap();
+        assert_eq!(value, json!({"keycode": "Select", "duration": 100}));
+        let keypress: cec_keypress = serde_json::from_value(value).unwrap();
+        assert_eq!(keypress.keycode, UserControlCode::Select.raw());
+
+        let unknown = json!({"keycode": {"Unknown": 200}, "duration": 0});
+        let keypress: cec_keypress = serde_json::from_value(unknown).unwrap();
+        assert_eq!(
+            keypress.keycode,
+            UserControlCode::Unknown(c_enum!(cec_user_control_code, 200)).raw()
+        );
+    }
+
+    #[test]
+    fn test_enum_unknown() {
+        let unknown = UserControlCode::Unknown(c_enum!(cec_user_control_code, 200));
+        assert_eq!(
+            serde_json::to_value(unknown).unwrap(),
+            json!({"Unknown": 200})
+        );
+        assert_eq!(
+            serde_json::to_value(LogicalAddress::UNKNOWN).unwrap(),
+            "UNKNOWN"
+        );
+        assert_eq!(
+            serde_json::from_value::<LogicalAddress>("UNKNOWN".into()).unwrap(),
+            LogicalAddress::UNKNOWN
+        );
+        // no C `UNKNOWN` value
+        assert!(serde_json::from_value::<Opcode>("UNKNOWN".into()).is_err());
+        assert!(serde_json::from_value::<Opcode>(json!({"Unknown": -1})).is_err());
+        assert!(serde_json::from_value::<Opcode>("NoSuchOpcode".into()).is_err());
+    }
+
+    #[test]
+    fn test_command() {
+        let mut command = cec_command {
+            initiator: LogicalAddress::PlaybackDevice1.raw(),
+            destination: LogicalAddress::Tv.raw(),
+            opcode: Opcode::ReportPhysicalAddress.raw(),
+            opcode_set: 1,
+            ..Default::default()
+        };
+        command.parameters.data[..3].copy_from_slice(&[0x10, 0x00, 0x04]);
+        command.parameters.size = 3;
+
+        let value = serde_json::to_value(command).unwrap();
+        assert_eq!(value["opcode"], "ReportPhysicalAddress");
+        assert_eq!(value["parameters"], json!([0x10, 0x00, 0x04]));
+        let parsed: cec_command = serde_json::from_value(value).unwrap();
+        assert_eq!(parsed.initiator, command.initiator);
+        assert_eq!(parsed.parameters.size, 3);
+        assert_eq!(parsed.parameters.data, command.parameters.data);
+
+        let too_long = serde_json::to_value(vec![0; 65]).unwrap();
+        assert!(serde_json::from_value::<cec_datapacket>(too_long).is_err());
+    }
+
+    #[test]
+    fn test_configuration() {
+        let mut config = libcec_configuration::default();
+        set_str(&mut config.strDeviceName, "Kodi", 13).unwrap();
+        set_str(&mut config.strDeviceLanguage, "eng", 3).unwrap();
+        config.deviceTypes.types[0] = DeviceType::PlaybackDevice.raw();
+        config.callbackParam = &mut config as *mut _ as *mut std::os::raw::c_void;
+
+        let mut value = serde_json::to_value(config).unwrap();
+        assert_eq!(value["strDeviceName"], "Kodi");
+        assert_eq!(value["strDeviceLanguage"], "eng");
+        assert_eq!(value["deviceTypes"]["types"][0], "PlaybackDevice");
+        assert!(value.get("callbacks").is_none());
+        assert!(value.get("callbackParam").is_none());
+
+        let parsed: libcec_configuration = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(parsed.strDeviceName, config.strDeviceName);
+        assert_eq!(parsed.strDeviceLanguage, config.strDeviceLanguage);
+        assert!(parsed.callbackParam.is_null());
+        assert!(parsed.callbacks.is_null());
+
+        value["strDeviceName"] = "a name which is too long".into();
+        assert!(serde_json::from_value::<libcec_configuration>(value).is_err());
+    }
+
+    #[test]
+    fn test_adapter_descriptor() {
+        let mut descriptor = cec_adapter_descriptor::default();
+        set_str(&mut descriptor.strComPath, "/dev/cec0", 1023).unwrap();
+        let value = serde_json::to_value(descriptor).unwrap();
+        assert_eq!(value["strComPath"], "/dev/cec0");
+        assert_eq!(value["adapterType"], "UNKNOWN");
+        let parsed: cec_adapter_descriptor = serde_json::from_value(value).unwrap();
+        assert_eq!(parsed.strComPath, descriptor.strComPath);
+        assert_eq!(parsed.adapterType, AdapterType::UNKNOWN.raw());
+    }
+}
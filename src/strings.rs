@@ -0,0 +1,298 @@
+//! Safe accessors for the fixed size C string fields
+//!
+//! Reading never relies on a terminating NUL: strings end at the first NUL or at the end of
+//! the field. Writing checks the length against the field size of the current ABI, leaving
+//! room for the terminating NUL when libcec expects one, and rejects strings containing NUL.
+//!
+//! ```
+//! use libcec_sys::libcec_configuration;
+//! use libcec_sys::strings::ConfigurationExt;
+//!
+//! let mut config = libcec_configuration::default();
+//! config.set_device_name("Kodi").unwrap();
+//! assert_eq!(config.device_name(), Ok("Kodi"));
+//! assert!(config.set_device_name("much too long device name").is_err());
+//! ```
+use std::error::Error;
+use std::fmt;
+use std::os::raw::c_char;
+use std::str::Utf8Error;
+
+use crate::*;
+
+/// Error setting a string field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StringError {
+    /// The string does not fit the field
+    TooLong {
+        /// Length of the string, in bytes
+        len: usize,
+        /// Maximum length of the field, in bytes
+        max_len: usize,
+    },
+    /// The string contains a NUL, which would end it early
+    Nul {
+        /// Position of the first NUL, in bytes
+        position: usize,
+    },
+}
+
+impl fmt::Display for StringError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StringError::TooLong { len, max_len } => write!(
+                f,
+                "string of {len} bytes is too long, at most {max_len} bytes allowed"
+            ),
+            StringError::Nul { position } => {
+                write!(f, "string contains NUL at byte {position}")
+            }
+        }
+    }
+}
+
+impl Error for StringError {}
+
+/// Bytes of `chars` up to the first NUL, or all of them when there is none
+pub(crate) fn c_bytes(chars: &[c_char]) -> &[u8] {
+    // SAFETY: c_char and u8 have the same size and alignment
+    let bytes = unsafe { std::slice::from_raw_parts(chars.as_ptr().cast::<u8>(), chars.len()) };
+    let len = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+    &bytes[..len]
+}
+
+pub(crate) fn get_str(chars: &[c_char]) -> Result<&str, Utf8Error> {
+    std::str::from_utf8(c_bytes(chars))
+}
+
+/// Copy `s` to `chars`, padding with NULs
+pub(crate) fn set_str(chars: &mut [c_char], s: &str, max_len: usize) -> Result<(), StringError> {
+    let bytes = s.as_bytes();
+    if bytes.len() > max_len {
+        return Err(StringError::TooLong {
+            len: bytes.len(),
+            max_len,
+        });
+    }
+    if let Some(position) = bytes.iter().position(|&b| b == 0) {
+        return Err(StringError::Nul { position });
+    }
+    chars.fill(0);
+    for (c, &b) in chars.iter_mut().zip(bytes) {
+        *c = b as c_char;
+    }
+    Ok(())
+}
+
+/// NUL-terminated string field
+fn set_c_str(chars: &mut [c_char], s: &str) -> Result<(), StringError> {
+    set_str(chars, s, chars.len() - 1)
+}
+
+/// String accessors for [`cec_adapter`]
+pub trait AdapterExt {
+    /// `path`, the path to the com port
+    fn path(&self) -> Result<&str, Utf8Error>;
+    fn set_path(&mut self, path: &str) -> Result<(), StringError>;
+    /// `comm`, the name of the com port
+    fn comm(&self) -> Result<&str, Utf8Error>;
+    fn set_comm(&mut self, comm: &str) -> Result<(), StringError>;
+}
+
+impl AdapterExt for cec_adapter {
+    fn path(&self) -> Result<&str, Utf8Error> {
+        get_str(&self.path)
+    }
+
+    fn set_path(&mut self, path: &str) -> Result<(), StringError> {
+        set_c_str(&mut self.path, path)
+    }
+
+    fn comm(&self) -> Result<&str, Utf8Error> {
+        get_str(&self.comm)
+    }
+
+    fn set_comm(&mut self, comm: &str) -> Result<(), StringError> {
+        set_c_str(&mut self.comm, comm)
+    }
+}
+
+/// String accessors for [`cec_adapter_descriptor`]
+pub trait AdapterDescriptorExt {
+    /// `strComPath`, the path to the com port
+    fn com_path(&self) -> Result<&str, Utf8Error>;
+    fn set_com_path(&mut self, path: &str) -> Result<(), StringError>;
+    /// `strComName`, the name of the com port
+    fn com_name(&self) -> Result<&str, Utf8Error>;
+    fn set_com_name(&mut self, name: &str) -> Result<(), StringError>;
+}
+
+impl AdapterDescriptorExt for cec_adapter_descriptor {
+    fn com_path(&self) -> Result<&str, Utf8Error> {
+        get_str(&self.strComPath)
+    }
+
+    fn set_com_path(&mut self, path: &str) -> Result<(), StringError> {
+        set_c_str(&mut self.strComPath, path)
+    }
+
+    fn com_name(&self) -> Result<&str, Utf8Error> {
+        get_str(&self.strComName)
+    }
+
+    fn set_com_name(&mut self, name: &str) -> Result<(), StringError> {
+        set_c_str(&mut self.strComName, name)
+    }
+}
+
+/// String accessors for [`libcec_configuration`]
+pub trait ConfigurationExt {
+    /// Maximum length of the device name, 12 bytes with libcec 4 and 14 bytes otherwise
+    const DEVICE_NAME_MAX_LEN: usize = LIBCEC_OSD_NAME_SIZE as usize - 1;
+    /// Length of the device language
+    const DEVICE_LANGUAGE_LEN: usize = 3;
+
+    /// `strDeviceName`, the device name to use on the CEC bus
+    fn device_name(&self) -> Result<&str, Utf8Error>;
+    fn set_device_name(&mut self, name: &str) -> Result<(), StringError>;
+    /// `strDeviceLanguage`, the ISO 639-2 menu language, e.g. `"eng"`
+    fn device_language(&self) -> Result<&str, Utf8Error>;
+    /// The language is not NUL-terminated, so all [`Self::DEVICE_LANGUAGE_LEN`] bytes are
+    /// available
+    fn set_device_language(&mut self, language: &str) -> Result<(), StringError>;
+}
+
+impl ConfigurationExt for libcec_configuration {
+    fn device_name(&self) -> Result<&str, Utf8Error> {
+        get_str(&self.strDeviceName)
+    }
+
+    fn set_device_name(&mut self, name: &str) -> Result<(), StringError> {
+        set_str(&mut self.strDeviceName, name, Self::DEVICE_NAME_MAX_LEN)
+    }
+
+    fn device_language(&self) -> Result<&str, Utf8Error> {
+        get_str(&self.strDeviceLanguage)
+    }
+
+    fn set_device_language(&mut self, language: &str) -> Result<(), StringError> {
+        set_str(
+            &mut self.strDeviceLanguage,
+            language,
+            Self::DEVICE_LANGUAGE_LEN,
+        )
+    }
+}
+
+/// String accessors for [`cec_osd_name`], which is NUL-terminated only when shorter than 14
+/// bytes, like the operand of `<Set OSD Name>`
+pub trait OsdNameExt {
+    /// Maximum length of the name
+    const NAME_MAX_LEN: usize = 14;
+
+    fn name(&self) -> Result<&str, Utf8Error>;
+    fn set_name(&mut self, name: &str) -> Result<(), StringError>;
+}
+
+impl OsdNameExt for cec_osd_name {
+    fn name(&self) -> Result<&str, Utf8Error> {
+        get_str(self)
+    }
+
+    fn set_name(&mut self, name: &str) -> Result<(), StringError> {
+        set_str(self, name, Self::NAME_MAX_LEN)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_device_name() {
+        let mut config = libcec_configuration::default();
+        assert_eq!(config.device_name(), Ok(""));
+
+        let longest = "x".repeat(libcec_configuration::DEVICE_NAME_MAX_LEN);
+        config.set_device_name(&longest).unwrap();
+        assert_eq!(config.device_name(), Ok(longest.as_str()));
+        assert_eq!(*config.strDeviceName.last().unwrap(), 0);
+
+        let err = config.set_device_name(&format!("{longest}x")).unwrap_err();
+        assert_eq!(
+            err,
+            StringError::TooLong {
+                len: libcec_configuration::DEVICE_NAME_MAX_LEN + 1,
+                max_len: libcec_configuration::DEVICE_NAME_MAX_LEN
+            }
+        );
+        assert_eq!(config.device_name(), Ok(longest.as_str()));
+
+        config.set_device_name("Kodi").unwrap();
+        assert_eq!(config.device_name(), Ok("Kodi"));
+    }
+
+    #[cfg(abi4)]
+    #[test]
+    fn test_device_name_abi4() {
+        assert_eq!(libcec_configuration::DEVICE_NAME_MAX_LEN, 12);
+    }
+
+    #[cfg(not(abi4))]
+    #[test]
+    fn test_device_name_abi5_and_later() {
+        assert_eq!(libcec_configuration::DEVICE_NAME_MAX_LEN, 14);
+    }
+
+    #[test]
+    fn test_device_language() {
+        let mut config = libcec_configuration::default();
+        config.set_device_language("eng").unwrap();
+        // not NUL-terminated
+        assert_eq!(
+            config.strDeviceLanguage,
+            [b'e', b'n', b'g'].map(|b| b as c_char)
+        );
+        assert_eq!(config.device_language(), Ok("eng"));
+        assert!(config.set_device_language("engl").is_err());
+    }
+
+    #[test]
+    fn test_not_terminated() {
+        let mut descriptor = cec_adapter_descriptor::default();
+        descriptor.strComName.fill(b'a' as c_char);
+        assert_eq!(descriptor.com_name().unwrap().len(), 1024);
+        assert!(descriptor.set_com_name(&"a".repeat(1024)).is_err());
+        descriptor.set_com_name("cec0").unwrap();
+        assert_eq!(descriptor.com_name(), Ok("cec0"));
+
+        let mut name: cec_osd_name = [b'a' as c_char; 14];
+        assert_eq!(name.name().unwrap().len(), 14);
+        name.set_name("TV").unwrap();
+        assert_eq!(name.name(), Ok("TV"));
+        name.set_name("Living room TV").unwrap();
+        assert_eq!(name.name(), Ok("Living room TV"));
+        assert!(name.set_name("Living room TV2").is_err());
+    }
+
+    #[test]
+    fn test_nul() {
+        let mut config = libcec_configuration::default();
+        config.set_device_name("Kodi").unwrap();
+        assert_eq!(
+            config.set_device_name("Ko\0di"),
+            Err(StringError::Nul { position: 2 })
+        );
+        assert_eq!(config.device_name(), Ok("Kodi"));
+
+        let mut name = cec_osd_name::default();
+        assert!(name.set_name("\0").is_err());
+    }
+
+    #[test]
+    fn test_invalid_utf8() {
+        let mut adapter = cec_adapter::default();
+        adapter.path[0] = 0xffu8 as c_char;
+        assert!(adapter.path().is_err());
+    }
+}
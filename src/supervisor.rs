@@ -0,0 +1,708 @@
+//! Reconnecting a connection lost to libcec
+//!
+//! After a `CONNECTION_LOST` or `PORT_BUSY` alert, the adapter no longer answers and the
+//! connection has to be opened again. A [`Supervisor`] owns a [`Connection`] and does so on its
+//! own thread: it closes and destroys the connection, waits with an exponential [`Backoff`],
+//! initialises libcec again with the original configuration and handler, opens the port and
+//! re-applies the active source and OSD name successfully set through it. Each step is reported as a
+//! [`SupervisorEvent`].
+//!
+//! The alerts cannot be acted upon in the callback, as closing the connection waits for the
+//! threads of libcec calling it, so the handler only wakes the thread up.
+//!
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use libcec_sys::enums::DeviceType;
+//! use libcec_sys::supervisor::{Backoff, Supervisor};
+//! use libcec_sys::libcec_configuration;
+//!
+//! # struct Handler;
+//! # impl libcec_sys::handler::CecHandler for Handler {}
+//! let mut configuration = libcec_configuration::default();
+//! unsafe { libcec_sys::libcec_clear_configuration(&mut configuration) };
+//!
+//! let supervised = Supervisor::new(configuration, "/dev/ttyACM0")
+//!     .with_backoff(Backoff::default().with_max(Duration::from_secs(10)))
+//!     .start(Handler, |event| println!("{event}"))?;
+//! supervised.set_active_source(DeviceType::PlaybackDevice)?;
+//! supervised.with_connection(|connection| connection.volume_up(true))??;
+//! # Ok::<(), libcec_sys::safe::Error>(())
+//! ```
+use std::fmt;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use crate::config::ConfigurationError;
+use crate::enums::{Alert, DeviceType, LogicalAddress, MenuState};
+use crate::handler::{CecHandler, KeyPress, LogMessage};
+use crate::safe::{Connection, Error, Result};
+use crate::strings::ConfigurationExt;
+use crate::*;
+
+/// Timeout of `libcec_open`
+pub const DEFAULT_OPEN_TIMEOUT: Duration = Duration::from_secs(10);
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    // the values are replaced as a whole, so a panic while holding the lock cannot break them
+    mutex.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+/// Exponentially growing delays before the attempts to reconnect
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Backoff {
+    initial: Duration,
+    max: Duration,
+    factor: u32,
+}
+
+impl Backoff {
+    /// Delays starting at `initial`, multiplied by 2 up to 30 seconds
+    pub fn new(initial: Duration) -> Self {
+        Backoff {
+            initial,
+            max: Duration::from_secs(30),
+            factor: 2,
+        }
+    }
+
+    /// Longest delay
+    pub fn with_max(mut self, max: Duration) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Growth of the delay per attempt, 1 for a constant delay
+    pub fn with_factor(mut self, factor: u32) -> Self {
+        self.factor = factor;
+        self
+    }
+
+    /// Delay before attempt `attempt`, counted from 1
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let mut delay = self.initial;
+        for _ in 1..attempt {
+            if delay >= self.max {
+                break;
+            }
+            delay = delay.saturating_mul(self.factor);
+        }
+        delay.min(self.max)
+    }
+}
+
+impl Default for Backoff {
+    /// Delays of 0.5, 1, 2, 4, ... up to 30 seconds
+    fn default() -> Self {
+        Backoff::new(Duration::from_millis(500))
+    }
+}
+
+/// Step of the supervision of a connection
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SupervisorEvent {
+    /// libcec reported the connection lost, with [`Error::ConnectionLost`] or
+    /// [`Error::PortBusy`]
+    ConnectionLost(Error),
+    /// The lost connection was closed and destroyed
+    Closed,
+    /// Waiting `delay` before attempt `attempt`, counted from 1
+    Waiting {
+        attempt: u32,
+        delay: Duration,
+    },
+    /// Initialising libcec and opening the port
+    Reconnecting {
+        attempt: u32,
+    },
+    ReconnectFailed {
+        attempt: u32,
+        error: Error,
+    },
+    /// The connection is open again, after `attempts` attempts
+    Reconnected {
+        attempts: u32,
+    },
+    /// The active source or OSD name could not be re-applied. The connection is open.
+    RestoreFailed(Error),
+}
+
+impl fmt::Display for SupervisorEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SupervisorEvent::ConnectionLost(error) => write!(f, "connection lost: {error}"),
+            SupervisorEvent::Closed => f.write_str("connection closed"),
+            SupervisorEvent::Waiting { attempt, delay } => {
+                write!(f, "waiting {delay:?} before attempt {attempt}")
+            }
+            SupervisorEvent::Reconnecting { attempt } => {
+                write!(f, "reconnecting, attempt {attempt}")
+            }
+            SupervisorEvent::ReconnectFailed { attempt, error } => {
+                write!(f, "attempt {attempt} failed: {error}")
+            }
+            SupervisorEvent::Reconnected { attempts } => {
+                write!(f, "reconnected after {attempts} attempts")
+            }
+            SupervisorEvent::RestoreFailed(error) => write!(f, "failed to restore state: {error}"),
+        }
+    }
+}
+
+#[derive(Default)]
+struct AlarmState {
+    lost: Option<Error>,
+    stopped: bool,
+}
+
+/// Wakes the supervisor thread up from the callbacks, or to stop
+#[derive(Default)]
+struct Alarm {
+    state: Mutex<AlarmState>,
+    condvar: Condvar,
+}
+
+impl Alarm {
+    /// Report the connection lost, unless it already is
+    fn raise(&self, error: Error) {
+        lock(&self.state).lost.get_or_insert(error);
+        self.condvar.notify_all();
+    }
+
+    fn clear(&self) {
+        lock(&self.state).lost = None;
+    }
+
+    fn stop(&self) {
+        lock(&self.state).stopped = true;
+        self.condvar.notify_all();
+    }
+
+    /// Wait for the connection to be lost, `None` when stopped
+    fn wait_lost(&self) -> Option<Error> {
+        let mut state = lock(&self.state);
+        loop {
+            if state.stopped {
+                return None;
+            }
+            if let Some(error) = state.lost.take() {
+                return Some(error);
+            }
+            state = self
+                .condvar
+                .wait(state)
+                .unwrap_or_else(|err| err.into_inner());
+        }
+    }
+
+    /// Sleep `delay`, returning whether it was stopped meanwhile
+    fn sleep(&self, delay: Duration) -> bool {
+        let state = lock(&self.state);
+        let (state, _) = self
+            .condvar
+            .wait_timeout_while(state, delay, |state| !state.stopped)
+            .unwrap_or_else(|err| err.into_inner());
+        state.stopped
+    }
+}
+
+/// State re-applied after reconnecting
+#[derive(Debug, Clone, Default)]
+struct Restore {
+    active_source: Option<DeviceType>,
+    osd_name: Option<String>,
+}
+
+/// Handler passing the callbacks on, and raising the alarm on lost connections
+struct Supervised<H> {
+    handler: Arc<H>,
+    alarm: Arc<Alarm>,
+    restore: Arc<Mutex<Restore>>,
+}
+
+impl<H: CecHandler> CecHandler for Supervised<H> {
+    fn log_message(&self, message: LogMessage<'_>) {
+        self.handler.log_message(message)
+    }
+
+    fn key_press(&self, key: KeyPress) {
+        self.handler.key_press(key)
+    }
+
+    fn command_received(&self, command: &cec_command) {
+        self.handler.command_received(command)
+    }
+
+    fn configuration_changed(&self, configuration: &libcec_configuration) {
+        self.handler.configuration_changed(configuration)
+    }
+
+    fn alert(&self, alert: Alert, param: Option<&str>) {
+        if matches!(alert, Alert::ConnectionLost | Alert::PortBusy) {
+            if let Some(error) = Error::from_alert(alert, param) {
+                self.alarm.raise(error);
+            }
+        }
+        self.handler.alert(alert, param)
+    }
+
+    fn menu_state_changed(&self, state: MenuState) -> bool {
+        self.handler.menu_state_changed(state)
+    }
+
+    fn source_activated(&self, address: LogicalAddress, activated: bool) {
+        if !activated {
+            // another device became the active source
+            lock(&self.restore).active_source = None;
+        }
+        self.handler.source_activated(address, activated)
+    }
+
+    #[cfg(abi7)]
+    fn command_handler(&self, command: &cec_command) -> bool {
+        self.handler.command_handler(command)
+    }
+}
+
+/// The steps of reconnecting, apart for testing
+trait Link {
+    fn disconnect(&mut self);
+    fn connect(&mut self) -> Result<()>;
+    fn restore(&mut self) -> Result<()>;
+}
+
+/// Connection shared by the supervisor thread and the [`SupervisedConnection`]
+type Shared = Arc<Mutex<Option<Connection>>>;
+
+struct Reconnector<H> {
+    connection: Shared,
+    configuration: libcec_configuration,
+    port: String,
+    open_timeout: Duration,
+    handler: Arc<H>,
+    alarm: Arc<Alarm>,
+    restore: Arc<Mutex<Restore>>,
+}
+
+// SAFETY: `Supervisor::start`, which builds the only `Reconnector`, sets the `callbacks` and
+// `callbackParam` of its configuration to NULL. libcec gets the handler through the
+// `Connection` instead, and `H: Send + Sync` covers sharing it with the supervisor thread.
+unsafe impl<H: Send + Sync> Send for Reconnector<H> {}
+
+impl<H: CecHandler + 'static> Reconnector<H> {
+    fn open(&self) -> Result<Connection> {
+        let handler = Supervised {
+            handler: Arc::clone(&self.handler),
+            alarm: Arc::clone(&self.alarm),
+            restore: Arc::clone(&self.restore),
+        };
+        let mut connection = Connection::init_with_handler(self.configuration, handler)?;
+        connection.open(&self.port, self.open_timeout)?;
+        Ok(connection)
+    }
+}
+
+impl<H: CecHandler + 'static> Link for Reconnector<H> {
+    fn disconnect(&mut self) {
+        let connection = lock(&self.connection).take();
+        // closed and destroyed without the lock, as closing waits for the callbacks
+        drop(connection);
+    }
+
+    fn connect(&mut self) -> Result<()> {
+        let connection = self.open()?;
+        *lock(&self.connection) = Some(connection);
+        Ok(())
+    }
+
+    fn restore(&mut self) -> Result<()> {
+        // copied, as the callbacks change it while libcec is called
+        let restore = lock(&self.restore).clone();
+        let connection = lock(&self.connection);
+        let connection = connection
+            .as_ref()
+            .ok_or(Error::ConnectionLost { message: None })?;
+        if let Some(name) = &restore.osd_name {
+            set_osd_name(connection, name)?;
+        }
+        if let Some(device_type) = restore.active_source {
+            connection.set_active_source(device_type)?;
+        }
+        Ok(())
+    }
+}
+
+fn set_osd_name(connection: &Connection, name: &str) -> Result<()> {
+    let mut configuration = connection.current_configuration()?;
+    configuration
+        .set_device_name(name)
+        .map_err(ConfigurationError::InvalidDeviceName)?;
+    connection.set_configuration(&configuration)
+}
+
+/// Reconnect whenever the connection is lost, until stopped
+fn supervise<L, F>(alarm: &Alarm, backoff: Backoff, mut link: L, mut on_event: F)
+where
+    L: Link,
+    F: FnMut(SupervisorEvent),
+{
+    while let Some(error) = alarm.wait_lost() {
+        on_event(SupervisorEvent::ConnectionLost(error));
+        link.disconnect();
+        on_event(SupervisorEvent::Closed);
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            let delay = backoff.delay(attempt);
+            on_event(SupervisorEvent::Waiting { attempt, delay });
+            if alarm.sleep(delay) {
+                return;
+            }
+            // alerts of the closed connection and of failed attempts
+            alarm.clear();
+            on_event(SupervisorEvent::Reconnecting { attempt });
+            match link.connect() {
+                Ok(()) => break,
+                Err(error) => on_event(SupervisorEvent::ReconnectFailed { attempt, error }),
+            }
+        }
+        on_event(SupervisorEvent::Reconnected { attempts: attempt });
+        if let Err(error) = link.restore() {
+            on_event(SupervisorEvent::RestoreFailed(error));
+        }
+    }
+}
+
+/// Configuration of a [`SupervisedConnection`]
+#[derive(Clone, Copy)]
+pub struct Supervisor<'a> {
+    configuration: libcec_configuration,
+    port: &'a str,
+    backoff: Backoff,
+    open_timeout: Duration,
+}
+
+impl<'a> Supervisor<'a> {
+    /// Supervisor of connections initialised with `configuration`, to the adapter at `port`
+    ///
+    /// Any `callbacks` and `callbackParam` of the configuration are ignored.
+    pub fn new(configuration: libcec_configuration, port: &'a str) -> Self {
+        Supervisor {
+            configuration,
+            port,
+            backoff: Backoff::default(),
+            open_timeout: DEFAULT_OPEN_TIMEOUT,
+        }
+    }
+
+    pub fn with_backoff(mut self, backoff: Backoff) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Timeout of `libcec_open`, [`DEFAULT_OPEN_TIMEOUT`] by default
+    pub fn with_open_timeout(mut self, open_timeout: Duration) -> Self {
+        self.open_timeout = open_timeout;
+        self
+    }
+
+    /// Initialise libcec and open the port, then supervise the connection
+    ///
+    /// `handler` gets the callbacks of every connection, and `on_event` the steps of
+    /// reconnecting, from the thread of the supervisor. Fails when the first connection
+    /// cannot be opened, or the thread cannot be spawned.
+    pub fn start<H, F>(self, handler: H, on_event: F) -> Result<SupervisedConnection>
+    where
+        H: CecHandler + 'static,
+        F: FnMut(SupervisorEvent) + Send + 'static,
+    {
+        let mut configuration = self.configuration;
+        configuration.callbacks = std::ptr::null_mut();
+        configuration.callbackParam = std::ptr::null_mut();
+        let alarm = Arc::new(Alarm::default());
+        let restore = Arc::new(Mutex::new(Restore::default()));
+        let connection = Shared::default();
+        let mut reconnector = Reconnector {
+            connection: Arc::clone(&connection),
+            configuration,
+            port: self.port.to_owned(),
+            open_timeout: self.open_timeout,
+            handler: Arc::new(handler),
+            alarm: Arc::clone(&alarm),
+            restore: Arc::clone(&restore),
+        };
+        reconnector.connect()?;
+        let thread_alarm = Arc::clone(&alarm);
+        let backoff = self.backoff;
+        let thread = thread::Builder::new()
+            .name("libcec-supervisor".to_owned())
+            .spawn(move || supervise(&thread_alarm, backoff, reconnector, on_event))
+            .map_err(|err| Error::SpawnFailed(err.kind()))?;
+        Ok(SupervisedConnection {
+            connection,
+            alarm,
+            restore,
+            thread: Some(thread),
+        })
+    }
+}
+
+impl fmt::Debug for Supervisor<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Supervisor")
+            .field("port", &self.port)
+            .field("backoff", &self.backoff)
+            .field("open_timeout", &self.open_timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Connection opened again whenever it is lost, closed and destroyed on drop
+pub struct SupervisedConnection {
+    connection: Shared,
+    alarm: Arc<Alarm>,
+    restore: Arc<Mutex<Restore>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl SupervisedConnection {
+    /// Call `f` with the connection
+    ///
+    /// Fails with [`Error::ConnectionLost`] while reconnecting. The connection is locked during
+    /// the call, so it must not call back into this supervised connection.
+    pub fn with_connection<R>(&self, f: impl FnOnce(&Connection) -> R) -> Result<R> {
+        lock(&self.connection)
+            .as_ref()
+            .map(f)
+            .ok_or(Error::ConnectionLost { message: None })
+    }
+
+    /// Whether the connection is open, i.e. not being reconnected
+    pub fn is_connected(&self) -> bool {
+        lock(&self.connection).is_some()
+    }
+
+    /// Make this device the active source, also after reconnecting
+    ///
+    /// It stays the active source after reconnecting until another device becomes the active
+    /// source, or [`Self::set_inactive_view`] is called. When this fails, e.g. while
+    /// reconnecting, nothing is re-applied.
+    pub fn set_active_source(&self, device_type: DeviceType) -> Result<()> {
+        self.with_connection(|connection| connection.set_active_source(device_type))??;
+        lock(&self.restore).active_source = Some(device_type);
+        Ok(())
+    }
+
+    pub fn set_inactive_view(&self) -> Result<()> {
+        self.with_connection(Connection::set_inactive_view)??;
+        lock(&self.restore).active_source = None;
+        Ok(())
+    }
+
+    /// Change the OSD name, `strDeviceName`, also after reconnecting
+    ///
+    /// When this fails, e.g. for a name which does not fit, the name is not re-applied.
+    pub fn set_osd_name(&self, name: &str) -> Result<()> {
+        self.with_connection(|connection| set_osd_name(connection, name))??;
+        lock(&self.restore).osd_name = Some(name.to_owned());
+        Ok(())
+    }
+}
+
+impl Drop for SupervisedConnection {
+    fn drop(&mut self) {
+        self.alarm.stop();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl fmt::Debug for SupervisedConnection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SupervisedConnection")
+            .field("connection", &*lock(&self.connection))
+            .field("restore", &*lock(&self.restore))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+
+    #[test]
+    fn test_backoff() {
+        let backoff = Backoff::new(Duration::from_millis(100)).with_max(Duration::from_secs(1));
+        let delays: Vec<_> = (1..=6).map(|attempt| backoff.delay(attempt)).collect();
+        assert_eq!(
+            delays,
+            [100, 200, 400, 800, 1000, 1000].map(Duration::from_millis)
+        );
+        assert_eq!(backoff.delay(u32::MAX), Duration::from_secs(1));
+        let constant = backoff.with_factor(1);
+        assert_eq!(constant.delay(5), Duration::from_millis(100));
+    }
+
+    /// Link failing to connect `failures` times, recording its calls
+    struct FakeLink {
+        failures: u32,
+        calls: mpsc::Sender<&'static str>,
+    }
+
+    impl Link for FakeLink {
+        fn disconnect(&mut self) {
+            self.calls.send("disconnect").unwrap();
+        }
+
+        fn connect(&mut self) -> Result<()> {
+            self.calls.send("connect").unwrap();
+            if self.failures > 0 {
+                self.failures -= 1;
+                return Err(Error::OpenFailed {
+                    port: "/dev/cec0".to_owned(),
+                });
+            }
+            Ok(())
+        }
+
+        fn restore(&mut self) -> Result<()> {
+            self.calls.send("restore").unwrap();
+            Err(Error::TransmitFailed)
+        }
+    }
+
+    struct Ignore;
+
+    impl CecHandler for Ignore {}
+
+    fn handler(alarm: &Arc<Alarm>) -> Supervised<Ignore> {
+        Supervised {
+            handler: Arc::new(Ignore),
+            alarm: Arc::clone(alarm),
+            restore: Arc::new(Mutex::new(Restore {
+                active_source: Some(DeviceType::PlaybackDevice),
+                osd_name: None,
+            })),
+        }
+    }
+
+    #[test]
+    fn test_alerts() {
+        let alarm = Arc::new(Alarm::default());
+        let handler = handler(&alarm);
+        handler.alert(Alert::TvPollFailed, None);
+        assert!(lock(&alarm.state).lost.is_none());
+        handler.alert(Alert::PortBusy, Some("busy"));
+        handler.alert(Alert::ConnectionLost, None);
+        // the first alert is kept
+        assert_eq!(
+            alarm.wait_lost(),
+            Some(Error::PortBusy {
+                message: Some("busy".to_owned())
+            })
+        );
+        handler.source_activated(LogicalAddress::PlaybackDevice1, false);
+        assert!(lock(&handler.restore).active_source.is_none());
+    }
+
+    #[test]
+    fn test_supervise() {
+        let alarm = Arc::new(Alarm::default());
+        let (calls, call_receiver) = mpsc::channel();
+        let (events, event_receiver) = mpsc::channel();
+        let link = FakeLink { failures: 2, calls };
+        let backoff = Backoff::new(Duration::from_millis(1));
+        let thread_alarm = Arc::clone(&alarm);
+        let thread = thread::spawn(move || {
+            supervise(&thread_alarm, backoff, link, |event| {
+                events.send(event).unwrap()
+            })
+        });
+
+        let lost = Error::ConnectionLost { message: None };
+        handler(&alarm).alert(Alert::ConnectionLost, None);
+        let expected = [
+            SupervisorEvent::ConnectionLost(lost),
+            SupervisorEvent::Closed,
+            SupervisorEvent::Waiting {
+                attempt: 1,
+                delay: Duration::from_millis(1),
+            },
+            SupervisorEvent::Reconnecting { attempt: 1 },
+            SupervisorEvent::ReconnectFailed {
+                attempt: 1,
+                error: Error::OpenFailed {
+                    port: "/dev/cec0".to_owned(),
+                },
+            },
+            SupervisorEvent::Waiting {
+                attempt: 2,
+                delay: Duration::from_millis(2),
+            },
+            SupervisorEvent::Reconnecting { attempt: 2 },
+            SupervisorEvent::ReconnectFailed {
+                attempt: 2,
+                error: Error::OpenFailed {
+                    port: "/dev/cec0".to_owned(),
+                },
+            },
+            SupervisorEvent::Waiting {
+                attempt: 3,
+                delay: Duration::from_millis(4),
+            },
+            SupervisorEvent::Reconnecting { attempt: 3 },
+            SupervisorEvent::Reconnected { attempts: 3 },
+            SupervisorEvent::RestoreFailed(Error::TransmitFailed),
+        ];
+        for event in expected {
+            assert_eq!(
+                event_receiver.recv_timeout(Duration::from_secs(10)),
+                Ok(event)
+            );
+        }
+        alarm.stop();
+        thread.join().unwrap();
+        let calls: Vec<_> = call_receiver.iter().collect();
+        assert_eq!(
+            calls,
+            ["disconnect", "connect", "connect", "connect", "restore"]
+        );
+    }
+
+    #[test]
+    fn test_restore_after_success() {
+        let supervised = SupervisedConnection {
+            connection: Shared::default(),
+            alarm: Arc::new(Alarm::default()),
+            restore: Arc::new(Mutex::new(Restore::default())),
+            thread: None,
+        };
+        let lost = Err(Error::ConnectionLost { message: None });
+        assert_eq!(
+            supervised.set_active_source(DeviceType::PlaybackDevice),
+            lost
+        );
+        assert_eq!(supervised.set_osd_name("Kodi"), lost);
+        let restore = lock(&supervised.restore).clone();
+        assert!(restore.active_source.is_none());
+        assert!(restore.osd_name.is_none());
+    }
+
+    #[test]
+    fn test_stop_while_waiting() {
+        let alarm = Alarm::default();
+        alarm.raise(Error::ConnectionLost { message: None });
+        let (calls, call_receiver) = mpsc::channel();
+        let link = FakeLink { failures: 0, calls };
+        let backoff = Backoff::new(Duration::from_secs(60));
+        thread::scope(|scope| {
+            scope.spawn(|| supervise(&alarm, backoff, link, |_| {}));
+            thread::sleep(Duration::from_millis(10));
+            alarm.stop();
+        });
+        assert_eq!(call_receiver.iter().collect::<Vec<_>>(), ["disconnect"]);
+    }
+}
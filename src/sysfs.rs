@@ -0,0 +1,330 @@
+//! Adapter enumeration on Linux without libcec
+//!
+//! Listing the adapters with `libcec_detect_adapters` needs an initialised libcec. The
+//! [`Enumerator`] finds them in the file system instead, as [`AdapterDescriptor`]s:
+//!
+//! - devices of the kernel CEC framework, in `/sys/class/cec`, opened as `/dev/cecN`, and
+//!   `/dev/cec*` nodes without a sysfs entry, e.g. in containers
+//! - Pulse-Eight USB adapters, USB vendor id 0x2548, in `/sys/bus/usb/devices`, opened as
+//!   their `/dev/ttyACMN` serial port
+//!
+//! The firmware version and build date are only known once an adapter is opened, and are left
+//! unset. [`Enumerator::with_root`] reads another directory instead of `/`, e.g. a copy of
+//! the sysfs tree.
+//!
+//! ```no_run
+//! use libcec_sys::sysfs::Enumerator;
+//!
+//! for adapter in Enumerator::new().adapters()? {
+//!     println!("{} ({})", adapter.path, adapter.adapter_type);
+//! }
+//! # Ok::<(), std::io::Error>(())
+//! ```
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::adapter::AdapterDescriptor;
+use crate::address::PhysicalAddress;
+use crate::enums::AdapterType;
+
+/// USB vendor id of Pulse-Eight
+pub const PULSE_EIGHT_VENDOR_ID: u16 = 0x2548;
+
+/// USB product id of the external Pulse-Eight adapter
+pub const PULSE_EIGHT_EXTERNAL_PRODUCT_ID: u16 = 0x1001;
+
+/// USB product id of the Pulse-Eight daughterboard
+pub const PULSE_EIGHT_DAUGHTERBOARD_PRODUCT_ID: u16 = 0x1002;
+
+/// Finds CEC adapters in sysfs and `/dev`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Enumerator {
+    root: PathBuf,
+}
+
+impl Enumerator {
+    /// Enumerator of the adapters of this system
+    pub fn new() -> Self {
+        Enumerator::with_root("/")
+    }
+
+    /// Enumerator reading `root/sys` and `root/dev` instead of `/sys` and `/dev`
+    ///
+    /// The paths of the adapters found are under `root` as well.
+    pub fn with_root(root: impl Into<PathBuf>) -> Self {
+        Enumerator { root: root.into() }
+    }
+
+    /// Directory containing `sys` and `dev`
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Adapters found, sorted by path
+    ///
+    /// Missing directories are skipped, as they are on systems without the kernel CEC
+    /// framework or USB.
+    pub fn adapters(&self) -> io::Result<Vec<AdapterDescriptor>> {
+        let mut adapters = self.cec_devices()?;
+        adapters.extend(self.pulse_eight_devices()?);
+        adapters.sort_by(|a, b| a.path.cmp(&b.path));
+        adapters.dedup_by(|a, b| a.path == b.path);
+        Ok(adapters)
+    }
+
+    /// Devices of the kernel CEC framework
+    fn cec_devices(&self) -> io::Result<Vec<AdapterDescriptor>> {
+        let mut names = entries(&self.root.join("sys/class/cec"))?
+            .into_iter()
+            .filter_map(|path| file_name(&path))
+            .collect::<Vec<_>>();
+        names.extend(
+            entries(&self.root.join("dev"))?
+                .iter()
+                .filter_map(|path| file_name(path))
+                .filter(|name| is_cec_node(name)),
+        );
+        names.sort();
+        names.dedup();
+        Ok(names
+            .into_iter()
+            .filter(|name| is_cec_node(name))
+            .map(|name| {
+                let device = self.root.join("sys/class/cec").join(&name).join("device");
+                let (vendor_id, product_id) = usb_ids(&device).unwrap_or_default();
+                descriptor(
+                    self.root.join("dev").join(&name),
+                    "Linux".to_owned(),
+                    vendor_id,
+                    product_id,
+                    AdapterType::Linux,
+                )
+            })
+            .collect())
+    }
+
+    /// Pulse-Eight USB adapters with a serial port
+    fn pulse_eight_devices(&self) -> io::Result<Vec<AdapterDescriptor>> {
+        let mut adapters = Vec::new();
+        for device in entries(&self.root.join("sys/bus/usb/devices"))? {
+            let Some((vendor_id, product_id)) = read_ids(&device) else {
+                continue;
+            };
+            if vendor_id != PULSE_EIGHT_VENDOR_ID {
+                continue;
+            }
+            let adapter_type = match product_id {
+                PULSE_EIGHT_DAUGHTERBOARD_PRODUCT_ID => AdapterType::P8Daughterboard,
+                _ => AdapterType::P8External,
+            };
+            for tty in serial_ports(&device)? {
+                adapters.push(descriptor(
+                    self.root.join("dev").join(tty),
+                    device.to_string_lossy().into_owned(),
+                    vendor_id,
+                    product_id,
+                    adapter_type,
+                ));
+            }
+        }
+        Ok(adapters)
+    }
+}
+
+impl Default for Enumerator {
+    fn default() -> Self {
+        Enumerator::new()
+    }
+}
+
+fn descriptor(
+    path: PathBuf,
+    name: String,
+    vendor_id: u16,
+    product_id: u16,
+    adapter_type: AdapterType,
+) -> AdapterDescriptor {
+    AdapterDescriptor {
+        path: path.to_string_lossy().into_owned(),
+        name,
+        vendor_id,
+        product_id,
+        firmware_version: 0,
+        firmware_build_date: None,
+        physical_address: PhysicalAddress::INVALID,
+        adapter_type,
+    }
+}
+
+/// Paths in `dir`, none when it does not exist
+fn entries(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+    entries
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect()
+}
+
+fn file_name(path: &Path) -> Option<String> {
+    path.file_name()?.to_str().map(str::to_owned)
+}
+
+/// `cec0`, `cec1`, ...
+fn is_cec_node(name: &str) -> bool {
+    name.strip_prefix("cec")
+        .is_some_and(|number| !number.is_empty() && number.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Hex number of a sysfs attribute, e.g. `idVendor`
+fn read_hex(path: &Path) -> Option<u16> {
+    u16::from_str_radix(fs::read_to_string(path).ok()?.trim(), 16).ok()
+}
+
+/// `idVendor` and `idProduct` of a USB device directory
+fn read_ids(device: &Path) -> Option<(u16, u16)> {
+    Some((
+        read_hex(&device.join("idVendor"))?,
+        read_hex(&device.join("idProduct"))?,
+    ))
+}
+
+/// USB ids of the first USB device above `device`, a sysfs device link
+fn usb_ids(device: &Path) -> Option<(u16, u16)> {
+    fs::canonicalize(device)
+        .ok()?
+        .ancestors()
+        .find_map(read_ids)
+}
+
+/// Names of the tty devices of the interfaces of a USB device, e.g. `ttyACM0`
+fn serial_ports(device: &Path) -> io::Result<Vec<String>> {
+    let mut ttys = Vec::new();
+    for interface in entries(device)? {
+        // interfaces are named `<device>:<configuration>.<interface>`
+        if !file_name(&interface).is_some_and(|name| name.contains(':')) {
+            continue;
+        }
+        ttys.extend(
+            entries(&interface.join("tty"))?
+                .iter()
+                .filter_map(|tty| file_name(tty)),
+        );
+    }
+    ttys.sort();
+    Ok(ttys)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Fake root directory, removed on drop
+    struct Root(PathBuf);
+
+    impl Root {
+        fn new(name: &str) -> Self {
+            let root = std::env::temp_dir()
+                .join(format!("libcec-sys-sysfs-{name}-{}", std::process::id()));
+            let _ = fs::remove_dir_all(&root);
+            fs::create_dir_all(&root).unwrap();
+            Root(root)
+        }
+
+        fn file(&self, path: &str, contents: &str) {
+            let path = self.0.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        }
+
+        fn dir(&self, path: &str) {
+            fs::create_dir_all(self.0.join(path)).unwrap();
+        }
+    }
+
+    impl Drop for Root {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn test_empty() {
+        let root = Root::new("empty");
+        assert_eq!(Enumerator::with_root(&root.0).adapters().unwrap(), []);
+    }
+
+    #[test]
+    fn test_pulse_eight() {
+        let root = Root::new("pulse-eight");
+        root.file("sys/bus/usb/devices/1-1/idVendor", "2548\n");
+        root.file("sys/bus/usb/devices/1-1/idProduct", "1002\n");
+        root.dir("sys/bus/usb/devices/1-1/1-1:1.0/tty/ttyACM0");
+        // other USB devices are skipped
+        root.file("sys/bus/usb/devices/1-2/idVendor", "1d6b\n");
+        root.file("sys/bus/usb/devices/1-2/idProduct", "0002\n");
+        root.dir("sys/bus/usb/devices/1-2/1-2:1.0/tty/ttyACM1");
+        root.dir("sys/bus/usb/devices/usb1");
+
+        let adapters = Enumerator::with_root(&root.0).adapters().unwrap();
+        assert_eq!(adapters.len(), 1);
+        let adapter = &adapters[0];
+        assert_eq!(
+            Path::new(&adapter.path),
+            root.0.join("dev/ttyACM0").as_path()
+        );
+        assert_eq!(adapter.vendor_id, PULSE_EIGHT_VENDOR_ID);
+        assert_eq!(adapter.product_id, PULSE_EIGHT_DAUGHTERBOARD_PRODUCT_ID);
+        assert_eq!(adapter.adapter_type, AdapterType::P8Daughterboard);
+        assert_eq!(adapter.firmware_build_date, None);
+    }
+
+    #[test]
+    fn test_cec_framework() {
+        let root = Root::new("cec");
+        root.dir("sys/class/cec/cec0");
+        root.file("dev/cec0", "");
+        // a node without sysfs entry
+        root.file("dev/cec1", "");
+        root.file("dev/cecx", "");
+        root.file("dev/null", "");
+
+        let adapters = Enumerator::with_root(&root.0).adapters().unwrap();
+        let paths: Vec<_> = adapters
+            .iter()
+            .map(|adapter| adapter.path.clone())
+            .collect();
+        assert_eq!(
+            paths,
+            [root.0.join("dev/cec0"), root.0.join("dev/cec1")]
+                .map(|path| path.to_string_lossy().into_owned())
+        );
+        assert!(adapters
+            .iter()
+            .all(|adapter| adapter.adapter_type == AdapterType::Linux));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_cec_framework_usb_ids() {
+        let root = Root::new("cec-usb");
+        root.file("sys/devices/usb1/1-1/idVendor", "2548\n");
+        root.file("sys/devices/usb1/1-1/idProduct", "1001\n");
+        root.dir("sys/devices/usb1/1-1/1-1:1.0/serio0");
+        root.dir("sys/class/cec/cec0");
+        std::os::unix::fs::symlink(
+            root.0.join("sys/devices/usb1/1-1/1-1:1.0/serio0"),
+            root.0.join("sys/class/cec/cec0/device"),
+        )
+        .unwrap();
+
+        let adapters = Enumerator::with_root(&root.0).adapters().unwrap();
+        assert_eq!(adapters.len(), 1);
+        assert_eq!(adapters[0].vendor_id, PULSE_EIGHT_VENDOR_ID);
+        assert_eq!(adapters[0].product_id, PULSE_EIGHT_EXTERNAL_PRODUCT_ID);
+        assert_eq!(adapters[0].adapter_type, AdapterType::Linux);
+    }
+}
@@ -0,0 +1,240 @@
+//! Parser of libcec traffic logs
+//!
+//! libcec logs each frame at `CEC_LOG_TRAFFIC`, as `<< 10:8f` when it transmits it and
+//! `>> 01:90:00` when it receives it. `cec-client` writes these messages to its log as
+//! `TRAFFIC: [  2405] >> 01:90:00`, with a tab before `>>` and the milliseconds since libcec
+//! started.
+//!
+//! [`Traffic::from_log`] parses the messages of the log callback, [`parse_line`] the lines of
+//! saved logs and [`parse_log`] whole log files, one line at a time.
+//!
+//! ```
+//! use libcec_sys::enums::Opcode;
+//! use libcec_sys::traffic::{self, Direction};
+//!
+//! let traffic = traffic::parse_line("TRAFFIC: [            2405]\t>> 01:90:00")
+//!     .unwrap()
+//!     .unwrap();
+//! assert_eq!(traffic.time, 2405);
+//! assert_eq!(traffic.direction, Direction::Received);
+//! assert_eq!(Opcode::from_raw(traffic.command.opcode), Opcode::ReportPowerStatus);
+//! ```
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead};
+
+use crate::enums::LogLevel;
+use crate::frame::{self, FrameError};
+use crate::handler::LogMessage;
+use crate::*;
+
+/// Whether libcec sent or received a frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    /// `>>`
+    Received,
+    /// `<<`
+    Transmitted,
+}
+
+impl Direction {
+    /// Marker of the direction in the log, `">>"` or `"<<"`
+    pub const fn marker(self) -> &'static str {
+        match self {
+            Direction::Received => ">>",
+            Direction::Transmitted => "<<",
+        }
+    }
+}
+
+/// Frame of a traffic log
+#[derive(Debug, Clone, Copy)]
+pub struct Traffic {
+    /// Milliseconds since libcec was initialised
+    pub time: i64,
+    pub direction: Direction,
+    pub command: cec_command,
+}
+
+impl Traffic {
+    /// Frame of a message of the log callback, `None` for messages of other levels
+    pub fn from_log(message: &LogMessage<'_>) -> Option<Result<Traffic, FrameError>> {
+        if message.level != LogLevel::Traffic {
+            return None;
+        }
+        Some(parse_message(message.message, message.time))
+    }
+}
+
+/// Formats the frame as libcec logs it, e.g. `<< 10:8f`
+impl fmt::Display for Traffic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hex = frame::format_command(&self.command).map_err(|_| fmt::Error)?;
+        write!(f, "{} {hex}", self.direction.marker())
+    }
+}
+
+fn parse_message(message: &str, time: i64) -> Result<Traffic, FrameError> {
+    let message = message.trim();
+    let (direction, hex) = if let Some(hex) = message.strip_prefix(">>") {
+        (Direction::Received, hex)
+    } else if let Some(hex) = message.strip_prefix("<<") {
+        (Direction::Transmitted, hex)
+    } else {
+        return Err(FrameError::InvalidHex(message.to_owned()));
+    };
+    // libcec may append a note after the frame
+    let hex = hex.split_whitespace().next().unwrap_or_default();
+    Ok(Traffic {
+        time,
+        direction,
+        command: frame::parse_command(hex)?,
+    })
+}
+
+/// Frame of a line of a traffic log, `None` for lines of other levels
+///
+/// The line is either a message of the log callback, e.g. `>> 01:90:00` with a time of 0, or a
+/// `cec-client` log line, e.g. `TRAFFIC: [  2405] >> 01:90:00`.
+pub fn parse_line(line: &str) -> Option<Result<Traffic, FrameError>> {
+    let line = line.trim();
+    if line.starts_with(">>") || line.starts_with("<<") {
+        return Some(parse_message(line, 0));
+    }
+    let rest = line.strip_prefix("TRAFFIC:")?.trim_start();
+    let invalid = || FrameError::InvalidHex(line.to_owned());
+    let Some((time, message)) = rest.strip_prefix('[').and_then(|rest| rest.split_once(']')) else {
+        return Some(Err(invalid()));
+    };
+    let Ok(time) = time.trim().parse() else {
+        return Some(Err(invalid()));
+    };
+    Some(parse_message(message, time))
+}
+
+/// Error of a line of a traffic log
+#[derive(Debug)]
+pub enum TrafficError {
+    /// Error reading the log
+    Io(io::Error),
+    /// Traffic line with an invalid frame, at the 1-based `line`
+    Frame { line: usize, error: FrameError },
+}
+
+impl fmt::Display for TrafficError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrafficError::Io(err) => write!(f, "failed to read traffic log: {err}"),
+            TrafficError::Frame { line, error } => write!(f, "line {line}: {error}"),
+        }
+    }
+}
+
+impl Error for TrafficError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            TrafficError::Io(err) => Some(err),
+            TrafficError::Frame { error, .. } => Some(error),
+        }
+    }
+}
+
+impl From<io::Error> for TrafficError {
+    fn from(err: io::Error) -> Self {
+        TrafficError::Io(err)
+    }
+}
+
+/// Frames of a traffic log, skipping lines of other levels
+///
+/// Lines are read one at a time, so that large logs do not need to fit in memory. Invalid
+/// frames are returned as errors, and parsing continues with the next line.
+pub fn parse_log<R: BufRead>(reader: R) -> impl Iterator<Item = Result<Traffic, TrafficError>> {
+    reader
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| match line {
+            Ok(line) => parse_line(&line).map(|traffic| {
+                traffic.map_err(|error| TrafficError::Frame {
+                    line: index + 1,
+                    error,
+                })
+            }),
+            Err(err) => Some(Err(err.into())),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::{LogicalAddress, Opcode};
+
+    #[test]
+    fn test_log_message() {
+        let message = LogMessage {
+            message: "<< 10:47:6c:69",
+            level: LogLevel::Traffic,
+            time: 42,
+        };
+        let traffic = Traffic::from_log(&message).unwrap().unwrap();
+        assert_eq!(traffic.time, 42);
+        assert_eq!(traffic.direction, Direction::Transmitted);
+        assert_eq!(
+            LogicalAddress::from_raw(traffic.command.initiator),
+            LogicalAddress::RecordingDevice1
+        );
+        assert_eq!(Opcode::from_raw(traffic.command.opcode), Opcode::SetOsdName);
+        assert_eq!(traffic.to_string(), "<< 10:47:6c:69");
+
+        let debug = LogMessage {
+            level: LogLevel::Debug,
+            ..message
+        };
+        assert!(Traffic::from_log(&debug).is_none());
+    }
+
+    #[test]
+    fn test_lines() {
+        let traffic = parse_line(">> 0f:36").unwrap().unwrap();
+        assert_eq!(traffic.direction, Direction::Received);
+        assert_eq!(traffic.time, 0);
+        let poll = parse_line("TRAFFIC: [  10]\t<< 11").unwrap().unwrap();
+        assert_eq!(poll.command.opcode_set, 0);
+        assert!(parse_line("DEBUG:   [  10]\tTV (0): power status changed").is_none());
+        assert!(parse_line("").is_none());
+        assert!(matches!(
+            parse_line("TRAFFIC: [x]\t<< 11"),
+            Some(Err(FrameError::InvalidHex(_)))
+        ));
+        assert_eq!(
+            parse_line("TRAFFIC: [  10]\t>> zz").unwrap().err(),
+            Some(FrameError::InvalidHex("zz".to_owned()))
+        );
+    }
+
+    #[test]
+    fn test_log() {
+        let log = "\
+NOTICE:  [             297]\tconnection opened
+TRAFFIC: [             300]\t<< 10:8f
+TRAFFIC: [             340]\t>> 01:90:00
+TRAFFIC: [             350]\t>> 01:90
+DEBUG:   [             351]\tTV (0): power status changed
+";
+        let entries: Vec<_> = parse_log(log.as_bytes()).collect();
+        assert_eq!(entries.len(), 3);
+        let received = entries[1].as_ref().unwrap();
+        assert_eq!(received.time, 340);
+        assert_eq!(received.direction, Direction::Received);
+        assert_eq!(received.command.parameters.size, 1);
+        // a truncated frame is still a frame, it is for the codec to reject it
+        assert!(entries[2].is_ok());
+
+        let entries: Vec<_> = parse_log("TRAFFIC: [1]\t>> 0g".as_bytes()).collect();
+        assert!(matches!(
+            entries[..],
+            [Err(TrafficError::Frame { line: 1, .. })]
+        ));
+    }
+}
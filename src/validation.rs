@@ -0,0 +1,437 @@
+//! Validation of commands against the CEC specification
+//!
+//! [`validate`] checks a `cec_command` before it is transmitted: the addressing of the opcode,
+//! the number of operand bytes, the values of the operands, and rules such as no
+//! `<Feature Abort>` of a broadcast message. It uses the [`crate::opcode::OPCODES`] table and
+//! the [`crate::message`] codec.
+//!
+//! [`Mode::Lenient`] accepts what devices commonly send although the specification does not
+//! allow it, e.g. extra operands, which receivers must ignore.
+//!
+//! ```
+//! use libcec_sys::enums::{CecVersion, LogicalAddress, Opcode};
+//! use libcec_sys::frame;
+//! use libcec_sys::validation::{validate, Violation};
+//!
+//! // <Give Device Power Status> broadcast
+//! let command = frame::parse_command("4f:8f").unwrap();
+//! assert_eq!(
+//!     validate(&command, CecVersion::V1_4),
+//!     [Violation::WrongAddressing(Opcode::GiveDevicePowerStatus)]
+//! );
+//! ```
+use std::fmt;
+use std::os::raw::{c_int, c_uint};
+
+use crate::enums::{
+    AbortReason, AudioRate, CecVersion, DeckControlMode, DeckInfo, DisplayControl, LogicalAddress,
+    MenuRequestType, MenuState, Opcode, PlayMode, PowerStatus, RecordStatusInfo, StatusRequest,
+    SystemAudioStatus, TimerClearedStatusData, UserControlCode,
+};
+use crate::message::{self, CecMessage, MessageError};
+use crate::opcode::Addressing;
+use crate::*;
+
+/// How strictly to apply the specification
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Mode {
+    /// Report every violation
+    #[default]
+    Strict,
+    /// Accept extra operands, broadcast-only messages sent to a single device, opcodes which
+    /// are not known or newer than the CEC version, and unknown user control codes
+    Lenient,
+}
+
+/// Opcodes which a device without a logical address may broadcast, as `UNREGISTERED`
+pub const UNREGISTERED_BROADCASTS: &[Opcode] = &[
+    Opcode::ActiveSource,
+    Opcode::ReportPhysicalAddress,
+    Opcode::RequestActiveSource,
+    Opcode::RoutingChange,
+    Opcode::RoutingInformation,
+    Opcode::Standby,
+];
+
+/// Rule of the specification which a command breaks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Violation {
+    /// Initiator which is not a logical address from 0 to 15
+    InvalidInitiator(c_int),
+    /// Destination which is not a logical address from 0 to 15
+    InvalidDestination(c_int),
+    /// Message other than a POLL sent by a device to itself
+    SameInitiatorAndDestination,
+    /// POLL message, without opcode, with operands
+    OperandsWithoutOpcode,
+    /// Opcode which is not known to libcec
+    UnknownOpcode(Opcode),
+    /// Opcode introduced after the CEC version
+    UnsupportedOpcode { opcode: Opcode, version: CecVersion },
+    /// Broadcast message which must be sent to a single device, or the other way around
+    WrongAddressing(Opcode),
+    /// Broadcast from `UNREGISTERED` which is not in [`UNREGISTERED_BROADCASTS`]
+    UnregisteredBroadcast(Opcode),
+    /// Number of operand bytes not allowed for the opcode
+    InvalidLength { opcode: Opcode, length: usize },
+    /// Operand with a value not allowed by the specification
+    InvalidOperand {
+        opcode: Opcode,
+        operand: &'static str,
+    },
+    /// `<Feature Abort>` of a broadcast message, which must not be answered with one
+    FeatureAbortOfBroadcast(Opcode),
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::InvalidInitiator(address) => write!(f, "invalid initiator {address}"),
+            Violation::InvalidDestination(address) => write!(f, "invalid destination {address}"),
+            Violation::SameInitiatorAndDestination => {
+                f.write_str("initiator and destination are the same")
+            }
+            Violation::OperandsWithoutOpcode => f.write_str("operands without an opcode"),
+            Violation::UnknownOpcode(opcode) => {
+                let raw: c_uint = c_enum_value!(opcode.raw());
+                write!(f, "unknown opcode {raw:#04x}")
+            }
+            Violation::UnsupportedOpcode { opcode, version } => {
+                write!(f, "`{opcode}` is not supported by CEC version {version}")
+            }
+            Violation::WrongAddressing(opcode) => match opcode.info() {
+                Some(info) => write!(f, "`{opcode}` must be {}", info.addressing),
+                None => write!(f, "wrong addressing of `{opcode}`"),
+            },
+            Violation::UnregisteredBroadcast(opcode) => {
+                write!(
+                    f,
+                    "`{opcode}` must not be broadcast by an unregistered device"
+                )
+            }
+            Violation::InvalidLength { opcode, length } => {
+                write!(f, "invalid length {length} of `{opcode}` operands")
+            }
+            Violation::InvalidOperand { opcode, operand } => {
+                write!(f, "invalid `{opcode}` operand `{operand}`")
+            }
+            Violation::FeatureAbortOfBroadcast(opcode) => {
+                write!(f, "feature abort of broadcast message `{opcode}`")
+            }
+        }
+    }
+}
+
+/// Violations of the specification by `command`, in [`Mode::Strict`]
+pub fn validate(command: &cec_command, version: CecVersion) -> Vec<Violation> {
+    validate_with_mode(command, version, Mode::Strict)
+}
+
+/// Violations of the specification by `command`
+pub fn validate_with_mode(
+    command: &cec_command,
+    version: CecVersion,
+    mode: Mode,
+) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    let initiator = LogicalAddress::from_raw(command.initiator);
+    let destination = LogicalAddress::from_raw(command.destination);
+    if initiator.index().is_none() {
+        violations.push(Violation::InvalidInitiator(c_enum_value!(
+            command.initiator
+        )));
+    }
+    if destination.index().is_none() {
+        violations.push(Violation::InvalidDestination(c_enum_value!(
+            command.destination
+        )));
+    }
+    let operands = match message::operands(command) {
+        Ok(operands) => operands,
+        Err(_) => {
+            violations.push(Violation::InvalidLength {
+                opcode: Opcode::from_raw(command.opcode),
+                length: command.parameters.size.into(),
+            });
+            return violations;
+        }
+    };
+    if command.opcode_set == 0 {
+        if !operands.is_empty() {
+            violations.push(Violation::OperandsWithoutOpcode);
+        }
+        return violations;
+    }
+    let broadcast = destination.is_broadcast();
+    if initiator == destination && !broadcast {
+        violations.push(Violation::SameInitiatorAndDestination);
+    }
+
+    let opcode = Opcode::from_raw(command.opcode);
+    let Some(info) = opcode.info() else {
+        if mode == Mode::Strict {
+            violations.push(Violation::UnknownOpcode(opcode));
+        }
+        return violations;
+    };
+    if mode == Mode::Strict && version_number(info.version) > version_number(version) {
+        violations.push(Violation::UnsupportedOpcode { opcode, version });
+    }
+    let lenient_directed = mode == Mode::Lenient && info.addressing == Addressing::Broadcast;
+    if !info.addressing.allows(broadcast) && !lenient_directed {
+        violations.push(Violation::WrongAddressing(opcode));
+    }
+    if broadcast
+        && initiator == LogicalAddress::UNREGISTERED
+        && !UNREGISTERED_BROADCASTS.contains(&opcode)
+    {
+        violations.push(Violation::UnregisteredBroadcast(opcode));
+    }
+
+    let max = usize::from(info.max_operands);
+    let operands = match mode {
+        Mode::Lenient if operands.len() > max => &operands[..max],
+        _ => operands,
+    };
+    match CecMessage::decode(opcode, operands) {
+        Ok(message) => {
+            if let Some(operand) = invalid_operand(&message, mode) {
+                violations.push(Violation::InvalidOperand { opcode, operand });
+            }
+            if let CecMessage::FeatureAbort(abort) = message {
+                let aborted = abort.opcode.info().map(|info| info.addressing);
+                if aborted == Some(Addressing::Broadcast) {
+                    violations.push(Violation::FeatureAbortOfBroadcast(abort.opcode));
+                }
+            }
+        }
+        Err(MessageError::InvalidOperand { operand, .. }) => {
+            violations.push(Violation::InvalidOperand { opcode, operand });
+        }
+        Err(_) => violations.push(Violation::InvalidLength {
+            opcode,
+            length: operands.len(),
+        }),
+    }
+    violations
+}
+
+fn version_number(version: CecVersion) -> c_uint {
+    c_enum_value!(version.raw())
+}
+
+/// First operand of `message` with a value not allowed by the specification
+fn invalid_operand(message: &CecMessage, mode: Mode) -> Option<&'static str> {
+    match message {
+        CecMessage::ActiveSource(message::ActiveSource { physical_address })
+        | CecMessage::InactiveSource(message::InactiveSource { physical_address })
+        | CecMessage::RoutingInformation(message::RoutingInformation { physical_address })
+        | CecMessage::SetStreamPath(message::SetStreamPath { physical_address })
+        | CecMessage::ReportPhysicalAddress(message::ReportPhysicalAddress {
+            physical_address,
+            ..
+        }) => (!physical_address.is_valid()).then_some("physical_address"),
+        CecMessage::RoutingChange(message) => {
+            if !message.original_address.is_valid() {
+                Some("original_address")
+            } else {
+                (!message.new_address.is_valid()).then_some("new_address")
+            }
+        }
+        CecMessage::SetMenuLanguage(message) => {
+            let valid = message.language.iter().all(u8::is_ascii_lowercase);
+            (!valid).then_some("language")
+        }
+        CecMessage::CecVersion(message) => {
+            matches!(message.version, CecVersion::Unknown(_)).then_some("version")
+        }
+        CecMessage::ReportPowerStatus(message) => {
+            matches!(message.status, PowerStatus::Unknown(_)).then_some("status")
+        }
+        CecMessage::MenuRequest(message) => {
+            matches!(message.request, MenuRequestType::Unknown(_)).then_some("request")
+        }
+        CecMessage::MenuStatus(message) => {
+            matches!(message.state, MenuState::Unknown(_)).then_some("state")
+        }
+        CecMessage::DeckControl(message) => {
+            matches!(message.mode, DeckControlMode::Unknown(_)).then_some("mode")
+        }
+        CecMessage::DeckStatus(message) => {
+            matches!(message.info, DeckInfo::Unknown(_)).then_some("info")
+        }
+        CecMessage::GiveDeckStatus(message::GiveDeckStatus { request })
+        | CecMessage::GiveTunerDeviceStatus(message::GiveTunerDeviceStatus { request }) => {
+            matches!(request, StatusRequest::Unknown(_)).then_some("request")
+        }
+        CecMessage::Play(message) => matches!(message.mode, PlayMode::Unknown(_)).then_some("mode"),
+        CecMessage::FeatureAbort(message) => {
+            matches!(message.reason, AbortReason::Unknown(_)).then_some("reason")
+        }
+        CecMessage::SetSystemAudioMode(message::SetSystemAudioMode { status })
+        | CecMessage::SystemAudioModeStatus(message::SystemAudioModeStatus { status }) => {
+            matches!(status, SystemAudioStatus::Unknown(_)).then_some("status")
+        }
+        CecMessage::SystemAudioModeRequest(message) => message
+            .physical_address
+            .filter(|address| !address.is_valid())
+            .map(|_| "physical_address"),
+        CecMessage::SetAudioRate(message) => {
+            matches!(message.rate, AudioRate::Unknown(_)).then_some("rate")
+        }
+        CecMessage::RecordStatus(message) => {
+            matches!(message.status, RecordStatusInfo::Unknown(_)).then_some("status")
+        }
+        CecMessage::TimerClearedStatus(message) => {
+            matches!(message.status, TimerClearedStatusData::Unknown(_)).then_some("status")
+        }
+        CecMessage::SetOsdString(message) => {
+            matches!(message.control, DisplayControl::Unknown(_)).then_some("control")
+        }
+        CecMessage::UserControlPressed(message) if mode == Mode::Strict => {
+            matches!(message.code, UserControlCode::Unknown(_)).then_some("code")
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frame;
+
+    fn check(hex: &str, mode: Mode) -> Vec<Violation> {
+        validate_with_mode(&frame::parse_command(hex).unwrap(), CecVersion::V1_4, mode)
+    }
+
+    #[test]
+    fn test_valid() {
+        for hex in [
+            "4f:82:10:00",
+            "40:8f",
+            "04:90:01",
+            "4f:84:10:00:04",
+            "40:47:4b:6f:64:69",
+            "04:00:8f:04",
+            "0f:36",
+            "44",
+            "ff:84:10:00:04",
+            "45:c0",
+        ] {
+            assert_eq!(check(hex, Mode::Strict), [], "{hex}");
+        }
+    }
+
+    #[test]
+    fn test_addressing() {
+        assert_eq!(
+            check("40:82:10:00", Mode::Strict),
+            [Violation::WrongAddressing(Opcode::ActiveSource)]
+        );
+        assert_eq!(check("40:82:10:00", Mode::Lenient), []);
+        assert_eq!(
+            check("4f:8f", Mode::Lenient),
+            [Violation::WrongAddressing(Opcode::GiveDevicePowerStatus)]
+        );
+        assert_eq!(
+            check("44:8f", Mode::Strict),
+            [Violation::SameInitiatorAndDestination]
+        );
+        assert_eq!(
+            check("ff:87:00:15:82", Mode::Strict),
+            [Violation::UnregisteredBroadcast(Opcode::DeviceVendorId)]
+        );
+        let command = cec_command {
+            destination: LogicalAddress::UNKNOWN.raw(),
+            ..frame::parse_command("40:8f").unwrap()
+        };
+        assert_eq!(
+            validate(&command, CecVersion::V1_4),
+            [Violation::InvalidDestination(-1)]
+        );
+    }
+
+    #[test]
+    fn test_operands() {
+        assert_eq!(
+            check("04:90:07", Mode::Lenient),
+            [Violation::InvalidOperand {
+                opcode: Opcode::ReportPowerStatus,
+                operand: "status"
+            }]
+        );
+        assert_eq!(
+            check("04:8e:05", Mode::Strict),
+            [Violation::InvalidOperand {
+                opcode: Opcode::MenuStatus,
+                operand: "state"
+            }]
+        );
+        assert_eq!(
+            check("40:42:20", Mode::Strict),
+            [Violation::InvalidOperand {
+                opcode: Opcode::DeckControl,
+                operand: "mode"
+            }]
+        );
+        assert_eq!(
+            check("4f:82:01:00", Mode::Strict),
+            [Violation::InvalidOperand {
+                opcode: Opcode::ActiveSource,
+                operand: "physical_address"
+            }]
+        );
+        assert_eq!(
+            check("04:90", Mode::Lenient),
+            [Violation::InvalidLength {
+                opcode: Opcode::ReportPowerStatus,
+                length: 0
+            }]
+        );
+        assert_eq!(
+            check("04:90:00:00", Mode::Strict),
+            [Violation::InvalidLength {
+                opcode: Opcode::ReportPowerStatus,
+                length: 2
+            }]
+        );
+        assert_eq!(check("04:90:00:00", Mode::Lenient), []);
+        assert_eq!(
+            check("40:44:f0", Mode::Strict),
+            [Violation::InvalidOperand {
+                opcode: Opcode::UserControlPressed,
+                operand: "code"
+            }]
+        );
+        assert_eq!(check("40:44:f0", Mode::Lenient), []);
+        assert_eq!(
+            check("41", Mode::Strict),
+            [],
+            "POLL message without operands"
+        );
+    }
+
+    #[test]
+    fn test_opcodes() {
+        assert_eq!(
+            check("40:01", Mode::Strict),
+            [Violation::UnknownOpcode(Opcode::Unknown(c_enum!(
+                cec_opcode, 0x01
+            )))]
+        );
+        assert_eq!(check("40:01", Mode::Lenient), []);
+        let command = frame::parse_command("45:c0").unwrap();
+        assert_eq!(
+            validate(&command, CecVersion::V1_3a),
+            [Violation::UnsupportedOpcode {
+                opcode: Opcode::StartArc,
+                version: CecVersion::V1_3a
+            }]
+        );
+        assert_eq!(
+            check("04:00:82:04", Mode::Lenient),
+            [Violation::FeatureAbortOfBroadcast(Opcode::ActiveSource)]
+        );
+    }
+}